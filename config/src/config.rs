@@ -89,6 +89,11 @@ pub struct Config {
     #[partial_struct(serde(default))]
     pub mining: Mining,
 
+    /// RAD engine configuration
+    #[partial_struct(ty = "PartialRad")]
+    #[partial_struct(serde(default))]
+    pub rad: Rad,
+
     /// Wallet-related configuration
     #[partial_struct(ty = "PartialWallet")]
     #[partial_struct(serde(default))]
@@ -262,6 +267,32 @@ pub struct Mining {
     pub enabled: bool,
 }
 
+/// RAD engine configuration, which limits how data is retrieved from the sources of data requests
+#[derive(PartialStruct, Debug, Clone, PartialEq)]
+#[partial_struct(derive(Deserialize, Default, Debug, Clone, PartialEq))]
+pub struct Rad {
+    /// Maximum time to wait for the response to a request made to an HTTP source
+    #[partial_struct(serde(
+        default,
+        deserialize_with = "from_secs",
+        rename = "retrieval_timeout_seconds"
+    ))]
+    pub retrieval_timeout: Duration,
+
+    /// Maximum size in bytes of the body of the response of an HTTP source
+    pub max_response_size: u64,
+
+    /// Maximum number of redirects followed when retrieving an HTTP source
+    pub max_redirects: usize,
+
+    /// Media types accepted in the `Content-Type` of the response of an HTTP source. Any media
+    /// type is accepted if empty
+    pub content_types: Vec<String>,
+
+    /// Maximum number of sources of a data request that are retrieved at the same time
+    pub max_concurrent_retrievals: usize,
+}
+
 impl Config {
     pub fn from_partial(config: &PartialConfig) -> Self {
         let defaults: &dyn Defaults = match config.environment {
@@ -301,6 +332,7 @@ impl Config {
             consensus_constants,
             jsonrpc: JsonRPC::from_partial(&config.jsonrpc, defaults),
             mining: Mining::from_partial(&config.mining, defaults),
+            rad: Rad::from_partial(&config.rad, defaults),
             wallet: Wallet::from_partial(&config.wallet, defaults),
             rocksdb: Rocksdb::from_partial(&config.rocksdb, defaults),
         }
@@ -482,6 +514,29 @@ pub struct Wallet {
     pub session_expires_in: u64,
 }
 
+impl Rad {
+    pub fn from_partial(config: &PartialRad, defaults: &dyn Defaults) -> Self {
+        Rad {
+            retrieval_timeout: config
+                .retrieval_timeout
+                .unwrap_or_else(|| defaults.rad_retrieval_timeout()),
+            max_response_size: config
+                .max_response_size
+                .unwrap_or_else(|| defaults.rad_max_response_size()),
+            max_redirects: config
+                .max_redirects
+                .unwrap_or_else(|| defaults.rad_max_redirects()),
+            content_types: config
+                .content_types
+                .clone()
+                .unwrap_or_else(|| defaults.rad_content_types()),
+            max_concurrent_retrievals: config
+                .max_concurrent_retrievals
+                .unwrap_or_else(|| defaults.rad_max_concurrent_retrievals()),
+        }
+    }
+}
+
 impl Wallet {
     pub fn from_partial(config: &PartialWallet, defaults: &dyn Defaults) -> Self {
         Wallet {
@@ -683,6 +738,39 @@ mod tests {
         assert_eq!(config.server_address, addr);
     }
 
+    #[test]
+    fn test_rad_default_from_partial() {
+        let partial_config = PartialRad::default();
+        let config = Rad::from_partial(&partial_config, &Testnet1);
+
+        assert_eq!(config.retrieval_timeout, Testnet1.rad_retrieval_timeout());
+        assert_eq!(config.max_response_size, Testnet1.rad_max_response_size());
+        assert_eq!(config.max_redirects, Testnet1.rad_max_redirects());
+        assert_eq!(config.content_types, Testnet1.rad_content_types());
+        assert_eq!(
+            config.max_concurrent_retrievals,
+            Testnet1.rad_max_concurrent_retrievals()
+        );
+    }
+
+    #[test]
+    fn test_rad_from_partial() {
+        let partial_config = PartialRad {
+            retrieval_timeout: Some(Duration::from_secs(3)),
+            max_response_size: Some(1024),
+            max_redirects: Some(0),
+            content_types: Some(vec![]),
+            max_concurrent_retrievals: Some(2),
+        };
+        let config = Rad::from_partial(&partial_config, &Testnet1);
+
+        assert_eq!(config.retrieval_timeout, Duration::from_secs(3));
+        assert_eq!(config.max_response_size, 1024);
+        assert_eq!(config.max_redirects, 0);
+        assert!(config.content_types.is_empty());
+        assert_eq!(config.max_concurrent_retrievals, 2);
+    }
+
     #[test]
     fn test_config_default_from_partial() {
         let partial_config = PartialConfig::default();
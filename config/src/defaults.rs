@@ -135,6 +135,42 @@ pub trait Defaults {
         0.5
     }
 
+    /// Default timeout for the requests made to HTTP sources: `10` seconds
+    fn rad_retrieval_timeout(&self) -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// Default maximum size of the responses of HTTP sources: `1` MiB
+    fn rad_max_response_size(&self) -> u64 {
+        1 << 20
+    }
+
+    /// Default maximum number of redirects followed when retrieving HTTP sources: `3`
+    fn rad_max_redirects(&self) -> usize {
+        3
+    }
+
+    /// Default media types accepted in the responses of HTTP sources, which are the textual
+    /// formats that RADON scripts can parse
+    fn rad_content_types(&self) -> Vec<String> {
+        [
+            "application/json",
+            "application/xml",
+            "text/csv",
+            "text/html",
+            "text/plain",
+            "text/xml",
+        ]
+        .iter()
+        .map(|content_type| content_type.to_string())
+        .collect()
+    }
+
+    /// Default maximum number of sources of a data request retrieved at the same time: `8`
+    fn rad_max_concurrent_retrievals(&self) -> usize {
+        8
+    }
+
     /// Wallet server address
     fn wallet_server_addr(&self) -> SocketAddr {
         SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 11212)
@@ -125,6 +125,12 @@ pub struct ExtendedSK {
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct ChildNumber(u32);
 
+impl From<u32> for ChildNumber {
+    fn from(index: u32) -> Self {
+        ChildNumber(index)
+    }
+}
+
 impl ChildNumber {
     /// check if a child is hardened
     pub fn is_hardened(self) -> bool {
@@ -446,7 +446,7 @@ pub type SHA256 = [u8; 32];
 /// Public Key Hash: slice of the digest of a public key (20 bytes).
 ///
 /// It is the first 20 bytes of the SHA256 hash of the PublicKey.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, ProtobufConvert)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, ProtobufConvert)]
 #[protobuf_convert(pb = "witnet::PublicKeyHash")]
 pub struct PublicKeyHash {
     pub(crate) hash: [u8; 20],
@@ -619,8 +619,19 @@ pub struct ExtendedSecretKey {
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Hash)]
 pub enum RADType {
+    /// Fetch the body of the response to an HTTP GET request to the URL
     #[serde(rename = "HTTP-GET")]
     HttpGet,
+    /// Fetch the body of the response to an HTTP POST request with the given body and headers
+    #[serde(rename = "HTTP-POST")]
+    HttpPost,
+    /// Random bytes derived from the beacon of the block on top of which the data request is
+    /// resolved, so that every witness gets the same ones
+    #[serde(rename = "RNG")]
+    Rng,
+    /// Result of the tally of another data request, whose hash is given as the URL
+    #[serde(rename = "TALLY")]
+    Tally,
 }
 
 impl Default for RADType {
@@ -649,6 +660,19 @@ pub struct RADRetrieve {
     pub kind: RADType,
     pub url: String,
     pub script: Vec<u8>,
+    /// Body of the request, only for `HTTP-POST` sources
+    #[serde(default)]
+    pub body: Vec<u8>,
+    /// Headers of the request, only for `HTTP-POST` sources
+    #[serde(default)]
+    pub headers: Vec<RADHeader>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, ProtobufConvert, Hash, Default)]
+#[protobuf_convert(pb = "witnet::DataRequestOutput_RADRequest_RADHeader", crate = "crate")]
+pub struct RADHeader {
+    pub name: String,
+    pub value: String,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, ProtobufConvert, Hash, Default)]
@@ -1060,6 +1084,53 @@ impl TransactionsPool {
         self.dr_transactions.values()
     }
 
+    /// An iterator visiting all the commit transactions
+    /// in the pool
+    pub fn co_iter(&self) -> impl Iterator<Item = &CommitTransaction> {
+        self.co_transactions.values().flat_map(HashMap::values)
+    }
+
+    /// An iterator visiting all the reveal transactions
+    /// in the pool
+    pub fn re_iter(&self) -> impl Iterator<Item = &RevealTransaction> {
+        self.re_transactions.values().flat_map(HashMap::values)
+    }
+
+    /// Returns a copy of the transaction identified by `key`,
+    /// whatever its type is.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// # use witnet_data_structures::chain::{TransactionsPool, Hash, Hashable};
+    /// # use witnet_data_structures::transaction::{Transaction, DRTransaction};
+    /// let mut pool = TransactionsPool::new();
+    ///
+    /// let transaction = Transaction::DataRequest(DRTransaction::default());
+    /// let hash = transaction.hash();
+    ///
+    /// assert!(pool.get(&hash).is_none());
+    ///
+    /// pool.insert(transaction.clone());
+    ///
+    /// assert_eq!(pool.get(&hash), Some(transaction));
+    /// ```
+    pub fn get(&self, key: &Hash) -> Option<Transaction> {
+        if let Some(vt_tx) = self.vt_get(key) {
+            return Some(Transaction::ValueTransfer(vt_tx.clone()));
+        }
+        if let Some(dr_tx) = self.dr_transactions.get(key) {
+            return Some(Transaction::DataRequest(dr_tx.clone()));
+        }
+        if let Some(co_tx) = self.co_iter().find(|tx| tx.hash() == *key) {
+            return Some(Transaction::Commit(co_tx.clone()));
+        }
+
+        self.re_iter()
+            .find(|tx| tx.hash() == *key)
+            .map(|re_tx| Transaction::Reveal(re_tx.clone()))
+    }
+
     /// Returns a reference to the value corresponding to the key.
     ///
     /// Examples:
@@ -1232,6 +1303,28 @@ impl TryFrom<DataRequestInfo> for DataRequestReport {
     }
 }
 
+/// Finished data request whose result has to be delivered to the endpoints declared in the
+/// deliver clauses of its RAD request
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DataRequestDelivery {
+    /// Deliver clauses of the RAD request
+    pub deliver: Vec<RADDeliver>,
+    /// Report of the data request (its tally contains the result to be delivered)
+    pub report: DataRequestReport,
+}
+
+/// Outcome of delivering the result of a data request to one of its endpoints, as persisted in
+/// the delivery log of the data request
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DeliveryRecord {
+    /// URL of the endpoint
+    pub url: String,
+    /// Number of attempts made
+    pub attempts: u32,
+    /// Error of the last attempt, if the result could not be delivered
+    pub error: Option<String>,
+}
+
 /// List of outputs related to a data request
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DataRequestInfo {
@@ -1370,6 +1463,13 @@ pub type UnspentOutputsPool = HashMap<OutputPointer, ValueTransferOutput>;
 
 pub type Blockchain = BTreeMap<Epoch, Hash>;
 
+/// Version of the format in which the `ChainState` is persisted into storage.
+///
+/// The `ChainState` is persisted with bincode, which cannot read values written with a
+/// different layout, so this must be increased on every change to `ChainState` or to any type
+/// nested in it. A node discards a chain state persisted with another version.
+pub const CHAIN_STATE_VERSION: u32 = 2;
+
 /// Blockchain state (valid at a certain epoch)
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ChainState {
@@ -1782,4 +1882,18 @@ mod tests {
             .unwrap();
         assert!(a < b);
     }
+
+    #[test]
+    fn rad_retrieve_bincode_roundtrip() {
+        // The chain state is persisted with bincode, so every field must be written even if empty
+        let retrieve = RADRetrieve {
+            kind: RADType::HttpGet,
+            url: "https://example.com".to_string(),
+            script: vec![0x80],
+            ..RADRetrieve::default()
+        };
+        let bytes = bincode::serialize(&retrieve).unwrap();
+        let decoded: RADRetrieve = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, retrieve);
+    }
 }
@@ -3,8 +3,9 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::{
     chain::{
-        DataRequestOutput, DataRequestReport, DataRequestStage, DataRequestState, Epoch, Hash,
-        Hashable, PublicKeyHash, ValueTransferOutput,
+        CheckpointBeacon, DataRequestDelivery, DataRequestOutput, DataRequestReport,
+        DataRequestStage, DataRequestState, Epoch, Hash, Hashable, PublicKeyHash,
+        ValueTransferOutput,
     },
     error::{DataRequestError, TransactionError},
     transaction::{CommitTransaction, DRTransaction, RevealTransaction, TallyTransaction},
@@ -21,8 +22,16 @@ pub struct DataRequestPool {
     pub data_requests_by_epoch: BTreeMap<Epoch, HashSet<Hash>>,
     /// List of active data requests indexed by output pointer
     pub data_request_pool: HashMap<Hash, DataRequestState>,
+    /// Beacon of the chain right after consolidating the block that included every active data
+    /// request, indexed by output pointer. It seeds the `RNG` sources of the data request, so
+    /// they resolve to the same value whatever the epoch in which each witness commits
+    pub data_request_beacons: HashMap<Hash, CheckpointBeacon>,
     /// List of data requests that should be persisted into storage
     pub to_be_stored: Vec<DataRequestReport>,
+    /// List of finished data requests whose results should be delivered. It is drained right
+    /// after the block that fills it is consolidated, so it is not persisted
+    #[serde(skip)]
+    pub to_be_delivered: Vec<DataRequestDelivery>,
 }
 
 impl DataRequestPool {
@@ -42,11 +51,20 @@ impl DataRequestPool {
             .map(|dr_state| dr_state.data_request.clone())
     }
 
-    /// Get all reveals related to a `DataRequestOuput`
+    /// Get all reveals related to a `DataRequestOuput`, sorted by the public key hash of their
+    /// witnesses
     pub fn get_reveals(&self, dr_pointer: &Hash) -> Option<Vec<&RevealTransaction>> {
-        self.data_request_pool
-            .get(dr_pointer)
-            .map(|dr_state| dr_state.info.reveals.values().collect())
+        self.data_request_pool.get(dr_pointer).map(|dr_state| {
+            let mut reveals: Vec<&RevealTransaction> = dr_state.info.reveals.values().collect();
+            reveals.sort_by_key(|reveal| reveal.body.pkh);
+
+            reveals
+        })
+    }
+
+    /// Get the beacon from which the `RNG` sources of a data request are seeded
+    pub fn get_dr_beacon(&self, dr_pointer: &Hash) -> Option<CheckpointBeacon> {
+        self.data_request_beacons.get(dr_pointer).cloned()
     }
 
     /// Insert a reveal transaction into the pool
@@ -54,13 +72,16 @@ impl DataRequestPool {
         self.waiting_for_reveal.insert(dr_pointer, reveal);
     }
 
-    /// Get all the reveals
+    /// Get all the reveals of the data requests in tally stage, sorted by the public key hash of
+    /// their witnesses
     pub fn get_all_reveals(&self) -> HashMap<Hash, Vec<RevealTransaction>> {
         self.data_request_pool
             .iter()
             .filter_map(|(dr_pointer, dr_state)| {
                 if let DataRequestStage::TALLY = dr_state.stage {
-                    let reveals = dr_state.info.reveals.values().cloned().collect();
+                    let mut reveals: Vec<RevealTransaction> =
+                        dr_state.info.reveals.values().cloned().collect();
+                    reveals.sort_by_key(|reveal| reveal.body.pkh);
                     Some((*dr_pointer, reveals))
                 } else {
                     None
@@ -149,12 +170,27 @@ impl DataRequestPool {
         tally: TallyTransaction,
         block_hash: &Hash,
     ) -> Result<(), failure::Error> {
+        let deliver = self
+            .data_request_pool
+            .get(&tally.dr_pointer)
+            .map(|dr_state| dr_state.data_request.data_request.deliver.clone())
+            .unwrap_or_default();
+        let dr_pointer = tally.dr_pointer;
         let dr_report = Self::resolve_data_request(&mut self.data_request_pool, tally, block_hash)?;
+        self.data_request_beacons.remove(&dr_pointer);
 
         // Since this method does not have access to the storage, we save the
         // "to be stored" inside a vector and provide another method to store them
         self.to_be_stored.push(dr_report.clone());
 
+        // The same goes for the network, so results are delivered later on
+        if !deliver.is_empty() {
+            self.to_be_delivered.push(DataRequestDelivery {
+                deliver,
+                report: dr_report,
+            });
+        }
+
         Ok(())
     }
 
@@ -265,11 +301,13 @@ impl DataRequestPool {
     }
 
     /// New data requests are inserted and wait for commitments
-    /// The epoch is needed as the key to the available data requests map
+    /// The epoch is needed as the key to the available data requests map, and along with the hash
+    /// of the block that includes the data request, it makes the beacon of its `RNG` sources
     pub fn process_data_request(
         &mut self,
         dr_transaction: &DRTransaction,
         epoch: Epoch,
+        block_hash: &Hash,
     ) -> Result<(), failure::Error> {
         // A data request output should have a valid value transfer input
         // Which we assume valid as it should have been already verified
@@ -281,7 +319,16 @@ impl DataRequestPool {
         // calls to GetEpoch
         let time_lock_epoch = 0;
         let dr_epoch = std::cmp::max(epoch, time_lock_epoch);
-        self.add_data_request(dr_epoch, dr_transaction.clone())
+        self.add_data_request(dr_epoch, dr_transaction.clone())?;
+        self.data_request_beacons.insert(
+            dr_transaction.hash(),
+            CheckpointBeacon {
+                checkpoint: epoch,
+                hash_prev_block: *block_hash,
+            },
+        );
+
+        Ok(())
     }
 
     /// New tallies are added to their respective data requests and finish them
@@ -294,7 +341,6 @@ impl DataRequestPool {
     }
 
     /// Get the detailed state of a data request.
-    #[allow(unused)]
     pub fn data_request_state(&self, dr_pointer: &Hash) -> Option<&DataRequestState> {
         self.data_request_pool.get(dr_pointer)
     }
@@ -304,6 +350,11 @@ impl DataRequestPool {
     pub fn finished_data_requests(&mut self) -> Vec<DataRequestReport> {
         std::mem::replace(&mut self.to_be_stored, vec![])
     }
+
+    /// Get the finished data requests whose results have to be delivered
+    pub fn finished_deliveries(&mut self) -> Vec<DataRequestDelivery> {
+        std::mem::replace(&mut self.to_be_delivered, vec![])
+    }
 }
 
 /// Function to calculate the value transfer reward
@@ -313,36 +364,44 @@ pub fn calculate_dr_vt_reward(dr_output: &DataRequestOutput) -> u64 {
     total_reward / u64::from(dr_output.witnesses)
 }
 
-// FIXME(#640): replace with real truthness check function from radon engine
-// (currently we assume that all nodes are honest)
-pub fn true_revealer(_reveal: &RevealTransaction, _tally: &[u8]) -> bool {
-    true
+/// Whether a reveal was considered honest by the given tally, that is, whether it survived the
+/// filters of the consensus script.
+pub fn true_revealer(reveal: &RevealTransaction, tally: &TallyTransaction) -> bool {
+    !tally.liars.contains(&reveal.body.pkh)
 }
 
+/// Create the tally of a data request from the result of its consensus script. `honest` tells for
+/// each one of the `reveals` whether it survived the consensus filters: only honest revealers are
+/// rewarded, and the rest are flagged as liars.
 pub fn create_tally(
     dr_pointer: Hash,
     dr_output: &DataRequestOutput,
     pkh: PublicKeyHash,
     consensus_tally: Vec<u8>,
     reveals: Vec<RevealTransaction>,
+    honest: Vec<bool>,
 ) -> TallyTransaction {
     let reveal_reward = calculate_dr_vt_reward(dr_output);
 
+    let mut liars = vec![];
     let mut outputs: Vec<ValueTransferOutput> = reveals
         .into_iter()
-        .filter_map(|reveal| {
+        .zip(honest)
+        .filter_map(|(reveal, honest)| {
             // Only reward reveals in consensus
-            if true_revealer(&reveal, &consensus_tally) {
+            if honest {
                 let vt_output = ValueTransferOutput {
                     pkh: reveal.body.pkh,
                     value: reveal_reward,
                 };
                 Some(vt_output)
             } else {
+                liars.push(reveal.body.pkh);
                 None
             }
         })
         .collect();
+    liars.sort();
 
     let n_honest = outputs.len() as u16;
     // Create tally change for the data request creator
@@ -356,7 +415,7 @@ pub fn create_tally(
         outputs.push(vt_output_change);
     }
 
-    TallyTransaction::new(dr_pointer, consensus_tally, outputs)
+    TallyTransaction::new(dr_pointer, consensus_tally, outputs, liars)
 }
 
 #[cfg(test)]
@@ -375,7 +434,8 @@ mod tests {
         let dr_pointer = dr_transaction.hash();
 
         let mut p = DataRequestPool::default();
-        p.process_data_request(&dr_transaction, epoch).unwrap();
+        p.process_data_request(&dr_transaction, epoch, &fake_block_hash)
+            .unwrap();
 
         assert!(p.waiting_for_reveal.is_empty());
         assert!(p.data_requests_by_epoch[&epoch].contains(&dr_pointer));
@@ -385,6 +445,13 @@ mod tests {
             DataRequestStage::COMMIT
         );
         assert!(p.to_be_stored.is_empty());
+        assert_eq!(
+            p.get_dr_beacon(&dr_pointer),
+            Some(CheckpointBeacon {
+                checkpoint: epoch,
+                hash_prev_block: fake_block_hash,
+            })
+        );
 
         assert!(p.update_data_request_stages().is_empty());
 
@@ -487,7 +554,7 @@ mod tests {
     }
 
     fn from_tally_to_storage(fake_block_hash: Hash, mut p: DataRequestPool, dr_pointer: Hash) {
-        let tally_transaction = TallyTransaction::new(dr_pointer, vec![], vec![]);
+        let tally_transaction = TallyTransaction::new(dr_pointer, vec![], vec![], vec![]);
 
         // There is nothing to be stored yet
         assert_eq!(p.to_be_stored.len(), 0);
@@ -504,6 +571,9 @@ mod tests {
 
         assert_eq!(p.to_be_stored.len(), 1);
         assert_eq!(p.to_be_stored[0].tally.dr_pointer, dr_pointer);
+
+        // The data request has no deliver clauses, so there is nothing to deliver
+        assert!(p.finished_deliveries().is_empty());
     }
 
     #[test]
@@ -537,6 +607,65 @@ mod tests {
         from_tally_to_storage(fake_block_hash, p, dr_pointer);
     }
 
+    #[test]
+    fn test_from_tally_to_delivery() {
+        let fake_block_hash = Hash::SHA256([1; 32]);
+        let epoch = 0;
+        let deliver = vec![RADDeliver {
+            kind: RADType::HttpPost,
+            url: "https://example.com/results".to_string(),
+        }];
+        let mut dr_output = DataRequestOutput::default();
+        dr_output.data_request.deliver = deliver.clone();
+        let dr_transaction = DRTransaction::new(
+            DRTransactionBody::new(vec![Input::default()], vec![], dr_output),
+            vec![KeyedSignature::default()],
+        );
+        let dr_pointer = dr_transaction.hash();
+
+        let mut p = DataRequestPool::default();
+        p.process_data_request(&dr_transaction, epoch, &fake_block_hash)
+            .unwrap();
+        let (fake_block_hash, p, dr_pointer) =
+            from_commit_to_reveal(epoch, fake_block_hash, p, dr_pointer);
+        let (fake_block_hash, mut p, dr_pointer) =
+            from_reveal_to_tally(fake_block_hash, p, dr_pointer);
+
+        let tally_transaction = TallyTransaction::new(dr_pointer, vec![], vec![], vec![]);
+        p.process_tally(&tally_transaction, &fake_block_hash)
+            .unwrap();
+
+        let deliveries = p.finished_deliveries();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].deliver, deliver);
+        assert_eq!(deliveries[0].report.tally, tally_transaction);
+
+        // Deliveries are only handed out once
+        assert!(p.finished_deliveries().is_empty());
+        // Finished data requests do not keep their beacon
+        assert_eq!(p.get_dr_beacon(&dr_pointer), None);
+    }
+
+    #[test]
+    fn deliveries_are_not_persisted() {
+        // The pool is persisted with bincode as part of the chain state, which must not change
+        // when there are pending deliveries
+        let mut p = DataRequestPool::default();
+        let bytes = bincode::serialize(&p).unwrap();
+        p.to_be_delivered.push(DataRequestDelivery {
+            deliver: vec![],
+            report: DataRequestReport {
+                commits: vec![],
+                reveals: vec![],
+                tally: TallyTransaction::new(Hash::default(), vec![], vec![], vec![]),
+            },
+        });
+
+        assert_eq!(bincode::serialize(&p).unwrap(), bytes);
+        let decoded: DataRequestPool = bincode::deserialize(&bytes).unwrap();
+        assert!(decoded.to_be_delivered.is_empty());
+    }
+
     #[test]
     fn my_claims() {
         // Test the `add_own_reveal` function
@@ -628,4 +757,52 @@ mod tests {
 
         from_tally_to_storage(fake_block_hash, p, dr_pointer);
     }
+
+    #[test]
+    fn create_tally_flags_liars() {
+        let dr_output = DataRequestOutput {
+            witnesses: 2,
+            value: 1000,
+            ..DataRequestOutput::default()
+        };
+        let creator = PublicKeyHash { hash: [0; 20] };
+        let honest_pkh = PublicKeyHash { hash: [1; 20] };
+        let liar_pkh = PublicKeyHash { hash: [2; 20] };
+        let reveals = vec![
+            RevealTransaction::new(
+                RevealTransactionBody::new(Hash::default(), vec![0x00], honest_pkh),
+                vec![],
+            ),
+            RevealTransaction::new(
+                RevealTransactionBody::new(Hash::default(), vec![0x01], liar_pkh),
+                vec![],
+            ),
+        ];
+
+        let tally = create_tally(
+            Hash::default(),
+            &dr_output,
+            creator,
+            vec![0x00],
+            reveals.clone(),
+            vec![true, false],
+        );
+
+        assert_eq!(tally.liars, vec![liar_pkh]);
+        assert_eq!(
+            tally.outputs,
+            vec![
+                ValueTransferOutput {
+                    pkh: honest_pkh,
+                    value: 500,
+                },
+                ValueTransferOutput {
+                    pkh: creator,
+                    value: 500,
+                },
+            ]
+        );
+        assert!(true_revealer(&reveals[0], &tally));
+        assert!(!true_revealer(&reveals[1], &tally));
+    }
 }
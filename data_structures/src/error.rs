@@ -3,7 +3,7 @@
 use failure::Fail;
 use std::num::ParseIntError;
 
-use crate::chain::{Epoch, Hash, HashParseError, OutputPointer, PublicKeyHash};
+use crate::chain::{Epoch, Hash, HashParseError, OutputPointer, PublicKeyHash, RADType};
 
 /// The error type for operations on a [`ChainInfo`](ChainInfo)
 #[derive(Debug, PartialEq, Fail)]
@@ -75,6 +75,14 @@ pub enum TransactionError {
         local_tally: Vec<u8>,
         miner_tally: Vec<u8>,
     },
+    #[fail(
+        display = "Mismatching between local liars ({:?}) and miner liars ({:?})",
+        local_liars, miner_liars
+    )]
+    MismatchedLiars {
+        local_liars: Vec<PublicKeyHash>,
+        miner_liars: Vec<PublicKeyHash>,
+    },
     #[fail(
         display = "Mismatching number of signatures ({}) and inputs ({})",
         signatures_n, inputs_n
@@ -305,4 +313,19 @@ pub enum DataRequestError {
     NotTallyStage,
     #[fail(display = "Cannot persist unfinished data request (with no Tally)")]
     UnfinishedDataRequest,
+    /// Only HTTP-POST sources can have a body or headers
+    #[fail(display = "A source of kind {:?} cannot have a body or headers", kind)]
+    UnexpectedRequestPayload { kind: RADType },
+    /// RNG sources do not fetch anything, so they cannot have a URL
+    #[fail(display = "A source of kind {:?} cannot have a URL", kind)]
+    UnexpectedUrl { kind: RADType },
+    /// The name or the value of a header of a source is not valid in HTTP
+    #[fail(display = "Invalid header `{}` in HTTP-POST source", name)]
+    InvalidHeader { name: String },
+    /// The URL of a TALLY source is not the hash of a data request
+    #[fail(
+        display = "The URL of a TALLY source is not the hash of a data request: `{}`",
+        url
+    )]
+    InvalidTallySource { url: String },
 }
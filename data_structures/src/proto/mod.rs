@@ -46,12 +46,18 @@ impl ProtobufConvert for chain::RADType {
     fn to_pb(&self) -> Self::ProtoStruct {
         match self {
             chain::RADType::HttpGet => witnet::DataRequestOutput_RADRequest_RADType::HttpGet,
+            chain::RADType::HttpPost => witnet::DataRequestOutput_RADRequest_RADType::HttpPost,
+            chain::RADType::Rng => witnet::DataRequestOutput_RADRequest_RADType::Rng,
+            chain::RADType::Tally => witnet::DataRequestOutput_RADRequest_RADType::Tally,
         }
     }
 
     fn from_pb(pb: Self::ProtoStruct) -> Result<Self, Error> {
         Ok(match pb {
             witnet::DataRequestOutput_RADRequest_RADType::HttpGet => chain::RADType::HttpGet,
+            witnet::DataRequestOutput_RADRequest_RADType::HttpPost => chain::RADType::HttpPost,
+            witnet::DataRequestOutput_RADRequest_RADType::Rng => chain::RADType::Rng,
+            witnet::DataRequestOutput_RADRequest_RADType::Tally => chain::RADType::Tally,
         })
     }
 }
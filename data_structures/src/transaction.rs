@@ -168,6 +168,11 @@ impl DRTransaction {
         DRTransaction { body, signatures }
     }
 
+    /// Returns the byte size that a transaction will have on the wire
+    pub fn size(&self) -> u32 {
+        self.to_pb().write_to_bytes().unwrap().len() as u32
+    }
+
     /// Creates a proof of inclusion.
     ///
     /// Returns None if the transaction is not included in this block.
@@ -242,6 +247,11 @@ impl CommitTransaction {
     pub fn new(body: CommitTransactionBody, signatures: Vec<KeyedSignature>) -> Self {
         CommitTransaction { body, signatures }
     }
+
+    /// Returns the byte size that a transaction will have on the wire
+    pub fn size(&self) -> u32 {
+        self.to_pb().write_to_bytes().unwrap().len() as u32
+    }
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Clone, Serialize, Deserialize, ProtobufConvert)]
@@ -286,6 +296,11 @@ impl RevealTransaction {
     pub fn new(body: RevealTransactionBody, signatures: Vec<KeyedSignature>) -> Self {
         RevealTransaction { body, signatures }
     }
+
+    /// Returns the byte size that a transaction will have on the wire
+    pub fn size(&self) -> u32 {
+        self.to_pb().write_to_bytes().unwrap().len() as u32
+    }
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Clone, Serialize, Deserialize, ProtobufConvert)]
@@ -322,6 +337,9 @@ pub struct TallyTransaction {
     // Outputs
     pub tally: Vec<u8>,
     pub outputs: Vec<ValueTransferOutput>, // Witness rewards
+    // Revealers whose reveals did not survive the consensus filters, sorted. The rest of
+    // revealers are considered honest.
+    pub liars: Vec<PublicKeyHash>,
 
     #[protobuf_convert(skip)]
     #[serde(skip)]
@@ -330,11 +348,17 @@ pub struct TallyTransaction {
 
 impl TallyTransaction {
     /// Creates a new tally transaction.
-    pub fn new(dr_pointer: Hash, tally: Vec<u8>, outputs: Vec<ValueTransferOutput>) -> Self {
+    pub fn new(
+        dr_pointer: Hash,
+        tally: Vec<u8>,
+        outputs: Vec<ValueTransferOutput>,
+        liars: Vec<PublicKeyHash>,
+    ) -> Self {
         TallyTransaction {
             dr_pointer,
             tally,
             outputs,
+            liars,
             hash: MemoHash::new(),
         }
     }
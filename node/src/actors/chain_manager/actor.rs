@@ -8,13 +8,14 @@ use super::{
 use crate::actors::{
     epoch_manager::{EpochManager, EpochManagerError::CheckpointZeroInTheFuture},
     messages::{GetEpoch, Subscribe},
-    storage_keys::CHAIN_STATE_KEY,
+    storage_keys::{CHAIN_STATE_KEY, CHAIN_STATE_VERSION_KEY},
 };
 use crate::config_mngr;
 use crate::signature_mngr;
 use crate::storage_mngr;
+use futures::future::{self, Either, Future};
 use witnet_data_structures::{
-    chain::{ChainInfo, ChainState, CheckpointBeacon, ReputationEngine},
+    chain::{ChainInfo, ChainState, CheckpointBeacon, ReputationEngine, CHAIN_STATE_VERSION},
     vrf::VrfCtx,
 };
 
@@ -58,7 +59,31 @@ impl ChainManager {
             let consensus_constants = (&config.consensus_constants).clone();
             act.max_block_weight = consensus_constants.max_block_weight;
 
-            storage_mngr::get::<_, ChainState>(&CHAIN_STATE_KEY)
+            storage_mngr::get::<_, u32>(&CHAIN_STATE_VERSION_KEY)
+                .and_then(|version| match version {
+                    Some(version) if version != CHAIN_STATE_VERSION => {
+                        warn!(
+                            "The chain state in storage was persisted with format version {}, \
+                             but this node uses version {}. Discarding it and initializing a new chain.",
+                            version, CHAIN_STATE_VERSION
+                        );
+                        Either::B(future::ok(None))
+                    }
+                    version => Either::A(
+                        storage_mngr::get::<_, ChainState>(&CHAIN_STATE_KEY).or_else(move |e| {
+                            if version.is_some() {
+                                return Err(e);
+                            }
+                            // Chain states persisted before the format was versioned cannot be read
+                            warn!(
+                                "The chain state in storage was persisted with an older format \
+                                 ({}). Discarding it and initializing a new chain.",
+                                e
+                            );
+                            Ok(None)
+                        }),
+                    ),
+                })
                 .into_actor(act)
                 .map_err(|e, _, _| error!("Error while getting chain state from storage: {}", e))
                 .and_then(move |chain_state_from_storage, act, _ctx| {
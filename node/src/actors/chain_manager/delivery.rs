@@ -0,0 +1,122 @@
+use actix::{Context, ContextFutureSpawner, System, WrapFuture};
+use futures::future::{join_all, Future};
+use log::{error, info};
+use serde::Serialize;
+
+use crate::{
+    actors::{
+        chain_manager::ChainManager, messages::RunDelivery, rad_manager::RadManager,
+        storage_keys::delivery_log_key,
+    },
+    signature_mngr, storage_mngr,
+};
+
+use witnet_crypto::hash::calculate_sha256;
+use witnet_data_structures::chain::{
+    DataRequestDelivery, DeliveryRecord, Hash, Hashable, KeyedSignature,
+};
+
+/// Data signed by a node to prove the result it delivers for a data request: the SHA256 of the
+/// data request pointer followed by the tally bytes
+struct DeliveryProof {
+    dr_pointer: Hash,
+    result: Vec<u8>,
+}
+
+impl Hashable for DeliveryProof {
+    fn hash(&self) -> Hash {
+        let Hash::SHA256(dr_pointer) = self.dr_pointer;
+        let data = [&dr_pointer[..], &self.result[..]].concat();
+
+        calculate_sha256(&data).into()
+    }
+}
+
+/// Body of the HTTP POST requests made to the endpoints of deliver clauses
+#[derive(Serialize)]
+struct DeliveryPayload {
+    /// Data request pointer, as an hexadecimal string
+    dr_pointer: String,
+    /// Result of the data request as found in its tally, as an hexadecimal string
+    result: String,
+    /// Signature of the node over the hash of the data request pointer followed by the result
+    proof: KeyedSignature,
+}
+
+impl ChainManager {
+    /// Deliver the result of a finished data request to the endpoints of its deliver clauses, and
+    /// persist the outcome of every delivery into the delivery log of the data request
+    pub fn deliver_data_request(&self, ctx: &mut Context<Self>, delivery: DataRequestDelivery) {
+        let DataRequestDelivery { deliver, report } = delivery;
+        let dr_pointer = report.tally.dr_pointer;
+        let result = report.tally.tally;
+
+        signature_mngr::sign(&DeliveryProof {
+            dr_pointer,
+            result: result.clone(),
+        })
+        .map_err(move |e| {
+            error!(
+                "Couldn't sign the result of data request {}: {}",
+                dr_pointer, e
+            )
+        })
+        .and_then(move |proof| {
+            let payload = DeliveryPayload {
+                dr_pointer: dr_pointer.to_string(),
+                result: hex::encode(&result),
+                proof,
+            };
+
+            serde_json::to_vec(&payload).map_err(|e| error!("Couldn't encode payload: {}", e))
+        })
+        .and_then(|payload| {
+            let rad_manager_addr = System::current().registry().get::<RadManager>();
+
+            join_all(deliver.into_iter().map(move |deliver| {
+                let url = deliver.url.clone();
+                rad_manager_addr
+                    .send(RunDelivery {
+                        deliver,
+                        payload: payload.clone(),
+                    })
+                    .then(move |result| {
+                        let record = match result {
+                            Ok(Ok(record)) => record,
+                            Ok(Err(())) => DeliveryRecord {
+                                url,
+                                attempts: 0,
+                                error: Some("The delivery could not be run".to_string()),
+                            },
+                            Err(e) => DeliveryRecord {
+                                url,
+                                attempts: 0,
+                                error: Some(e.to_string()),
+                            },
+                        };
+
+                        Ok::<_, ()>(record)
+                    })
+            }))
+        })
+        .and_then(move |records| {
+            for record in &records {
+                match &record.error {
+                    None => info!(
+                        "Delivered the result of data request {} to {}",
+                        dr_pointer, record.url
+                    ),
+                    Some(e) => error!(
+                        "Couldn't deliver the result of data request {} to {} after {} attempts: {}",
+                        dr_pointer, record.url, record.attempts, e
+                    ),
+                }
+            }
+
+            storage_mngr::put(&delivery_log_key(&dr_pointer), &records)
+                .map_err(|e| error!("Failed to persist delivery log into storage: {}", e))
+        })
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
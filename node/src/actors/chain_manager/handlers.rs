@@ -7,18 +7,20 @@ use witnet_data_structures::{
     transaction::{DRTransaction, Transaction, VTTransaction},
 };
 use witnet_validations::validations::{
-    validate_block, validate_commit_transaction, validate_dr_transaction,
-    validate_reveal_transaction, validate_vt_transaction, UtxoDiff,
+    dr_transaction_fee, validate_block, validate_commit_transaction, validate_dr_transaction,
+    validate_reveal_transaction, validate_vt_transaction, vt_transaction_fee, UtxoDiff,
 };
 
 use super::{ChainManager, ChainManagerError, StateMachine};
 use crate::{
     actors::{
         chain_manager::transaction_factory,
+        json_rpc::JsonRpcServer,
         messages::{
             AddBlocks, AddCandidates, AddTransaction, Anycast, Broadcast, BuildDrt, BuildVtt,
-            EpochNotification, GetBlocksEpochRange, GetHighestCheckpointBeacon, PeersBeacons,
-            SendLastBeacon, SessionUnitResult,
+            DataRequestStatus, EpochNotification, GetBlocksEpochRange, GetDataRequestState,
+            GetDataRequestsByEpoch, GetHighestCheckpointBeacon, GetMempool, GetMempoolTransaction,
+            Mempool, MempoolEntry, NewTransaction, PeersBeacons, SendLastBeacon, SessionUnitResult,
         },
         sessions_manager::SessionsManager,
     },
@@ -441,7 +443,12 @@ impl Handler<AddTransaction> for ChainManager {
                 self.broadcast_item(InventoryItem::Transaction(msg.transaction.clone()));
 
                 // Add valid transaction to transactions_pool
-                self.transactions_pool.insert(msg.transaction);
+                self.transactions_pool.insert(msg.transaction.clone());
+
+                // Send notification to JsonRpcServer
+                JsonRpcServer::from_registry().do_send(NewTransaction {
+                    transaction: msg.transaction,
+                })
             }
 
             Err(e) => log::warn!("{}", e),
@@ -480,6 +487,129 @@ impl Handler<GetBlocksEpochRange> for ChainManager {
     }
 }
 
+/// Handler for GetDataRequestState
+impl Handler<GetDataRequestState> for ChainManager {
+    type Result = MessageResult<GetDataRequestState>;
+
+    fn handle(
+        &mut self,
+        GetDataRequestState { dr_pointer }: GetDataRequestState,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        MessageResult(
+            self.chain_state
+                .data_request_pool
+                .data_request_state(&dr_pointer)
+                .map(|dr_state| {
+                    let mut witnesses: Vec<PublicKeyHash> =
+                        dr_state.info.commits.keys().cloned().collect();
+                    witnesses.sort();
+
+                    DataRequestStatus {
+                        stage: dr_state.stage,
+                        epoch: dr_state.epoch,
+                        commits: dr_state.info.commits.len(),
+                        reveals: dr_state.info.reveals.len(),
+                        witnesses,
+                    }
+                }),
+        )
+    }
+}
+
+/// Handler for GetDataRequestsByEpoch
+impl Handler<GetDataRequestsByEpoch> for ChainManager {
+    type Result = MessageResult<GetDataRequestsByEpoch>;
+
+    fn handle(
+        &mut self,
+        GetDataRequestsByEpoch { epoch }: GetDataRequestsByEpoch,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        let mut dr_pointers = self
+            .chain_state
+            .data_request_pool
+            .get_dr_output_pointers_by_epoch(epoch);
+        dr_pointers.sort();
+
+        MessageResult(dr_pointers)
+    }
+}
+
+/// Handler for GetMempool
+impl Handler<GetMempool> for ChainManager {
+    type Result = MessageResult<GetMempool>;
+
+    fn handle(&mut self, _msg: GetMempool, _ctx: &mut Context<Self>) -> Self::Result {
+        let utxo_diff = UtxoDiff::new(&self.chain_state.unspent_outputs_pool);
+        let dr_pool = &self.chain_state.data_request_pool;
+        let sorted = |mut entries: Vec<MempoolEntry>| {
+            entries.sort_by(|a, b| a.hash.cmp(&b.hash));
+            entries
+        };
+
+        MessageResult(Mempool {
+            value_transfer: sorted(
+                self.transactions_pool
+                    .vt_iter()
+                    .map(|tx| MempoolEntry {
+                        hash: tx.hash(),
+                        fee: vt_transaction_fee(tx, &utxo_diff).ok(),
+                        weight: tx.size(),
+                    })
+                    .collect(),
+            ),
+            data_request: sorted(
+                self.transactions_pool
+                    .dr_iter()
+                    .map(|tx| MempoolEntry {
+                        hash: tx.hash(),
+                        fee: dr_transaction_fee(tx, &utxo_diff).ok(),
+                        weight: tx.size(),
+                    })
+                    .collect(),
+            ),
+            commit: sorted(
+                self.transactions_pool
+                    .co_iter()
+                    .map(|tx| MempoolEntry {
+                        hash: tx.hash(),
+                        fee: dr_pool
+                            .get_dr_output(&tx.body.dr_pointer)
+                            .map(|dr_output| dr_output.commit_fee),
+                        weight: tx.size(),
+                    })
+                    .collect(),
+            ),
+            reveal: sorted(
+                self.transactions_pool
+                    .re_iter()
+                    .map(|tx| MempoolEntry {
+                        hash: tx.hash(),
+                        fee: dr_pool
+                            .get_dr_output(&tx.body.dr_pointer)
+                            .map(|dr_output| dr_output.reveal_fee),
+                        weight: tx.size(),
+                    })
+                    .collect(),
+            ),
+        })
+    }
+}
+
+/// Handler for GetMempoolTransaction
+impl Handler<GetMempoolTransaction> for ChainManager {
+    type Result = MessageResult<GetMempoolTransaction>;
+
+    fn handle(
+        &mut self,
+        GetMempoolTransaction { hash }: GetMempoolTransaction,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        MessageResult(self.transactions_pool.get(&hash))
+    }
+}
+
 impl Handler<PeersBeacons> for ChainManager {
     type Result = <PeersBeacons as Message>::Result;
 
@@ -5,7 +5,7 @@ use ansi_term::Color::{White, Yellow};
 use log::{debug, error, info, warn};
 
 use futures::future::{join_all, Future};
-use std::{convert::TryFrom, time::Duration};
+use std::{collections::HashMap, convert::TryFrom, time::Duration};
 
 use crate::{
     actors::{
@@ -15,13 +15,14 @@ use crate::{
         },
         rad_manager::RadManager,
     },
-    signature_mngr,
+    signature_mngr, storage_mngr,
 };
 
 use witnet_data_structures::{
     chain::{
-        Block, BlockHeader, BlockMerkleRoots, BlockTransactions, CheckpointBeacon, Hashable,
-        PublicKeyHash, TransactionsPool, UnspentOutputsPool, ValueTransferOutput,
+        Block, BlockHeader, BlockMerkleRoots, BlockTransactions, CheckpointBeacon,
+        DataRequestReport, Hash, Hashable, PublicKeyHash, RADRequest, TransactionsPool,
+        UnspentOutputsPool, ValueTransferOutput,
     },
     data_request::{create_tally, DataRequestPool},
     transaction::{
@@ -30,7 +31,7 @@ use witnet_data_structures::{
     },
     vrf::{BlockEligibilityClaim, DataRequestEligibilityClaim, VrfMessage},
 };
-use witnet_rad::types::RadonTypes;
+use witnet_rad::{self as rad, types::RadonTypes, RetrievalContext};
 use witnet_validations::validations::{
     block_reward, calculate_randpoe_threshold, calculate_reppoe_threshold, dr_transaction_fee,
     merkle_tree_root, update_utxo_diff, validate_block, vt_transaction_fee, UtxoDiff,
@@ -222,13 +223,16 @@ impl ChainManager {
             my_reputation.0, total_active_reputation.0, num_active_identities,
         );
 
-        for (dr_pointer, data_request_output) in dr_pointers.into_iter().filter_map(|dr_pointer| {
-            // Filter data requests that are not in data_request_pool
-            self.chain_state
-                .data_request_pool
-                .get_dr_output(&dr_pointer)
-                .map(|data_request_output| (dr_pointer, data_request_output))
-        }) {
+        for (dr_pointer, data_request_output, rng_seed) in
+            dr_pointers.into_iter().filter_map(|dr_pointer| {
+                // Filter data requests that are not in data_request_pool
+                let data_request_pool = &self.chain_state.data_request_pool;
+                let data_request_output = data_request_pool.get_dr_output(&dr_pointer)?;
+                let rng_seed = data_request_rng_seed(data_request_pool, dr_pointer)?;
+
+                Some((dr_pointer, data_request_output, rng_seed))
+            })
+        {
             let num_witnesses =
                 data_request_output.witnesses + data_request_output.backup_witnesses;
             // The beacon used to create and verify data requests must be set to the current epoch
@@ -273,18 +277,30 @@ impl ChainManager {
                 .and_then(move |vrf_proof| {
                     let rad_request = data_request_output.data_request.clone();
 
-                    // Send ResolveRA message to RADManager
-                    let rad_manager_addr = System::current().registry().get::<RadManager>();
-                    rad_manager_addr
-                        .send(ResolveRA { rad_request })
-                        .map(|result| match result {
-                            Ok(value) => Ok((vrf_proof, value)),
-                            Err(e) => {
-                                log::error!("Couldn't resolve rad request: {}", e);
-                                Err(())
-                            }
-                        })
-                        .map_err(|e| log::error!("Couldn't resolve rad request: {}", e))
+                    read_tallies(&rad_request).and_then(move |tallies| {
+                        let context = RetrievalContext {
+                            rng_seed: Some(rng_seed),
+                            tallies,
+                            // The limits are those configured in the `RadManager`
+                            ..RetrievalContext::default()
+                        };
+
+                        // Send ResolveRA message to RADManager
+                        let rad_manager_addr = System::current().registry().get::<RadManager>();
+                        rad_manager_addr
+                            .send(ResolveRA {
+                                rad_request,
+                                context,
+                            })
+                            .map(|result| match result {
+                                Ok(value) => Ok((vrf_proof, value)),
+                                Err(e) => {
+                                    log::error!("Couldn't resolve rad request: {}", e);
+                                    Err(())
+                                }
+                            })
+                            .map_err(|e| log::error!("Couldn't resolve rad request: {}", e))
+                    })
                 })
                 .flatten()
                 .and_then(move |(vrf_proof, reveal_value)| {
@@ -385,13 +401,14 @@ impl ChainManager {
                                 futures::future::err(())
                             }
                         })
-                        .and_then(move |consensus| {
+                        .and_then(move |(consensus, honest)| {
                             let tally = create_tally(
                                 dr_pointer,
                                 &dr_state.data_request,
                                 dr_state.pkh,
                                 consensus.clone(),
                                 reveals,
+                                honest,
                             );
 
                             let print_results: Vec<_> = results
@@ -574,6 +591,37 @@ fn build_block(
     (block_header, txns)
 }
 
+/// Seed of the `RNG` sources of a data request. It is derived from the beacon of the block that
+/// included the data request, so that it does not depend on the epoch in which each witness
+/// commits to it.
+fn data_request_rng_seed(data_request_pool: &DataRequestPool, dr_pointer: Hash) -> Option<Hash> {
+    data_request_pool
+        .get_dr_beacon(&dr_pointer)
+        .map(|beacon| rad::rng_seed(beacon, dr_pointer))
+}
+
+/// Read from the storage the tallies of the finalized data requests that are read by the `TALLY`
+/// sources of a RAD request. Those that cannot be read are left out, so those sources will fail.
+fn read_tallies(
+    rad_request: &RADRequest,
+) -> impl Future<Item = HashMap<Hash, Vec<u8>>, Error = ()> {
+    let reports = rad::tally_sources(rad_request)
+        .into_iter()
+        .map(|dr_pointer| {
+            storage_mngr::get::<_, DataRequestReport>(&dr_pointer).then(
+                move |result| match result {
+                    Ok(report) => Ok(report.map(|report| (dr_pointer, report.tally.tally))),
+                    Err(e) => {
+                        log::error!("Couldn't read report of data request {}: {}", dr_pointer, e);
+                        Ok(None)
+                    }
+                },
+            )
+        });
+
+    join_all(reports).map(|tallies| tallies.into_iter().flatten().collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -847,4 +895,39 @@ mod tests {
 
         assert!(verify(&public_key2, &data, &signature2).is_ok());
     }
+
+    #[test]
+    fn rng_seed_does_not_depend_on_the_mining_epoch() {
+        let inclusion_block_hash = Hash::SHA256([1; 32]);
+        let dr_transaction = DRTransaction::new(
+            DRTransactionBody::new(vec![Input::default()], vec![], DataRequestOutput::default()),
+            vec![KeyedSignature::default()],
+        );
+        let dr_pointer = dr_transaction.hash();
+
+        // The data request is included in a block of epoch 3
+        let mut data_request_pool = DataRequestPool::default();
+        data_request_pool
+            .process_data_request(&dr_transaction, 3, &inclusion_block_hash)
+            .unwrap();
+        let seed_at_epoch_4 = data_request_rng_seed(&data_request_pool, dr_pointer).unwrap();
+
+        // A block of epoch 7 is consolidated before mining again
+        let other_dr_transaction = DRTransaction::new(
+            DRTransactionBody::new(vec![], vec![], DataRequestOutput::default()),
+            vec![KeyedSignature::default()],
+        );
+        data_request_pool
+            .process_data_request(&other_dr_transaction, 7, &Hash::SHA256([2; 32]))
+            .unwrap();
+        data_request_pool.update_data_request_stages();
+        let seed_at_epoch_8 = data_request_rng_seed(&data_request_pool, dr_pointer).unwrap();
+
+        let inclusion_beacon = CheckpointBeacon {
+            checkpoint: 3,
+            hash_prev_block: inclusion_block_hash,
+        };
+        assert_eq!(seed_at_epoch_4, seed_at_epoch_8);
+        assert_eq!(seed_at_epoch_4, rad::rng_seed(inclusion_beacon, dr_pointer));
+    }
 }
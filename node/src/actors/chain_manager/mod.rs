@@ -36,6 +36,7 @@ use actix::{
 };
 use ansi_term::Color::{Purple, White, Yellow};
 use failure::Fail;
+use futures::Future;
 use itertools::Itertools;
 use log::{debug, error, info, warn};
 
@@ -45,7 +46,7 @@ use crate::{
         json_rpc::JsonRpcServer,
         messages::{AddItem, AddTransaction, Broadcast, NewBlock, SendInventoryItem},
         sessions_manager::SessionsManager,
-        storage_keys::CHAIN_STATE_KEY,
+        storage_keys::{CHAIN_STATE_KEY, CHAIN_STATE_VERSION_KEY},
     },
     storage_mngr,
 };
@@ -54,6 +55,7 @@ use witnet_data_structures::{
         penalize_factor, reputation_issuance, Alpha, Block, ChainState, CheckpointBeacon,
         ConsensusConstants, DataRequestReport, Epoch, Hash, Hashable, InventoryItem, OutputPointer,
         PublicKeyHash, Reputation, ReputationEngine, TransactionsPool, UnspentOutputsPool,
+        CHAIN_STATE_VERSION,
     },
     data_request::{true_revealer, DataRequestPool},
     transaction::{TallyTransaction, Transaction},
@@ -63,6 +65,7 @@ use witnet_rad::types::RadonTypes;
 use witnet_validations::validations::{validate_block, validate_candidate, Diff};
 
 mod actor;
+mod delivery;
 mod handlers;
 mod mining;
 /// High level transaction factory
@@ -154,7 +157,8 @@ impl ChainManager {
             }
         };
 
-        storage_mngr::put(&CHAIN_STATE_KEY, &self.last_chain_state)
+        storage_mngr::put(&CHAIN_STATE_VERSION_KEY, &CHAIN_STATE_VERSION)
+            .join(storage_mngr::put(&CHAIN_STATE_KEY, &self.last_chain_state))
             .into_actor(self)
             .and_then(|_, _, _| {
                 debug!("Successfully persisted chain_info into storage");
@@ -361,6 +365,9 @@ impl ChainManager {
                             .chain_state
                             .data_request_pool
                             .update_data_request_stages();
+
+                        // Results of old data requests are not delivered again
+                        let _deliveries = self.chain_state.data_request_pool.finished_deliveries();
                     }
                     StateMachine::Synced => {
                         // Persist finished data requests into storage
@@ -371,6 +378,20 @@ impl ChainManager {
                             self.persist_data_request(ctx, &dr_report);
                         });
 
+                        // Deliver the results of the finished data requests revealed by this node
+                        let to_be_delivered =
+                            self.chain_state.data_request_pool.finished_deliveries();
+                        for delivery in to_be_delivered {
+                            let revealed = delivery
+                                .report
+                                .reveals
+                                .iter()
+                                .any(|reveal| Some(reveal.body.pkh) == self.own_pkh);
+                            if revealed {
+                                self.deliver_data_request(ctx, delivery);
+                            }
+                        }
+
                         log::trace!("{:?}", block);
                         debug!("Mint transaction hash: {:?}", block.txns.mint.hash());
 
@@ -445,10 +466,8 @@ impl ReputationInfo {
         let replication_factor = dr_state.data_request.witnesses;
         self.alpha_diff += Alpha(u32::from(replication_factor));
 
-        let tally = &tally_transaction.tally;
-
         for (pkh, reveal_tx) in reveals {
-            let liar = if true_revealer(reveal_tx, tally) {
+            let liar = if true_revealer(reveal_tx, tally_transaction) {
                 0
             } else {
                 1
@@ -487,9 +506,11 @@ fn update_pools(
     }
 
     for dr_tx in &block.txns.data_request_txns {
-        if let Err(e) =
-            data_request_pool.process_data_request(&dr_tx, block.block_header.beacon.checkpoint)
-        {
+        if let Err(e) = data_request_pool.process_data_request(
+            &dr_tx,
+            block.block_header.beacon.checkpoint,
+            &block.hash(),
+        ) {
             log::error!("Error processing data request transaction:\n{}", e);
         } else {
             transactions_pool.dr_remove(&dr_tx.hash());
@@ -12,15 +12,20 @@ use jsonrpc_pubsub::{PubSubHandler, Session, Subscriber, SubscriptionId};
 use log::{debug, error, info};
 use serde::{Deserialize, Serialize};
 
-use witnet_data_structures::chain::{self, Block, Hash};
-
-use crate::actors::{
-    chain_manager::{ChainManager, ChainManagerError},
-    epoch_manager::EpochManager,
-    inventory_manager::InventoryManager,
-    messages::{
-        AddCandidates, AddTransaction, BuildDrt, BuildVtt, GetBlocksEpochRange, GetEpoch, GetItem,
+use witnet_data_structures::chain::{self, Block, DataRequestReport, Epoch, Hash};
+
+use crate::{
+    actors::{
+        chain_manager::{ChainManager, ChainManagerError},
+        epoch_manager::EpochManager,
+        inventory_manager::InventoryManager,
+        messages::{
+            AddCandidates, AddTransaction, BuildDrt, BuildVtt, GetBlocksEpochRange,
+            GetDataRequestState, GetDataRequestsByEpoch, GetEpoch, GetItem, GetMempool,
+            GetMempoolTransaction,
+        },
     },
+    storage_mngr,
 };
 
 //use std::str::FromStr;
@@ -43,6 +48,21 @@ pub fn jsonrpc_io_handler(subscriptions: Subscriptions) -> PubSubHandler<Arc<Ses
         get_block_chain(params.parse())
     });
     io.add_method("getBlock", |params: Params| get_block(params.parse()));
+    io.add_method("dataRequestReport", |params: Params| {
+        data_request_report(params.parse())
+    });
+    io.add_method("dataRequestState", |params: Params| {
+        data_request_state(params.parse())
+    });
+    io.add_method("dataRequestsByEpoch", |params: Params| {
+        data_requests_by_epoch(params.parse())
+    });
+    io.add_method("getMempool", |params: Params| {
+        get_mempool(params.expect_no_params())
+    });
+    io.add_method("getMempoolTransaction", |params: Params| {
+        get_mempool_transaction(params.parse())
+    });
     //io.add_method("getOutput", |params: Params| get_output(params.parse()));
     io.add_method("buildDataRequest", |params: Params| {
         build_data_request(params.parse()?)
@@ -110,6 +130,10 @@ pub fn jsonrpc_io_handler(subscriptions: Subscriptions) -> PubSubHandler<Arc<Ses
                         debug!("New subscription to newBlocks");
                         add_subscription("newBlocks", subscriber);
                     }
+                    "newTransactions" => {
+                        debug!("New subscription to newTransactions");
+                        add_subscription("newTransactions", subscriber);
+                    }
                     e => {
                         debug!("Unknown subscription method: {}", e);
                         // Ignore errors with `.ok()` because an error here means the connection was closed
@@ -371,6 +395,104 @@ pub fn get_block(hash: Result<(Hash,), jsonrpc_core::Error>) -> JsonRpcResultAsy
     )
 }
 
+/// Serialize the result of a query into a JSON value, or convert its error into a JSON-RPC error
+fn query_result<T, E>(
+    res: Result<T, E>,
+) -> futures::future::FutureResult<Value, jsonrpc_core::Error>
+where
+    T: Serialize,
+    E: std::fmt::Debug,
+{
+    let value = res
+        .map_err(internal_error)
+        .and_then(|x| serde_json::to_value(x).map_err(internal_error));
+
+    futures::future::result(value)
+}
+
+/// Get the report of a resolved data request, as persisted into the storage, or `null` if the
+/// data request has not been resolved yet
+/* test
+{"jsonrpc":"2.0","id":1,"method":"dataRequestReport","params":["c0002c6b25615c0f71069f159dffddf8a0b3e529efb054402f0649e969715bdb"]}
+*/
+pub fn data_request_report(hash: Result<(Hash,), jsonrpc_core::Error>) -> JsonRpcResultAsync {
+    let dr_pointer = match hash {
+        Ok(x) => x.0,
+        Err(e) => return Box::new(futures::failed(e)),
+    };
+
+    Box::new(storage_mngr::get::<_, DataRequestReport>(&dr_pointer).then(query_result))
+}
+
+/// Get the stage, the number of commitments and reveals, and the witnesses of a data request
+/// that has not been resolved yet, or `null` if it is unknown or already resolved
+/* test
+{"jsonrpc":"2.0","id":1,"method":"dataRequestState","params":["c0002c6b25615c0f71069f159dffddf8a0b3e529efb054402f0649e969715bdb"]}
+*/
+pub fn data_request_state(hash: Result<(Hash,), jsonrpc_core::Error>) -> JsonRpcResultAsync {
+    let dr_pointer = match hash {
+        Ok(x) => x.0,
+        Err(e) => return Box::new(futures::failed(e)),
+    };
+
+    Box::new(
+        ChainManager::from_registry()
+            .send(GetDataRequestState { dr_pointer })
+            .then(query_result),
+    )
+}
+
+/// Get the pointers of the data requests unlocked up to an epoch that are still accepting
+/// commitments
+/* test
+{"jsonrpc":"2.0","id":1,"method":"dataRequestsByEpoch","params":[100]}
+*/
+pub fn data_requests_by_epoch(epoch: Result<(Epoch,), jsonrpc_core::Error>) -> JsonRpcResultAsync {
+    let epoch = match epoch {
+        Ok(x) => x.0,
+        Err(e) => return Box::new(futures::failed(e)),
+    };
+
+    Box::new(
+        ChainManager::from_registry()
+            .send(GetDataRequestsByEpoch { epoch })
+            .then(query_result),
+    )
+}
+
+/// Get the hashes, fees and weights of the transactions waiting in the mempool, grouped by type
+/* test
+{"jsonrpc":"2.0","id":1,"method":"getMempool"}
+*/
+pub fn get_mempool(params: Result<(), jsonrpc_core::Error>) -> JsonRpcResultAsync {
+    if let Err(e) = params {
+        return Box::new(futures::failed(e));
+    }
+
+    Box::new(
+        ChainManager::from_registry()
+            .send(GetMempool)
+            .then(query_result),
+    )
+}
+
+/// Get a transaction waiting in the mempool, or `null` if it is not there
+/* test
+{"jsonrpc":"2.0","id":1,"method":"getMempoolTransaction","params":["c0002c6b25615c0f71069f159dffddf8a0b3e529efb054402f0649e969715bdb"]}
+*/
+pub fn get_mempool_transaction(hash: Result<(Hash,), jsonrpc_core::Error>) -> JsonRpcResultAsync {
+    let hash = match hash {
+        Ok(x) => x.0,
+        Err(e) => return Box::new(futures::failed(e)),
+    };
+
+    Box::new(
+        ChainManager::from_registry()
+            .send(GetMempoolTransaction { hash })
+            .then(query_result),
+    )
+}
+
 /*
 /// get output
 pub fn get_output(output_pointer: Result<(String,), jsonrpc_core::Error>) -> JsonRpcResultAsync {
@@ -537,6 +659,91 @@ mod tests {
         assert_eq!(response, Some(expected));
     }
 
+    #[test]
+    fn data_request_methods_invalid_params() {
+        // The data request methods reject malformed hashes and epochs before querying anything
+        let subscriptions = Subscriptions::default();
+        let (transport_sender, _transport_receiver) = mpsc::channel(0);
+        let meta = Arc::new(Session::new(transport_sender));
+        let io = jsonrpc_io_handler(subscriptions);
+        let expected = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid params"#;
+
+        for msg in &[
+            r#"{"jsonrpc":"2.0","method":"dataRequestReport","params":["abc"],"id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"dataRequestState","params":[],"id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"dataRequestsByEpoch","params":[-1],"id":1}"#,
+        ] {
+            let response = io.handle_request_sync(msg, meta.clone());
+            // Compare only the first N characters
+            let response =
+                response.map(|s| s.chars().take(expected.chars().count()).collect::<String>());
+            assert_eq!(response, Some(expected.to_string()), "{}", msg);
+        }
+    }
+
+    #[test]
+    fn serialize_data_request_status() {
+        // Check that the serialization of `DataRequestStatus` doesn't change
+        use crate::actors::messages::DataRequestStatus;
+        use witnet_data_structures::chain::{DataRequestStage, PublicKeyHash};
+
+        let status = DataRequestStatus {
+            stage: DataRequestStage::REVEAL,
+            epoch: 7,
+            commits: 2,
+            reveals: 1,
+            witnesses: vec![PublicKeyHash::default()],
+        };
+        let s = serde_json::to_string(&status).unwrap();
+        let expected = r#"{"stage":"REVEAL","epoch":7,"commits":2,"reveals":1,"witnesses":["0000000000000000000000000000000000000000"]}"#;
+        assert_eq!(s, expected, "\n{}\n", s);
+    }
+
+    #[test]
+    fn mempool_methods_invalid_params() {
+        // The mempool methods reject unexpected params and malformed hashes
+        let subscriptions = Subscriptions::default();
+        let (transport_sender, _transport_receiver) = mpsc::channel(0);
+        let meta = Arc::new(Session::new(transport_sender));
+        let io = jsonrpc_io_handler(subscriptions);
+        let expected = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"#;
+
+        for msg in &[
+            r#"{"jsonrpc":"2.0","method":"getMempool","params":[1],"id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"getMempoolTransaction","params":["abc"],"id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"getMempoolTransaction","params":[],"id":1}"#,
+        ] {
+            let response = io.handle_request_sync(msg, meta.clone());
+            // Compare only the first N characters
+            let response =
+                response.map(|s| s.chars().take(expected.chars().count()).collect::<String>());
+            assert_eq!(response, Some(expected.to_string()), "{}", msg);
+        }
+    }
+
+    #[test]
+    fn serialize_mempool() {
+        // Check that the serialization of `Mempool` doesn't change
+        use crate::actors::messages::{Mempool, MempoolEntry};
+
+        let mempool = Mempool {
+            value_transfer: vec![MempoolEntry {
+                hash: Hash::default(),
+                fee: Some(10),
+                weight: 120,
+            }],
+            reveal: vec![MempoolEntry {
+                hash: Hash::default(),
+                fee: None,
+                weight: 80,
+            }],
+            ..Mempool::default()
+        };
+        let s = serde_json::to_string(&mempool).unwrap();
+        let expected = r#"{"value_transfer":[{"hash":"0000000000000000000000000000000000000000000000000000000000000000","fee":10,"weight":120}],"data_request":[],"commit":[],"reveal":[{"hash":"0000000000000000000000000000000000000000000000000000000000000000","fee":null,"weight":80}]}"#;
+        assert_eq!(s, expected, "\n{}\n", s);
+    }
+
     #[test]
     fn subscribe_invalid_method() {
         // Try to subscribe to a non-existent subscription?
@@ -565,6 +772,20 @@ mod tests {
         assert_eq!(response, Some(expected));
     }
 
+    #[test]
+    fn subscribe_new_transactions() {
+        // Subscribe to new transactions gives us a SubscriptionId
+        let msg =
+            r#"{"jsonrpc":"2.0","method":"witnet_subscribe","params":["newTransactions"],"id":1}"#;
+        let expected = r#"{"jsonrpc":"2.0","result":"1","id":1}"#.to_string();
+        let subscriptions = Subscriptions::default();
+        let (transport_sender, _transport_receiver) = mpsc::channel(0);
+        let meta = Arc::new(Session::new(transport_sender));
+        let io = jsonrpc_io_handler(subscriptions);
+        let response = io.handle_request_sync(&msg, meta);
+        assert_eq!(response, Some(expected));
+    }
+
     #[test]
     fn unsubscribe_returns_true() {
         // Check that unsubscribe returns true
@@ -614,12 +835,14 @@ mod tests {
             kind: RADType::HttpGet,
             url: "https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22".to_string(),
             script: vec![0],
+            ..RADRetrieve::default()
         };
 
         let rad_retrieve_2 = RADRetrieve {
             kind: RADType::HttpGet,
             url: "https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22".to_string(),
             script: vec![0],
+            ..RADRetrieve::default()
         };
 
         let rad_consensus = RADConsensus { script: vec![0] };
@@ -646,7 +869,7 @@ mod tests {
 
         let inv_elem = InventoryItem::Transaction(transaction);
         let s = serde_json::to_string(&inv_elem).unwrap();
-        let expected = r#"{"transaction":{"DataRequest":{"body":{"inputs":[{"output_pointer":"0909090909090909090909090909090909090909090909090909090909090909:0"}],"outputs":[],"dr_output":{"data_request":{"not_before":0,"retrieve":[{"kind":"HTTP-GET","url":"https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22","script":[0]},{"kind":"HTTP-GET","url":"https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22","script":[0]}],"aggregate":{"script":[0]},"consensus":{"script":[0]},"deliver":[{"kind":"HTTP-GET","url":"https://hooks.zapier.com/hooks/catch/3860543/l2awcd/"},{"kind":"HTTP-GET","url":"https://hooks.zapier.com/hooks/catch/3860543/l1awcw/"}]},"value":0,"witnesses":0,"backup_witnesses":0,"commit_fee":0,"reveal_fee":0,"tally_fee":0,"time_lock":0}},"signatures":[{"signature":{"Secp256k1":{"der":[]}},"public_key":{"compressed":0,"bytes":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}}]}}}"#;
+        let expected = r#"{"transaction":{"DataRequest":{"body":{"inputs":[{"output_pointer":"0909090909090909090909090909090909090909090909090909090909090909:0"}],"outputs":[],"dr_output":{"data_request":{"not_before":0,"retrieve":[{"kind":"HTTP-GET","url":"https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22","script":[0],"body":[],"headers":[]},{"kind":"HTTP-GET","url":"https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22","script":[0],"body":[],"headers":[]}],"aggregate":{"script":[0]},"consensus":{"script":[0]},"deliver":[{"kind":"HTTP-GET","url":"https://hooks.zapier.com/hooks/catch/3860543/l2awcd/"},{"kind":"HTTP-GET","url":"https://hooks.zapier.com/hooks/catch/3860543/l1awcw/"}]},"value":0,"witnesses":0,"backup_witnesses":0,"commit_fee":0,"reveal_fee":0,"tally_fee":0,"time_lock":0}},"signatures":[{"signature":{"Secp256k1":{"der":[]}},"public_key":{"compressed":0,"bytes":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}}]}}}"#;
         assert_eq!(s, expected, "\n{}\n", s);
     }
 
@@ -18,7 +18,7 @@ use super::{
     SubscriptionResult, Subscriptions,
 };
 use crate::{
-    actors::messages::{InboundTcpConnect, NewBlock},
+    actors::messages::{InboundTcpConnect, NewBlock, NewTransaction},
     config_mngr,
 };
 use jsonrpc_pubsub::{PubSubHandler, Session};
@@ -160,20 +160,22 @@ impl Handler<Unregister> for JsonRpcServer {
     }
 }
 
-impl Handler<NewBlock> for JsonRpcServer {
-    type Result = ();
-
-    fn handle(&mut self, msg: NewBlock, ctx: &mut Self::Context) -> Self::Result {
-        debug!("Got NewBlock message, sending notifications...");
-        let block = serde_json::to_value(msg.block).unwrap();
+impl JsonRpcServer {
+    /// Send a notification with `value` to every subscriber of `method`
+    fn notify_subscribers(
+        &mut self,
+        method: &str,
+        value: serde_json::Value,
+        ctx: &mut Context<Self>,
+    ) {
         if let Ok(subs) = self.subscriptions.lock() {
             let empty_map = HashMap::new();
             for (subscription, (sink, _subscription_params)) in
-                subs.get("newBlocks").unwrap_or(&empty_map)
+                subs.get(method).unwrap_or(&empty_map)
             {
-                debug!("Sending NewBlock notification!");
+                debug!("Sending {} notification!", method);
                 let r = SubscriptionResult {
-                    result: block.clone(),
+                    result: value.clone(),
                     subscription: subscription.clone(),
                 };
                 ctx.spawn(
@@ -183,7 +185,27 @@ impl Handler<NewBlock> for JsonRpcServer {
                 );
             }
         } else {
-            error!("Failed to adquire lock in NewBlock handle");
+            error!("Failed to adquire lock in {} notification", method);
         }
     }
 }
+
+impl Handler<NewBlock> for JsonRpcServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: NewBlock, ctx: &mut Self::Context) -> Self::Result {
+        debug!("Got NewBlock message, sending notifications...");
+        let block = serde_json::to_value(msg.block).unwrap();
+        self.notify_subscribers("newBlocks", block, ctx);
+    }
+}
+
+impl Handler<NewTransaction> for JsonRpcServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: NewTransaction, ctx: &mut Self::Context) -> Self::Result {
+        debug!("Got NewTransaction message, sending notifications...");
+        let transaction = serde_json::to_value(msg.transaction).unwrap();
+        self.notify_subscribers("newTransactions", transaction, ctx);
+    }
+}
@@ -12,13 +12,14 @@ use tokio::net::TcpStream;
 
 use witnet_data_structures::{
     chain::{
-        Block, CheckpointBeacon, DataRequestOutput, Epoch, Hash, InventoryEntry, InventoryItem,
-        RADConsensus, RADRequest, ValueTransferOutput,
+        Block, CheckpointBeacon, DataRequestOutput, DataRequestStage, DeliveryRecord, Epoch, Hash,
+        InventoryEntry, InventoryItem, PublicKeyHash, RADConsensus, RADDeliver, RADRequest,
+        ValueTransferOutput,
     },
     transaction::Transaction,
 };
 use witnet_p2p::sessions::{SessionStatus, SessionType};
-use witnet_rad::error::RadError;
+use witnet_rad::{error::RadError, RetrievalContext};
 
 use super::{
     chain_manager::{ChainManagerError, MAX_BLOCKS_SYNC},
@@ -130,6 +131,87 @@ impl Message for GetBlocksEpochRange {
     type Result = Result<Vec<(Epoch, Hash)>, ChainManagerError>;
 }
 
+/// Message to obtain the progress of a data request that has not been resolved yet
+pub struct GetDataRequestState {
+    /// Data request pointer
+    pub dr_pointer: Hash,
+}
+
+/// Progress of a data request that has not been resolved yet
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DataRequestStatus {
+    /// Current stage of the data request
+    pub stage: DataRequestStage,
+    /// Epoch on which the data request was or will be unlocked
+    pub epoch: Epoch,
+    /// Number of commitments included in blocks
+    pub commits: usize,
+    /// Number of reveals included in blocks
+    pub reveals: usize,
+    /// Witnesses that committed to the data request, sorted
+    pub witnesses: Vec<PublicKeyHash>,
+}
+
+impl Message for GetDataRequestState {
+    /// `None` if the data request is unknown or already resolved
+    type Result = Option<DataRequestStatus>;
+}
+
+/// Message to obtain the pointers of the data requests unlocked up to an epoch that are still
+/// accepting commitments
+pub struct GetDataRequestsByEpoch {
+    /// Epoch
+    pub epoch: Epoch,
+}
+
+impl Message for GetDataRequestsByEpoch {
+    type Result = Vec<Hash>;
+}
+
+/// Message to obtain the transactions waiting in the mempool, grouped by type
+pub struct GetMempool;
+
+/// Transaction waiting in the mempool
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MempoolEntry {
+    /// Transaction hash
+    pub hash: Hash,
+    /// Fee offered by a value transfer or data request transaction, or fee paid by the data
+    /// request for the stage of a commit or reveal transaction. `None` if it cannot be computed,
+    /// for example because the inputs are not in the UTXO set anymore
+    pub fee: Option<u64>,
+    /// Weight of the transaction, currently its size in bytes
+    pub weight: u32,
+}
+
+/// Transactions waiting in the mempool, grouped by type and sorted by hash
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Mempool {
+    /// Value transfer transactions
+    pub value_transfer: Vec<MempoolEntry>,
+    /// Data request transactions
+    pub data_request: Vec<MempoolEntry>,
+    /// Commit transactions
+    pub commit: Vec<MempoolEntry>,
+    /// Reveal transactions
+    pub reveal: Vec<MempoolEntry>,
+}
+
+impl Message for GetMempool {
+    type Result = Mempool;
+}
+
+/// Message to obtain a transaction waiting in the mempool
+pub struct GetMempoolTransaction {
+    /// Transaction hash
+    pub hash: Hash,
+}
+
+impl Message for GetMempoolTransaction {
+    /// `None` if the transaction is not in the mempool
+    type Result = Option<Transaction>;
+}
+
 /// A list of peers and their respective last beacon, used to establish consensus
 pub struct PeersBeacons {
     /// A list of peers and their respective last beacon
@@ -357,6 +439,9 @@ impl Message for RequestPeers {
 pub struct ResolveRA {
     /// RAD request to be executed
     pub rad_request: RADRequest,
+    /// Data needed for resolving the sources of the RAD request. Its limits are replaced by those
+    /// configured in the `RadManager`
+    pub context: RetrievalContext,
 }
 
 /// Message for running the consensus step of a data request.
@@ -368,12 +453,28 @@ pub struct RunConsensus {
     pub reveals: Vec<Vec<u8>>,
 }
 
+/// Message for delivering the result of a data request to the endpoint of one of its deliver
+/// clauses, retrying if it fails.
+#[derive(Debug)]
+pub struct RunDelivery {
+    /// Deliver clause to be run
+    pub deliver: RADDeliver,
+    /// JSON payload with the result of the data request and the proof of it
+    pub payload: Vec<u8>,
+}
+
 impl Message for ResolveRA {
     type Result = Result<Vec<u8>, RadError>;
 }
 
 impl Message for RunConsensus {
-    type Result = Result<Vec<u8>, RadError>;
+    /// The tally, along with whether each one of the reveals is honest
+    type Result = Result<(Vec<u8>, Vec<bool>), RadError>;
+}
+
+impl Message for RunDelivery {
+    /// The outcome of the delivery, which is recorded even if it failed
+    type Result = Result<DeliveryRecord, ()>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -558,3 +659,10 @@ pub struct NewBlock {
     /// Block
     pub block: Block,
 }
+
+/// New transaction notification, sent when a transaction is added to the mempool
+#[derive(Message)]
+pub struct NewTransaction {
+    /// Transaction
+    pub transaction: Transaction,
+}
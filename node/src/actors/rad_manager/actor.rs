@@ -1,6 +1,9 @@
 use super::RadManager;
-use actix::{Actor, Context, Supervised, SystemService};
+use crate::config_mngr;
+use actix::prelude::*;
 use log;
+use std::time::Duration;
+use witnet_rad::RetrievalLimits;
 
 /// Implement Actor trait for `RadManager`
 impl Actor for RadManager {
@@ -8,8 +11,28 @@ impl Actor for RadManager {
     type Context = Context<Self>;
 
     /// Method to be executed when the actor is started
-    fn started(&mut self, _ctx: &mut Self::Context) {
+    fn started(&mut self, ctx: &mut Self::Context) {
         log::debug!("RadManager actor has been started!");
+
+        // Read the limits to the requests made to HTTP sources from the configuration
+        config_mngr::get()
+            .into_actor(self)
+            .and_then(|config, act, _ctx| {
+                act.limits = RetrievalLimits::from(&config.rad);
+                // Any client built before reading the configuration enforces other limits
+                act.http_client = None;
+                act.max_concurrent_retrievals = config.rad.max_concurrent_retrievals.max(1);
+                // Leave half of the epoch for broadcasting the commitment
+                act.retrieval_deadline =
+                    Duration::from_secs(u64::from(config.consensus_constants.checkpoints_period))
+                        / 2;
+
+                fut::ok(())
+            })
+            .map_err(|err, _, _| {
+                log::error!("Couldn't process config: {}", err);
+            })
+            .wait(ctx);
     }
 }
 
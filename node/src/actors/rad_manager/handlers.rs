@@ -1,25 +1,106 @@
 //! Message handlers for `RadManager`
-use actix::{Handler, Message};
-use std::convert::TryFrom;
-use witnet_rad as rad;
-use witnet_rad::types::RadonTypes;
+use std::time::Instant;
 
-use super::RadManager;
-use crate::actors::messages::{ResolveRA, RunConsensus};
+use actix::{Handler, Message, ResponseFuture};
+use futures::{
+    future::{self, Either, Loop},
+    stream, Future, Stream,
+};
+use tokio::timer::{Delay, Timeout};
+use witnet_data_structures::chain::{DeliveryRecord, RADRetrieve};
+use witnet_rad::{self as rad, error::RadError, types::RadonTypes, AsyncClient, RetrievalContext};
+
+use super::{RadManager, DELIVERY_ATTEMPTS, DELIVERY_RETRY_DELAY};
+use crate::actors::messages::{ResolveRA, RunConsensus, RunDelivery};
 
 impl Handler<ResolveRA> for RadManager {
-    type Result = <ResolveRA as Message>::Result;
+    type Result = ResponseFuture<Vec<u8>, RadError>;
 
     fn handle(&mut self, msg: ResolveRA, _ctx: &mut Self::Context) -> Self::Result {
         let retrieve_scripts = msg.rad_request.retrieve;
         let aggregate_script = msg.rad_request.aggregate;
+        let context = RetrievalContext {
+            limits: self.limits.clone(),
+            ..msg.context
+        };
+        let deadline = Instant::now() + self.retrieval_deadline;
+        let client = match self.http_client() {
+            Ok(client) => client,
+            Err(error) => return Box::new(future::err(error)),
+        };
+
+        let fut = retrieve_sources(
+            retrieve_scripts,
+            context,
+            client,
+            deadline,
+            self.max_concurrent_retrievals,
+        )
+        .and_then(move |results| {
+            rad::run_aggregation(successful_retrievals(results)?, &aggregate_script)
+        });
+
+        Box::new(fut)
+    }
+}
+
+/// Retrieve every source on its own future, at most `max_concurrent` of them at the same time.
+/// Those that fail or do not finish before the deadline are kept as errors instead of failing the
+/// whole stream, so that the result of every source is known.
+fn retrieve_sources(
+    retrieve_scripts: Vec<RADRetrieve>,
+    context: RetrievalContext,
+    client: AsyncClient,
+    deadline: Instant,
+    max_concurrent: usize,
+) -> impl Future<Item = Vec<Result<RadonTypes, RadError>>, Error = RadError> {
+    let retrievals = retrieve_scripts
+        .into_iter()
+        .enumerate()
+        .map(move |(index, retrieve)| {
+            Timeout::new_at(
+                rad::run_retrieval_async(&retrieve, &context, &client),
+                deadline,
+            )
+            .map_err(|error| error.into_inner().unwrap_or(RadError::RetrievalDeadline))
+            .then(move |result| {
+                if let Err(error) = &result {
+                    log::warn!(
+                        "Couldn't retrieve source #{} ({}): {}",
+                        index,
+                        retrieve.url,
+                        error
+                    );
+                }
+
+                Ok(result)
+            })
+        });
 
-        let retrieve_responses = retrieve_scripts
-            .iter()
-            .filter_map(|retrieve| rad::run_retrieval(retrieve).ok())
-            .collect();
+    stream::iter_ok::<_, RadError>(retrievals)
+        .buffered(max_concurrent)
+        .collect()
+}
+
+/// Values retrieved from every source of a data request. A data request is only resolved if all
+/// of its sources are retrieved, so that all the witnesses aggregate the same sources: otherwise
+/// the error of every failed source is returned along with its index.
+fn successful_retrievals(
+    results: Vec<Result<RadonTypes, RadError>>,
+) -> Result<Vec<RadonTypes>, RadError> {
+    let mut values = Vec::with_capacity(results.len());
+    let mut errors = vec![];
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push((index, error)),
+        }
+    }
 
-        rad::run_aggregation(retrieve_responses, &aggregate_script)
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(RadError::FailedRetrievals { errors })
     }
 }
 
@@ -28,13 +109,134 @@ impl Handler<RunConsensus> for RadManager {
 
     fn handle(&mut self, msg: RunConsensus, _ctx: &mut Self::Context) -> Self::Result {
         let packed_script = msg.script;
-        let reveals = msg.reveals;
+        let reveals: Vec<&[u8]> = msg.reveals.iter().map(Vec::as_slice).collect();
+
+        rad::run_consensus_on_reveals(&reveals, &packed_script)
+    }
+}
+
+impl Handler<RunDelivery> for RadManager {
+    type Result = ResponseFuture<DeliveryRecord, ()>;
+
+    fn handle(&mut self, msg: RunDelivery, _ctx: &mut Self::Context) -> Self::Result {
+        let RunDelivery { deliver, payload } = msg;
+        let limits = self.limits.clone();
+
+        // Retry failed deliveries, doubling the delay between attempts every time
+        let fut = future::loop_fn(1, move |attempt| {
+            let url = deliver.url.clone();
+            rad::run_delivery(&deliver, &payload, &limits).then(move |result| match result {
+                Ok(()) => Either::A(future::ok(Loop::Break(DeliveryRecord {
+                    url,
+                    attempts: attempt,
+                    error: None,
+                }))),
+                Err(error) if attempt < DELIVERY_ATTEMPTS => {
+                    log::warn!(
+                        "Delivery to {} failed (attempt {} of {}): {}",
+                        url,
+                        attempt,
+                        DELIVERY_ATTEMPTS,
+                        error
+                    );
+                    let delay = DELIVERY_RETRY_DELAY * 2u32.pow(attempt - 1);
+
+                    Either::B(
+                        Delay::new(Instant::now() + delay)
+                            .then(move |_| Ok::<_, ()>(Loop::Continue(attempt + 1))),
+                    )
+                }
+                Err(error) => Either::A(future::ok(Loop::Break(DeliveryRecord {
+                    url,
+                    attempts: attempt,
+                    error: Some(error.to_string()),
+                }))),
+            })
+        });
+
+        Box::new(fut)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use witnet_data_structures::chain::{Hash, RADType};
+    use witnet_rad::{types::bytes::RadonBytes, RetrievalLimits};
+
+    use super::*;
+
+    #[test]
+    fn retrieve_sources_before_the_deadline() {
+        // A server that accepts connections but never answers them
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let seed = Hash::SHA256([1; 32]);
+        let context = RetrievalContext {
+            rng_seed: Some(seed),
+            ..RetrievalContext::default()
+        };
+        let client = rad::async_http_client(&RetrievalLimits::default()).unwrap();
+        let retrieve_scripts = vec![
+            RADRetrieve {
+                kind: RADType::HttpGet,
+                url,
+                // [ Identity ]
+                script: vec![145, 0],
+                ..RADRetrieve::default()
+            },
+            RADRetrieve {
+                kind: RADType::Rng,
+                // [ Identity ]
+                script: vec![145, 0],
+                ..RADRetrieve::default()
+            },
+        ];
+        let deadline = Instant::now() + Duration::from_millis(200);
+
+        let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+        let results = runtime
+            .block_on(retrieve_sources(
+                retrieve_scripts,
+                context,
+                client,
+                deadline,
+                2,
+            ))
+            .unwrap();
+
+        assert!(Instant::now() >= deadline);
+        assert_eq!(
+            results,
+            vec![
+                Err(RadError::RetrievalDeadline),
+                Ok(RadonTypes::from(RadonBytes::from(vec![1; 32]))),
+            ]
+        );
+    }
 
-        let radon_types_vec: Vec<RadonTypes> = reveals
-            .iter()
-            .filter_map(|input| RadonTypes::try_from(input.as_slice()).ok())
-            .collect();
+    #[test]
+    fn resolve_only_if_every_source_is_retrieved() {
+        let value = RadonTypes::from(RadonBytes::from(vec![1]));
 
-        rad::run_consensus(radon_types_vec, &packed_script)
+        assert_eq!(
+            successful_retrievals(vec![Ok(value.clone()), Ok(value.clone())]),
+            Ok(vec![value.clone(), value.clone()])
+        );
+        assert_eq!(
+            successful_retrievals(vec![
+                Err(RadError::RetrievalDeadline),
+                Ok(value),
+                Err(RadError::MissingRngSeed),
+            ]),
+            Err(RadError::FailedRetrievals {
+                errors: vec![
+                    (0, RadError::RetrievalDeadline),
+                    (2, RadError::MissingRngSeed)
+                ]
+            })
+        );
     }
 }
@@ -6,9 +6,55 @@
 //! [Data Requests]: https://docs.witnet.io/protocol/data-requests/overview/
 //! [RAD Engine]: https://docs.witnet.io/protocol/data-requests/overview/#the-rad-engine
 
+use std::time::Duration;
+
+use witnet_rad::{self as rad, error::RadError, AsyncClient, RetrievalLimits};
+
 mod actor;
 mod handlers;
 
+/// Maximum number of attempts to deliver the result of a data request to an endpoint
+const DELIVERY_ATTEMPTS: u32 = 3;
+
+/// Delay before retrying a failed delivery, which doubles after every attempt
+const DELIVERY_RETRY_DELAY: Duration = Duration::from_secs(10);
+
 /// RadManager actor
-#[derive(Default)]
-pub struct RadManager;
+pub struct RadManager {
+    /// Limits to the requests made to HTTP sources, as read from the configuration
+    limits: RetrievalLimits,
+    /// Maximum number of sources of a data request that are retrieved at the same time
+    max_concurrent_retrievals: usize,
+    /// Time allowed for retrieving all the sources of a data request, so that the commitment
+    /// can still be broadcast within the same epoch
+    retrieval_deadline: Duration,
+    /// HTTP client shared by the retrievals of all the data requests, built out of the limits
+    /// the first time it is needed
+    http_client: Option<AsyncClient>,
+}
+
+impl RadManager {
+    /// Get the HTTP client shared by the retrievals, building it if there is none yet
+    fn http_client(&mut self) -> Result<AsyncClient, RadError> {
+        match &self.http_client {
+            Some(client) => Ok(client.clone()),
+            None => {
+                let client = rad::async_http_client(&self.limits)?;
+                self.http_client = Some(client.clone());
+
+                Ok(client)
+            }
+        }
+    }
+}
+
+impl Default for RadManager {
+    fn default() -> Self {
+        Self {
+            limits: RetrievalLimits::default(),
+            max_concurrent_retrievals: 1,
+            retrieval_deadline: Duration::from_secs(45),
+            http_client: None,
+        }
+    }
+}
@@ -1,8 +1,18 @@
+use witnet_data_structures::chain::Hash;
+
 /// Constant to specify the peers key for the storage
 pub static PEERS_KEY: &'static [u8] = b"peers";
 
 /// Constant to specify the chain state key for the storage
 pub static CHAIN_STATE_KEY: &'static [u8] = b"chain";
 
+/// Constant to specify the key of the format version of the chain state for the storage
+pub static CHAIN_STATE_VERSION_KEY: &'static [u8] = b"chain_version";
+
 /// Constant to specify the secret key key for the storage
 pub static MASTER_KEY: &'static [u8] = b"master_key";
+
+/// Key under which the delivery log of a data request is stored
+pub fn delivery_log_key(dr_pointer: &Hash) -> String {
+    format!("delivery-{}", dr_pointer)
+}
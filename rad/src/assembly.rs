@@ -0,0 +1,511 @@
+//! Human-readable notation of RADON scripts.
+//!
+//! Scripts are written as chains of calls to operators named as in
+//! [`RadonOpCodes`](crate::operators::RadonOpCodes), e.g.
+//! `ParseJson().ToMap().Get("main").ToMap().Get("temp").ToFloat()`.
+//!
+//! Arguments can be integers (also in hexadecimal, e.g. `0x30`), floats, strings, booleans,
+//! `null`, arrays (`[1, 2]`), maps (`{"key": "value"}`) or subscripts written in this same
+//! notation, e.g. `ArrayMap(Get("price").ToFloat())`.
+
+use rmpv::Value;
+
+use crate::error::RadError;
+use crate::operators::RadonOpCodes;
+use crate::script::{
+    pack_radon_script, pack_subscript, unpack_radon_script, unpack_subscript, RadonCall,
+    RadonScript,
+};
+
+/// Compile a script written in the textual notation into the packed format accepted by
+/// `unpack_radon_script`.
+pub fn assemble(source: &str) -> Result<Vec<u8>, RadError> {
+    parse_radon_script(source).and_then(|script| pack_radon_script(&script))
+}
+
+/// Write a packed script in the textual notation.
+pub fn disassemble(packed: &[u8]) -> Result<String, RadError> {
+    unpack_radon_script(packed).and_then(|script| format_radon_script(&script))
+}
+
+/// Parse a script written in the textual notation.
+pub fn parse_radon_script(source: &str) -> Result<RadonScript, RadError> {
+    let mut parser = Parser {
+        source,
+        position: 0,
+    };
+    let script = parser.script()?;
+
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("expected `.` or the end of the script"));
+    }
+
+    Ok(script)
+}
+
+/// Write a script in the textual notation.
+pub fn format_radon_script(script: &[RadonCall]) -> Result<String, RadError> {
+    script
+        .iter()
+        .map(format_radon_call)
+        .collect::<Result<Vec<_>, _>>()
+        .map(|calls| calls.join("."))
+}
+
+fn format_radon_call((op_code, args): &RadonCall) -> Result<String, RadError> {
+    let args = args
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, arg)| {
+            if index == 0 && takes_subscript(*op_code) && arg.is_array() {
+                unpack_subscript(arg).and_then(|subscript| {
+                    // An empty subscript would otherwise be read back as no argument at all
+                    if subscript.is_empty() {
+                        Ok(String::from("[]"))
+                    } else {
+                        format_radon_script(&subscript)
+                    }
+                })
+            } else {
+                format_value(arg)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(format!("{}({})", op_code, args.join(", ")))
+}
+
+/// Whether the first argument of the operator is a subscript, if it is an array.
+fn takes_subscript(op_code: RadonOpCodes) -> bool {
+    matches!(
+        op_code,
+        RadonOpCodes::ArrayEvery
+            | RadonOpCodes::ArrayFilter
+            | RadonOpCodes::ArrayMap
+            | RadonOpCodes::ArraySome
+            | RadonOpCodes::ArraySort
+    )
+}
+
+fn format_value(value: &Value) -> Result<String, RadError> {
+    let unsupported = || RadError::UnsupportedScriptValue {
+        value: value.to_string(),
+    };
+
+    Ok(match value {
+        Value::Nil => String::from("null"),
+        Value::Boolean(boolean) => boolean.to_string(),
+        Value::Integer(integer) => integer.to_string(),
+        // Debug formatting always keeps the decimal point, so that floats are not read back as
+        // integers. The notation has no literals for infinities or NaN.
+        Value::F32(float) if float.is_finite() => format!("{:?}", float),
+        Value::F64(float) if float.is_finite() => format!("{:?}", float),
+        Value::F32(_) | Value::F64(_) => return Err(unsupported()),
+        Value::String(string) => format!("{:?}", string.as_str().ok_or_else(unsupported)?),
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(format_value)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+        Value::Map(entries) => {
+            let entries = entries
+                .iter()
+                .map(|(key, value)| Ok(format!("{}: {}", format_value(key)?, format_value(value)?)))
+                .collect::<Result<Vec<_>, RadError>>()?;
+
+            format!("{{{}}}", entries.join(", "))
+        }
+        Value::Binary(_) | Value::Ext(..) => return Err(unsupported()),
+    })
+}
+
+/// Recursive descent parser of the textual notation.
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn script(&mut self) -> Result<RadonScript, RadError> {
+        let mut script = Vec::new();
+
+        self.skip_whitespace();
+        if !matches!(self.peek(), Some(c) if is_identifier_start(c)) {
+            return Ok(script);
+        }
+
+        loop {
+            script.push(self.call()?);
+            if !self.eat('.') {
+                break Ok(script);
+            }
+        }
+    }
+
+    fn call(&mut self) -> Result<RadonCall, RadError> {
+        self.skip_whitespace();
+        let name = self.identifier();
+        if name.is_empty() {
+            return Err(self.error("expected an operator"));
+        }
+        let op_code =
+            RadonOpCodes::from_name(name).ok_or_else(|| RadError::UnknownOperatorName {
+                name: name.to_string(),
+            })?;
+
+        self.expect('(')?;
+        let args = self.sequence(')')?;
+
+        Ok((op_code, if args.is_empty() { None } else { Some(args) }))
+    }
+
+    /// Values separated by commas, up to the given closing character.
+    fn sequence(&mut self, close: char) -> Result<Vec<Value>, RadError> {
+        let mut values = Vec::new();
+
+        if self.eat(close) {
+            return Ok(values);
+        }
+
+        loop {
+            values.push(self.value()?);
+            if self.eat(close) {
+                break Ok(values);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, RadError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('"') => self.string().map(Value::from),
+            Some('[') => {
+                self.bump();
+                self.sequence(']').map(Value::Array)
+            }
+            Some('{') => {
+                self.bump();
+                self.map()
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if is_identifier_start(c) => {
+                let start = self.position;
+                match self.identifier() {
+                    "true" => Ok(Value::from(true)),
+                    "false" => Ok(Value::from(false)),
+                    "null" => Ok(Value::Nil),
+                    _ => {
+                        self.position = start;
+                        self.script().map(|subscript| pack_subscript(&subscript))
+                    }
+                }
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn map(&mut self) -> Result<Value, RadError> {
+        let mut entries = Vec::new();
+
+        if self.eat('}') {
+            return Ok(Value::Map(entries));
+        }
+
+        loop {
+            let key = self.value()?;
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            if self.eat('}') {
+                break Ok(Value::Map(entries));
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, RadError> {
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || "+-._".contains(c)) {
+            self.bump();
+        }
+        let literal = self.source[start..self.position].replace('_', "");
+        let (sign, digits) = match literal.strip_prefix('-') {
+            Some(digits) => (-1, digits),
+            None => (1, literal.as_str()),
+        };
+        let hexadecimal = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"));
+
+        let number = if let Some(hexadecimal) = hexadecimal {
+            i64::from_str_radix(hexadecimal, 16)
+                .ok()
+                .map(|natural| Value::from(sign * natural))
+        } else if digits.contains(&['.', 'e', 'E'][..]) {
+            literal.parse::<f64>().ok().map(Value::from)
+        } else {
+            literal
+                .parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| literal.parse::<u64>().map(Value::from))
+                .ok()
+        };
+
+        number.ok_or_else(|| RadError::ScriptSyntax {
+            position: start,
+            description: format!("`{}` is not a valid number", literal),
+        })
+    }
+
+    fn string(&mut self) -> Result<String, RadError> {
+        let mut string = String::new();
+        self.bump();
+
+        loop {
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            match c {
+                '"' => break Ok(string),
+                '\\' => string.push(self.escape()?),
+                c => string.push(c),
+            }
+        }
+    }
+
+    /// Escape sequences are the same as in Rust string literals.
+    fn escape(&mut self) -> Result<char, RadError> {
+        match self.bump() {
+            Some('"') => Ok('"'),
+            Some('\'') => Ok('\''),
+            Some('\\') => Ok('\\'),
+            Some('0') => Ok('\0'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('u') if self.eat('{') => {
+                let start = self.position;
+                while matches!(self.peek(), Some(c) if c.is_ascii_hexdigit()) {
+                    self.bump();
+                }
+                let code = u32::from_str_radix(&self.source[start..self.position], 16).ok();
+                self.expect('}')?;
+
+                code.and_then(std::char::from_u32)
+                    .ok_or_else(|| self.error("invalid unicode escape"))
+            }
+            _ => Err(self.error("invalid escape sequence")),
+        }
+    }
+
+    fn identifier(&mut self) -> &'a str {
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            self.bump();
+        }
+
+        &self.source[start..self.position]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    /// Consume the given character if it is the next one, skipping whitespace.
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), RadError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", expected)))
+        }
+    }
+
+    fn error(&self, description: &str) -> RadError {
+        RadError::ScriptSyntax {
+            position: self.position,
+            description: description.to_string(),
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+#[test]
+fn test_assemble() {
+    let source = r#"ParseJson().ToMap().Get("main").ToMap().Get("temp").ToFloat()"#;
+    let expected = vec![
+        150, 83, 204, 132, 146, 1, 164, 109, 97, 105, 110, 204, 132, 146, 1, 164, 116, 101, 109,
+        112, 204, 130,
+    ];
+
+    assert_eq!(assemble(source).unwrap(), expected);
+}
+
+#[test]
+fn test_disassemble() {
+    let packed = [
+        150, 83, 204, 132, 146, 1, 164, 109, 97, 105, 110, 204, 132, 146, 1, 164, 116, 101, 109,
+        112, 204, 130,
+    ];
+    let expected = r#"ParseJson().ToMap().Get("main").ToMap().Get("temp").ToFloat()"#;
+
+    assert_eq!(disassemble(&packed).unwrap(), expected);
+}
+
+#[test]
+fn test_parse_radon_script_arguments() {
+    let source = r#"
+        ArrayMap(Get("price").ToFloat())
+            .ArrayFilter(0x30, 1.5)
+            .Reduce(0x21, [1, 2.0, -3])
+            .BooleanMatch([[true, "yes"]], null)
+            .ToMap()
+            .Get("line\n\"quoted\" \u{e9}")
+            .Get({"key": -0x10})
+    "#;
+    let expected = vec![
+        (
+            RadonOpCodes::ArrayMap,
+            Some(vec![Value::from(vec![
+                Value::from(vec![Value::from(0x01), Value::from("price")]),
+                Value::from(0x82),
+            ])]),
+        ),
+        (
+            RadonOpCodes::ArrayFilter,
+            Some(vec![Value::from(0x30), Value::from(1.5)]),
+        ),
+        (
+            RadonOpCodes::Reduce,
+            Some(vec![
+                Value::from(0x21),
+                Value::from(vec![Value::from(1), Value::from(2.0), Value::from(-3)]),
+            ]),
+        ),
+        (
+            RadonOpCodes::BooleanMatch,
+            Some(vec![
+                Value::from(vec![Value::from(vec![
+                    Value::from(true),
+                    Value::from("yes"),
+                ])]),
+                Value::Nil,
+            ]),
+        ),
+        (RadonOpCodes::ToMap, None),
+        (
+            RadonOpCodes::Get,
+            Some(vec![Value::from("line\n\"quoted\" \u{e9}")]),
+        ),
+        (
+            RadonOpCodes::Get,
+            Some(vec![Value::Map(vec![(
+                Value::from("key"),
+                Value::from(-0x10),
+            )])]),
+        ),
+    ];
+
+    let script = parse_radon_script(source).unwrap();
+
+    assert_eq!(script, expected);
+    assert_eq!(
+        parse_radon_script(&format_radon_script(&script).unwrap()).unwrap(),
+        script
+    );
+}
+
+#[test]
+fn test_parse_radon_script_errors() {
+    assert_eq!(
+        parse_radon_script("ParseJson().Nonexistent()"),
+        Err(RadError::UnknownOperatorName {
+            name: String::from("Nonexistent")
+        })
+    );
+    assert_eq!(
+        parse_radon_script("ParseJson().ToMap("),
+        Err(RadError::ScriptSyntax {
+            position: 18,
+            description: String::from("expected a value")
+        })
+    );
+    assert_eq!(
+        parse_radon_script("ParseJson() ToMap()"),
+        Err(RadError::ScriptSyntax {
+            position: 12,
+            description: String::from("expected `.` or the end of the script")
+        })
+    );
+    assert!(parse_radon_script(r#"Get("unterminated)"#).is_err());
+    assert!(parse_radon_script("Get(1.2.3)").is_err());
+}
+
+#[test]
+fn test_format_radon_script_empty_subscript() {
+    let script = vec![(RadonOpCodes::ArrayMap, Some(vec![pack_subscript(&[])]))];
+
+    let source = format_radon_script(&script).unwrap();
+
+    assert_eq!(source, "ArrayMap([])");
+    assert_eq!(parse_radon_script(&source).unwrap(), script);
+}
+
+#[test]
+fn test_format_radon_script_non_finite_floats() {
+    let script = vec![(
+        RadonOpCodes::Reduce,
+        Some(vec![Value::from(0x21), Value::from(1e300)]),
+    )];
+    assert_eq!(
+        parse_radon_script(&format_radon_script(&script).unwrap()).unwrap(),
+        script
+    );
+
+    for float in &[std::f64::NAN, std::f64::INFINITY, std::f64::NEG_INFINITY] {
+        let script = vec![(RadonOpCodes::FloatSum, Some(vec![Value::from(*float)]))];
+
+        assert_eq!(
+            format_radon_script(&script),
+            Err(RadError::UnsupportedScriptValue {
+                value: Value::from(*float).to_string()
+            })
+        );
+    }
+    let script = vec![(
+        RadonOpCodes::FloatSum,
+        Some(vec![Value::F32(std::f32::NAN)]),
+    )];
+    assert!(format_radon_script(&script).is_err());
+}
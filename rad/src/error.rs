@@ -6,15 +6,39 @@ use rmpv::{Integer, Value};
 /// RAD errors.
 #[derive(Debug, PartialEq, Fail)]
 pub enum RadError {
+    /// Failed to parse an object from a CSV buffer
+    #[fail(
+        display = "Failed to parse an object from a CSV buffer: {:?}",
+        description
+    )]
+    CsvParse { description: String },
     /// Failed to decode a type from other
     #[fail(display = "Failed to decode {} from {}", to, from)]
     Decode { from: String, to: String },
+    /// The operator would need to divide by zero
+    #[fail(display = "Division by zero in `{}::{}()`", input_type, operator)]
+    DivisionByZero {
+        input_type: String,
+        operator: String,
+    },
     /// Failed to encode a type into other
     #[fail(display = "Failed to encode {} into {}", from, to)]
     Encode { from: String, to: String },
     /// Failed to calculate the hash of a RADON value or structure
     #[fail(display = "Failed to calculate the hash of a RADON value or structure")]
     Hash,
+    /// The given regular expression is not valid
+    #[fail(display = "Invalid regular expression `{}`: {}", pattern, description)]
+    InvalidRegex {
+        pattern: String,
+        description: String,
+    },
+    /// The URL of a `TALLY` source is not the hash of a data request
+    #[fail(
+        display = "The URL of a TALLY source is not the hash of a data request: `{}`",
+        url
+    )]
+    InvalidTallySource { url: String },
     /// Failed to parse an object from a JSON buffer
     #[fail(
         display = "Failed to parse an object from a JSON buffer: {:?}",
@@ -30,27 +54,78 @@ pub enum RadError {
         description
     )]
     MessagePack { description: String },
+    /// The given filter needs an argument that was not provided
+    #[fail(display = "Filter `{}` needs an argument", filter)]
+    MissingFilterArgument { filter: String },
+    /// There is no seed for resolving `RNG` sources
+    #[fail(display = "There is no seed for resolving RNG sources")]
+    MissingRngSeed,
+    /// Several values are tied for the mode of a RadonArray
+    #[fail(
+        display = "There is no single mode: values `{}` appear {} times each",
+        values, max_count
+    )]
+    ModeTie { values: String, max_count: usize },
     /// No operator found in compound call
     #[fail(display = "No operator found in compound call")]
     NoOperatorInCompoundCall,
+    /// The input Array does not contain enough items
+    #[fail(
+        display = "At least {} items were required but the RadonArray only has {}",
+        required, found
+    )]
+    NotEnoughItems { required: usize, found: usize },
     /// The given operator code is not a valid Integer
     #[fail(display = "Operator code `{}` is not a valid Integer", code)]
     NotIntegerOperator { code: Box<Value> },
     /// The given operator code is not a valid natural number
     #[fail(display = "Operator code `{}` is not a valid natural number", code)]
     NotNaturalOperator { code: Integer },
+    /// The result of an arithmetic operator does not fit into its type
+    #[fail(display = "Arithmetic overflow in `{}::{}()`", input_type, operator)]
+    Overflow {
+        input_type: String,
+        operator: String,
+    },
     /// The parsed value was expected to be a script but is not even an Array
     #[fail(
         display = "The parsed value was expected to be a script but is not even an Array (it was a `{}`)",
         input_type
     )]
     ScriptNotArray { input_type: String },
+    /// The textual notation of a script is not well formed
+    #[fail(
+        display = "Syntax error at position {} of script: {}",
+        position, description
+    )]
+    ScriptSyntax {
+        position: usize,
+        description: String,
+    },
+    /// The tally of the data request read by a `TALLY` source is not available
+    #[fail(display = "The tally of data request {} is not available", dr_pointer)]
+    TallyNotFound { dr_pointer: String },
     /// The given operator code is unknown
     #[fail(display = "Operator code `{}` is unknown", code)]
     UnknownOperator { code: u64 },
+    /// The given operator name is unknown
+    #[fail(display = "Operator name `{}` is unknown", name)]
+    UnknownOperatorName { name: String },
+    /// The input value does not match any category and no default value was given
+    #[fail(
+        display = "Value `{}` does not match any category and no default value was given",
+        value
+    )]
+    UnmatchedValue { value: String },
     /// The given hash function is not implemented
     #[fail(display = "Hash function `{}` is not implemented", function)]
     UnsupportedHashFunction { function: String },
+    /// The given value cannot be written in the textual notation of scripts
+    #[fail(
+        display = "Value `{}` cannot be written in the textual notation of scripts",
+        value
+    )]
+    UnsupportedScriptValue { value: String },
     /// The given operator is not implemented for the input type
     #[fail(
         display = "Call to operator `{}` with args `{:?}` is not supported for input type `{}`",
@@ -77,6 +152,65 @@ pub enum RadError {
         operator: String,
         args: Vec<Value>,
     },
+    /// The output of a subscript does not have the type required by the operator
+    #[fail(
+        display = "Subscript of `{}()` was expected to return `{}` but returned `{}`",
+        operator, expected_type, value
+    )]
+    WrongSubscriptOutput {
+        operator: String,
+        expected_type: String,
+        value: String,
+    },
+    /// Failed to parse an object from an XML buffer
+    #[fail(
+        display = "Failed to parse an object from an XML buffer: {:?}",
+        description
+    )]
+    XmlParse { description: String },
+    /// The response of an HTTP source did not arrive in time
+    #[fail(
+        display = "The response of an HTTP source did not arrive within {} milliseconds",
+        millis
+    )]
+    RetrievalTimeout { millis: u128 },
+    /// The source was not retrieved before the deadline of the retrieval stage
+    #[fail(display = "The source was not retrieved before the deadline of the retrieval stage")]
+    RetrievalDeadline,
+    /// Some of the sources of a data request could not be retrieved, along with the index of
+    /// every failed source
+    #[fail(display = "Some of the sources could not be retrieved: {:?}", errors)]
+    FailedRetrievals { errors: Vec<(usize, RadError)> },
+    /// The body of the response of an HTTP source is too large
+    #[fail(
+        display = "The response of an HTTP source is larger than the limit of {} bytes",
+        limit
+    )]
+    ResponseTooLarge { limit: u64 },
+    /// An HTTP source redirected more times than allowed
+    #[fail(
+        display = "An HTTP source redirected more than the limit of {} times",
+        limit
+    )]
+    TooManyRedirects { limit: usize },
+    /// The response of an HTTP source has a media type that is not accepted
+    #[fail(
+        display = "The response of an HTTP source has an unsupported content type `{}`",
+        content_type
+    )]
+    UnsupportedContentType { content_type: String },
+    /// The response of an HTTP source is not encoded in UTF-8
+    #[fail(
+        display = "The response of an HTTP source has an unsupported charset `{}`",
+        charset
+    )]
+    UnsupportedCharset { charset: String },
+    /// The endpoint of a deliver clause answered with an unsuccessful HTTP status
+    #[fail(display = "The endpoint answered with HTTP status {}", status)]
+    HttpStatus { status: u16 },
+    /// The kind of a deliver clause cannot be delivered
+    #[fail(display = "Deliver clauses of kind `{}` are not supported", kind)]
+    UnsupportedDelivery { kind: String },
     /// Failed to execute HTTP request
     #[fail(
         display = "Failed to execute HTTP request with error message: {}",
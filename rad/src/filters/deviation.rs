@@ -0,0 +1,53 @@
+use crate::error::RadError;
+use crate::filters::{number, reduce_to_float};
+use crate::reducers::RadonReducers;
+use crate::types::{array::RadonArray, RadonType};
+
+/// Positions of the items within `factor` standard deviations from the mean.
+pub fn standard(input: &RadonArray, factor: f64) -> Result<Vec<usize>, RadError> {
+    let mean = reduce_to_float(input, RadonReducers::AverageMean)?;
+    let deviation = reduce_to_float(input, RadonReducers::DeviationStandard)?;
+
+    Ok(keep_within(input, mean, factor * deviation))
+}
+
+/// Positions of the items within `factor` median absolute deviations from the median.
+pub fn median_absolute(input: &RadonArray, factor: f64) -> Result<Vec<usize>, RadError> {
+    let median = reduce_to_float(input, RadonReducers::AverageMedian)?;
+    let deviation = reduce_to_float(input, RadonReducers::DeviationMedianAbsolute)?;
+
+    Ok(keep_within(input, median, factor * deviation))
+}
+
+fn keep_within(input: &RadonArray, center: f64, band: f64) -> Vec<usize> {
+    input
+        .value()
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| number(item).map_or(false, |value| (value - center).abs() <= band))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[cfg(test)]
+fn prices() -> RadonArray {
+    use crate::types::float::RadonFloat;
+
+    RadonArray::from(vec![
+        RadonFloat::from(1.0).into(),
+        RadonFloat::from(1.1).into(),
+        RadonFloat::from(0.9).into(),
+        RadonFloat::from(1.0).into(),
+        RadonFloat::from(10.0).into(),
+    ])
+}
+
+#[test]
+fn test_standard() {
+    assert_eq!(standard(&prices(), 1.5).unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_median_absolute() {
+    assert_eq!(median_absolute(&prices(), 3.0).unwrap(), vec![0, 1, 2, 3]);
+}
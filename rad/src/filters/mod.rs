@@ -0,0 +1,66 @@
+// FIXME: https://github.com/rust-num/num-derive/issues/20
+#![allow(clippy::useless_attribute)]
+
+use crate::error::RadError;
+use crate::reducers::{self, RadonReducers};
+use crate::types::{array::RadonArray, RadonType, RadonTypes};
+
+mod deviation;
+mod mode;
+
+use num_derive::FromPrimitive;
+use std::fmt;
+
+/// Predefined filters for discarding the outliers of an array before reducing it. Their codes
+/// match those of the reducers they are based on.
+#[derive(Debug, FromPrimitive, PartialEq)]
+pub enum RadonFilters {
+    Mode = 0x10,
+    DeviationStandard = 0x30,
+    DeviationMedianAbsolute = 0x32,
+}
+
+impl fmt::Display for RadonFilters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RadonFilters::{:?}", self)
+    }
+}
+
+/// Positions of the items of the input that pass the given filter, in order. Deviation filters
+/// take as `factor` how many deviations an item may be away from the center of the input.
+pub fn filter_positions(
+    input: &RadonArray,
+    filter_code: RadonFilters,
+    factor: Option<f64>,
+) -> Result<Vec<usize>, RadError> {
+    match (filter_code, factor) {
+        (RadonFilters::Mode, _) => mode::mode(input),
+        (RadonFilters::DeviationStandard, Some(factor)) => deviation::standard(input, factor),
+        (RadonFilters::DeviationMedianAbsolute, Some(factor)) => {
+            deviation::median_absolute(input, factor)
+        }
+        (filter_code, None) => Err(RadError::MissingFilterArgument {
+            filter: filter_code.to_string(),
+        }),
+    }
+}
+
+/// Reduce the input with one of the reducers that return a float.
+fn reduce_to_float(input: &RadonArray, reducer: RadonReducers) -> Result<f64, RadError> {
+    match reducers::reduce(input, reducer, None)? {
+        RadonTypes::Float(float) => Ok(float.value()),
+        other => Err(RadError::Decode {
+            from: other.to_string(),
+            to: "f64".to_string(),
+        }),
+    }
+}
+
+/// Numeric value of a float or integer item.
+fn number(item: &RadonTypes) -> Option<f64> {
+    match item {
+        RadonTypes::Float(float) => Some(float.value()),
+        RadonTypes::Integer(integer) => Some(integer.value() as f64),
+        _ => None,
+    }
+}
@@ -0,0 +1,29 @@
+use crate::error::RadError;
+use crate::reducers::{self, RadonReducers};
+use crate::types::{array::RadonArray, RadonType};
+
+/// Positions of the items that are equal to the mode of the input.
+pub fn mode(input: &RadonArray) -> Result<Vec<usize>, RadError> {
+    let mode = reducers::reduce(input, RadonReducers::Mode, None)?;
+
+    Ok(input
+        .value()
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| **item == mode)
+        .map(|(position, _)| position)
+        .collect())
+}
+
+#[test]
+fn test_mode() {
+    use crate::types::string::RadonString;
+
+    let input = RadonArray::from(vec![
+        RadonString::from("sunny").into(),
+        RadonString::from("rainy").into(),
+        RadonString::from("sunny").into(),
+    ]);
+
+    assert_eq!(mode(&input).unwrap(), vec![0, 2]);
+}
@@ -0,0 +1,347 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::{blake2b::Blake2b, blake2s::Blake2s};
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Indexes of the state words mixed by each one of the 8 `G` functions of a round: first the
+/// columns, then the diagonals.
+const MIXES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+const BLAKE_256_IV: [u32; 8] = [
+    0x6A09_E667,
+    0xBB67_AE85,
+    0x3C6E_F372,
+    0xA54F_F53A,
+    0x510E_527F,
+    0x9B05_688C,
+    0x1F83_D9AB,
+    0x5BE0_CD19,
+];
+
+const BLAKE_256_CONSTANTS: [u32; 16] = [
+    0x243F_6A88,
+    0x85A3_08D3,
+    0x1319_8A2E,
+    0x0370_7344,
+    0xA409_3822,
+    0x299F_31D0,
+    0x082E_FA98,
+    0xEC4E_6C89,
+    0x4528_21E6,
+    0x38D0_1377,
+    0xBE54_66CF,
+    0x34E9_0C6C,
+    0xC0AC_29B7,
+    0xC97C_50DD,
+    0x3F84_D5B5,
+    0xB547_0917,
+];
+
+const BLAKE_512_IV: [u64; 8] = [
+    0x6A09_E667_F3BC_C908,
+    0xBB67_AE85_84CA_A73B,
+    0x3C6E_F372_FE94_F82B,
+    0xA54F_F53A_5F1D_36F1,
+    0x510E_527F_ADE6_82D1,
+    0x9B05_688C_2B3E_6C1F,
+    0x1F83_D9AB_FB41_BD6B,
+    0x5BE0_CD19_137E_2179,
+];
+
+const BLAKE_512_CONSTANTS: [u64; 16] = [
+    0x243F_6A88_85A3_08D3,
+    0x1319_8A2E_0370_7344,
+    0xA409_3822_299F_31D0,
+    0x082E_FA98_EC4E_6C89,
+    0x4528_21E6_38D0_1377,
+    0xBE54_66CF_34E9_0C6C,
+    0xC0AC_29B7_C97C_50DD,
+    0x3F84_D5B5_B547_0917,
+    0x9216_D5D9_8979_FB1B,
+    0xD131_0BA6_98DF_B5AC,
+    0x2FFD_72DB_D01A_DFB7,
+    0xB8E1_AFED_6A26_7E96,
+    0xBA7C_9045_F12C_7F99,
+    0x24A1_9947_B391_6CF7,
+    0x0801_F2E2_858E_FC16,
+    0x6369_20D8_7157_4E69,
+];
+
+/// Split a message into the padded blocks of the original BLAKE, along with the number of
+/// message bits hashed up to the end of each block, which is zero for the blocks containing only
+/// padding.
+fn blake_blocks(input: &[u8], block_size: usize) -> Vec<(Vec<u8>, u128)> {
+    let length_size = block_size / 8;
+    let total_bits = input.len() as u128 * 8;
+
+    let mut blocks: Vec<(Vec<u8>, u128)> = input
+        .chunks(block_size)
+        .filter(|chunk| chunk.len() == block_size)
+        .scan(0u128, |counter, chunk| {
+            *counter += block_size as u128 * 8;
+            Some((chunk.to_vec(), *counter))
+        })
+        .collect();
+
+    let remainder = &input[blocks.len() * block_size..];
+    let counter = if remainder.is_empty() { 0 } else { total_bits };
+    let mut last = remainder.to_vec();
+    last.push(0x80);
+    if last.len() > block_size - length_size {
+        last.resize(block_size, 0);
+        blocks.push((last, counter));
+        last = vec![0; block_size - length_size];
+        blocks.push((Vec::new(), 0));
+    } else {
+        last.resize(block_size - length_size, 0);
+        blocks.push((Vec::new(), counter));
+    }
+    last[block_size - length_size - 1] |= 0x01;
+    last.extend_from_slice(&total_bits.to_be_bytes()[16 - length_size..]);
+    if let Some(block) = blocks.last_mut() {
+        block.0 = last;
+    }
+
+    blocks
+}
+
+/// Original BLAKE-256, as submitted to the SHA-3 competition. It is not implemented by
+/// `rust-crypto`.
+pub fn blake_256(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    let mut h = BLAKE_256_IV;
+    let c = BLAKE_256_CONSTANTS;
+
+    for (block, counter) in blake_blocks(input, 64) {
+        let mut m = [0u32; 16];
+        for (word, bytes) in m.iter_mut().zip(block.chunks(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        let (t0, t1) = (counter as u32, (counter >> 32) as u32);
+
+        let mut v = [0u32; 16];
+        v[..8].copy_from_slice(&h);
+        v[8..12].copy_from_slice(&c[..4]);
+        v[12] = t0 ^ c[4];
+        v[13] = t0 ^ c[5];
+        v[14] = t1 ^ c[6];
+        v[15] = t1 ^ c[7];
+
+        for round in 0..14 {
+            let sigma = &SIGMA[round % 10];
+            for (i, &[a, b, c_, d]) in MIXES.iter().enumerate() {
+                let (x, y) = (sigma[2 * i], sigma[2 * i + 1]);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[x] ^ c[y]);
+                v[d] = (v[d] ^ v[a]).rotate_right(16);
+                v[c_] = v[c_].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c_]).rotate_right(12);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[y] ^ c[x]);
+                v[d] = (v[d] ^ v[a]).rotate_right(8);
+                v[c_] = v[c_].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c_]).rotate_right(7);
+            }
+        }
+
+        for (i, word) in h.iter_mut().enumerate() {
+            *word ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    Ok(h.iter()
+        .flat_map(|word| word.to_be_bytes().to_vec())
+        .collect())
+}
+
+/// Original BLAKE-512, as submitted to the SHA-3 competition. It is not implemented by
+/// `rust-crypto`.
+pub fn blake_512(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    let mut h = BLAKE_512_IV;
+    let c = BLAKE_512_CONSTANTS;
+
+    for (block, counter) in blake_blocks(input, 128) {
+        let mut m = [0u64; 16];
+        for (word, bytes) in m.iter_mut().zip(block.chunks(8)) {
+            let mut word_bytes = [0u8; 8];
+            word_bytes.copy_from_slice(bytes);
+            *word = u64::from_be_bytes(word_bytes);
+        }
+        let (t0, t1) = (counter as u64, (counter >> 64) as u64);
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&h);
+        v[8..12].copy_from_slice(&c[..4]);
+        v[12] = t0 ^ c[4];
+        v[13] = t0 ^ c[5];
+        v[14] = t1 ^ c[6];
+        v[15] = t1 ^ c[7];
+
+        for round in 0..16 {
+            let sigma = &SIGMA[round % 10];
+            for (i, &[a, b, c_, d]) in MIXES.iter().enumerate() {
+                let (x, y) = (sigma[2 * i], sigma[2 * i + 1]);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[x] ^ c[y]);
+                v[d] = (v[d] ^ v[a]).rotate_right(32);
+                v[c_] = v[c_].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c_]).rotate_right(25);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(m[y] ^ c[x]);
+                v[d] = (v[d] ^ v[a]).rotate_right(16);
+                v[c_] = v[c_].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c_]).rotate_right(11);
+            }
+        }
+
+        for (i, word) in h.iter_mut().enumerate() {
+            *word ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    Ok(h.iter()
+        .flat_map(|word| word.to_be_bytes().to_vec())
+        .collect())
+}
+
+pub fn blake2s_256(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Blake2s::new(32), input)
+}
+
+pub fn blake2b_512(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Blake2b::new(64), input)
+}
+
+#[test]
+fn test_blake_256() {
+    let expected_empty = "716f6e863f744b9ac22c97ec7b76ea5f5908bc5b2f67c61510bfc4751384ea7a";
+    let expected_one_byte = "0ce8d4ef4dd7cd8d62dfded9d4edb0a774ae6a41929a74da23109e8f11139c87";
+    let expected_two_blocks = "d419bad32d504fb7d44d460c42c5593fe544fa4c135dec31e21bd9abdcc22d41";
+    let expected_fox = "7576698ee9cad30173080678e5965916adbb11cb5245d386bf1ffda1cb26c9d7";
+
+    assert_eq!(hex::encode(blake_256(&[]).unwrap()), expected_empty);
+    assert_eq!(hex::encode(blake_256(&[0]).unwrap()), expected_one_byte);
+    assert_eq!(
+        hex::encode(blake_256(&[0; 72]).unwrap()),
+        expected_two_blocks
+    );
+    assert_eq!(
+        hex::encode(blake_256(b"The quick brown fox jumps over the lazy dog").unwrap()),
+        expected_fox
+    );
+}
+
+#[test]
+fn test_blake_256_padding() {
+    // 55 bytes leave room for the padding in a single byte, 56 need another block for it and 64
+    // need a block of padding alone, which does not count any bit of the message
+    let expected = [
+        (
+            55,
+            "6e8d7898571228c1106fcec9ef9c5db9df8a3a2dcd2655a848af596d181bbae4",
+        ),
+        (
+            56,
+            "ea7a29472a26148914abb8033869be9bdea294fdd2b73ed7a02a7692940f5b9e",
+        ),
+        (
+            64,
+            "84d7f3bbf2cfc3ee940ddb6d25045c6d3f756c4b2077a8128e171d5d165be170",
+        ),
+        (
+            1_000_000,
+            "22be6de4aa4214c9403f10598f0a6b0e834570251a13bc27589437f7139a5d44",
+        ),
+    ];
+
+    for (length, expected) in expected.iter() {
+        assert_eq!(
+            hex::encode(blake_256(&vec![b'a'; *length]).unwrap()),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_blake_512() {
+    let expected_empty = "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b\
+                          628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8";
+    let expected_one_byte = "97961587f6d970faba6d2478045de6d1fabd09b61ae50932054d52bc29d31be4\
+                             ff9102b9f69e2bbdb83be13d4b9c06091e5fa0b48bd081b634058be0ec49beb3";
+    let expected_two_blocks = "313717d608e9cf758dcb1eb0f0c3cf9fc150b2d500fb33f51c52afc99d358a2f\
+                               1374b8a38bba7974e7f6ef79cab16f22ce1e649d6e01ad9589c213045d545dde";
+    let expected_fox = "1f7e26f63b6ad25a0896fd978fd050a1766391d2fd0471a77afb975e5034b7ad\
+                        2d9ccf8dfb47abbbe656e1b82fbc634ba42ce186e8dc5e1ce09a885d41f43451";
+
+    assert_eq!(hex::encode(blake_512(&[]).unwrap()), expected_empty);
+    assert_eq!(hex::encode(blake_512(&[0]).unwrap()), expected_one_byte);
+    assert_eq!(
+        hex::encode(blake_512(&[0; 144]).unwrap()),
+        expected_two_blocks
+    );
+    assert_eq!(
+        hex::encode(blake_512(b"The quick brown fox jumps over the lazy dog").unwrap()),
+        expected_fox
+    );
+}
+
+#[test]
+fn test_blake_512_padding() {
+    // The same boundaries as those of BLAKE-256, with blocks of 128 bytes and a length of 16
+    let expected = [
+        (
+            111,
+            "93e94241778a8b6e7461f8567963aee4dc7ce2a8d6f187bb4341c889570e2e96\
+             f8598569281c813a4283487b3492d8797c389a7c8927e99186efabb68cccab1d",
+        ),
+        (
+            112,
+            "2e09048abf211af05d6f9b76434798bfe3c6b89342fb3ba75c334062be9a9901\
+             ebf6197a223c570c7199205ea9a0d5c07b9541722c07513fa009d2445d6de61c",
+        ),
+        (
+            128,
+            "e9c1faf06ba46df441c96d4a1eaa97342c6c8e84a32405ef4920f697213f15d8\
+             ae9fe8446fa0574108704a853511523bfcc58bf67aafaa6101e2f70e393d542e",
+        ),
+        (
+            1_000_000,
+            "e5d3ecf60cbf8ed8b44307ae6606f4c8eae0a602c2a393f529cf405a1384c791\
+             c3ce58f54838a76b89a6455e988d63f98222ea82bf26e11e970516fb7c62b41d",
+        ),
+    ];
+
+    for (length, expected) in expected.iter() {
+        assert_eq!(
+            hex::encode(blake_512(&vec![b'a'; *length]).unwrap()),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_blake2() {
+    let expected_2s = "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982";
+    let expected_2b = "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+                       7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923";
+
+    assert_eq!(hex::encode(blake2s_256(b"abc").unwrap()), expected_2s);
+    assert_eq!(hex::encode(blake2b_512(b"abc").unwrap()), expected_2b);
+}
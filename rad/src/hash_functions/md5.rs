@@ -0,0 +1,16 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::md5::Md5;
+
+pub fn md5_128(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Md5::new(), input)
+}
+
+#[test]
+fn test_md5_128() {
+    assert_eq!(
+        hex::encode(md5_128(b"abc").unwrap()),
+        "900150983cd24fb0d6963f7d28e17f72"
+    );
+}
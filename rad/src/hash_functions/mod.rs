@@ -1,11 +1,17 @@
 // FIXME: https://github.com/rust-num/num-derive/issues/20
 #![allow(clippy::useless_attribute)]
 
+mod blake;
+mod md5;
+mod ripemd;
+mod sha1;
 mod sha2;
+mod sha3;
+mod whirlpool;
 
 use crate::error::RadError;
-use crate::hash_functions::sha2::sha2_256;
 
+use crypto::digest::Digest;
 use num_derive::FromPrimitive;
 use std::fmt;
 
@@ -40,13 +46,39 @@ impl fmt::Display for RadonHashFunctions {
 
 pub fn hash(input: &[u8], hash_function_code: RadonHashFunctions) -> Result<Vec<u8>, RadError> {
     match hash_function_code {
-        RadonHashFunctions::SHA2_256 => sha2_256(input),
-        _ => Err(RadError::UnsupportedHashFunction {
+        RadonHashFunctions::Blake256 => blake::blake_256(input),
+        RadonHashFunctions::Blake512 => blake::blake_512(input),
+        RadonHashFunctions::Blake2s256 => blake::blake2s_256(input),
+        RadonHashFunctions::Blake2b512 => blake::blake2b_512(input),
+        RadonHashFunctions::MD5_128 => md5::md5_128(input),
+        RadonHashFunctions::Ripemd128 => ripemd::ripemd_128(input),
+        RadonHashFunctions::Ripemd160 => ripemd::ripemd_160(input),
+        RadonHashFunctions::Ripemd320 => ripemd::ripemd_320(input),
+        RadonHashFunctions::SHA1_160 => sha1::sha1_160(input),
+        RadonHashFunctions::SHA2_224 => sha2::sha2_224(input),
+        RadonHashFunctions::SHA2_256 => sha2::sha2_256(input),
+        RadonHashFunctions::SHA2_384 => sha2::sha2_384(input),
+        RadonHashFunctions::SHA2_512 => sha2::sha2_512(input),
+        RadonHashFunctions::SHA3_224 => sha3::sha3_224(input),
+        RadonHashFunctions::SHA3_256 => sha3::sha3_256(input),
+        RadonHashFunctions::SHA3_384 => sha3::sha3_384(input),
+        RadonHashFunctions::SHA3_512 => sha3::sha3_512(input),
+        RadonHashFunctions::Whirlpool512 => whirlpool::whirlpool_512(input),
+        RadonHashFunctions::Fail => Err(RadError::UnsupportedHashFunction {
             function: hash_function_code.to_string(),
         }),
     }
 }
 
+/// Feed the input into a `rust-crypto` hasher and return the resulting digest.
+fn digest<D: Digest>(mut hasher: D, input: &[u8]) -> Result<Vec<u8>, RadError> {
+    hasher.input(input);
+    let mut digest = vec![0; hasher.output_bytes()];
+    hasher.result(&mut digest);
+
+    Ok(digest)
+}
+
 #[test]
 fn test_hash() {
     let input = [72, 101, 108, 108, 111, 44, 32, 87, 111, 114, 108, 100, 33];
@@ -0,0 +1,273 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::ripemd160::Ripemd160;
+
+/// Message word selected by each step of the left line.
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// Message word selected by each step of the right line.
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// Left rotation applied by each step of the left line.
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// Left rotation applied by each step of the right line.
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+const K_LEFT: [u32; 5] = [
+    0x0000_0000,
+    0x5A82_7999,
+    0x6ED9_EBA1,
+    0x8F1B_BCDC,
+    0xA953_FD4E,
+];
+const K_RIGHT_128: [u32; 4] = [0x50A2_8BE6, 0x5C4D_D124, 0x6D70_3EF3, 0x0000_0000];
+const K_RIGHT_320: [u32; 5] = [
+    0x50A2_8BE6,
+    0x5C4D_D124,
+    0x6D70_3EF3,
+    0x7A6D_76E9,
+    0x0000_0000,
+];
+
+/// Boolean function used in each one of the rounds of the left line. The right line uses them in
+/// reverse order.
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+/// Split a message into the 16-word little-endian blocks padded as in MD4.
+fn ripemd_blocks(input: &[u8]) -> Vec<[u32; 16]> {
+    let mut message = input.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(input.len() as u64 * 8).to_le_bytes());
+
+    message
+        .chunks(64)
+        .map(|chunk| {
+            let mut block = [0u32; 16];
+            for (word, bytes) in block.iter_mut().zip(chunk.chunks(4)) {
+                *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            }
+            block
+        })
+        .collect()
+}
+
+/// RIPEMD-128, which is not implemented by `rust-crypto`.
+pub fn ripemd_128(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    let mut h: [u32; 4] = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476];
+
+    for x in ripemd_blocks(input) {
+        let mut left = h;
+        let mut right = h;
+
+        for step in 0..64 {
+            let round = step / 16;
+            let [a, b, c, d] = left;
+            let t = a
+                .wrapping_add(f(round, b, c, d))
+                .wrapping_add(x[R_LEFT[step]])
+                .wrapping_add(K_LEFT[round])
+                .rotate_left(S_LEFT[step]);
+            left = [d, t, b, c];
+
+            let [a, b, c, d] = right;
+            let t = a
+                .wrapping_add(f(3 - round, b, c, d))
+                .wrapping_add(x[R_RIGHT[step]])
+                .wrapping_add(K_RIGHT_128[round])
+                .rotate_left(S_RIGHT[step]);
+            right = [d, t, b, c];
+        }
+
+        let t = h[1].wrapping_add(left[2]).wrapping_add(right[3]);
+        h[1] = h[2].wrapping_add(left[3]).wrapping_add(right[0]);
+        h[2] = h[3].wrapping_add(left[0]).wrapping_add(right[1]);
+        h[3] = h[0].wrapping_add(left[1]).wrapping_add(right[2]);
+        h[0] = t;
+    }
+
+    Ok(h.iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .collect())
+}
+
+pub fn ripemd_160(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Ripemd160::new(), input)
+}
+
+/// RIPEMD-320, which is not implemented by `rust-crypto`.
+pub fn ripemd_320(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    let mut h: [u32; 10] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+        0x7654_3210,
+        0xFEDC_BA98,
+        0x89AB_CDEF,
+        0x0123_4567,
+        0x3C2D_1E0F,
+    ];
+
+    for x in ripemd_blocks(input) {
+        let mut left = [h[0], h[1], h[2], h[3], h[4]];
+        let mut right = [h[5], h[6], h[7], h[8], h[9]];
+
+        for step in 0..80 {
+            let round = step / 16;
+            let [a, b, c, d, e] = left;
+            let t = a
+                .wrapping_add(f(round, b, c, d))
+                .wrapping_add(x[R_LEFT[step]])
+                .wrapping_add(K_LEFT[round])
+                .rotate_left(S_LEFT[step])
+                .wrapping_add(e);
+            left = [e, t, b, c.rotate_left(10), d];
+
+            let [a, b, c, d, e] = right;
+            let t = a
+                .wrapping_add(f(4 - round, b, c, d))
+                .wrapping_add(x[R_RIGHT[step]])
+                .wrapping_add(K_RIGHT_320[round])
+                .rotate_left(S_RIGHT[step])
+                .wrapping_add(e);
+            right = [e, t, b, c.rotate_left(10), d];
+
+            // After each round, a word of the left line is swapped with its right counterpart
+            if step % 16 == 15 {
+                let swapped = [1, 3, 0, 2, 4][round];
+                std::mem::swap(&mut left[swapped], &mut right[swapped]);
+            }
+        }
+
+        for (word, value) in h.iter_mut().zip(left.iter().chain(right.iter())) {
+            *word = word.wrapping_add(*value);
+        }
+    }
+
+    Ok(h.iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .collect())
+}
+
+/// Messages of the official RIPEMD test vectors that take more than one block once padded, and
+/// messages whose lengths are around the boundaries of the padding.
+#[cfg(test)]
+fn long_messages() -> Vec<Vec<u8>> {
+    vec![
+        // 55 bytes fit in one block along with the padding, 56 do not and 64 need a block of
+        // padding alone
+        vec![b'a'; 55],
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".to_vec(),
+        vec![b'a'; 64],
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".to_vec(),
+        vec![b'a'; 1_000_000],
+    ]
+}
+
+#[test]
+fn test_ripemd_128() {
+    assert_eq!(
+        hex::encode(ripemd_128(b"").unwrap()),
+        "cdf26213a150dc3ecb610f18f6b38b46"
+    );
+    assert_eq!(
+        hex::encode(ripemd_128(b"abc").unwrap()),
+        "c14a12199c66e4ba84636b0f69144c77"
+    );
+    assert_eq!(
+        hex::encode(ripemd_128(b"message digest").unwrap()),
+        "9e327b3d6e523062afc1132d7df9d1b8"
+    );
+}
+
+#[test]
+fn test_ripemd_128_long_messages() {
+    let expected = [
+        "418486955c126b27903aa01fef5d5d15",
+        "a1aa0689d0fafa2ddc22e88b49133a06",
+        "680716ac638f0d601982c696d37e5e56",
+        "d1e959eb179c911faea4624c60c5c702",
+        "4a7f5723f954eba1216c9d8f6320431f",
+    ];
+
+    for (message, expected) in long_messages().iter().zip(expected.iter()) {
+        assert_eq!(hex::encode(ripemd_128(message).unwrap()), *expected);
+    }
+}
+
+#[test]
+fn test_ripemd_160() {
+    assert_eq!(
+        hex::encode(ripemd_160(b"abc").unwrap()),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+    );
+}
+
+#[test]
+fn test_ripemd_320() {
+    assert_eq!(
+        hex::encode(ripemd_320(b"").unwrap()),
+        "22d65d5661536cdc75c1fdf5c6de7b41b9f27325ebc61e8557177d705a0ec880151c3a32a00899b8"
+    );
+    assert_eq!(
+        hex::encode(ripemd_320(b"abc").unwrap()),
+        "de4c01b3054f8930a79d09ae738e92301e5a17085beffdc1b8d116713e74f82fa942d64cdbc4682d"
+    );
+    assert_eq!(
+        hex::encode(ripemd_320(b"message digest").unwrap()),
+        "3a8e28502ed45d422f68844f9dd316e7b98533fa3f2a91d29f84d425c88d6b4eff727df66a7c0197"
+    );
+}
+
+#[test]
+fn test_ripemd_320_long_messages() {
+    let expected = [
+        "34da276ee34f04ccf15c53170e6e71e2c3fc7ae33ad17033f2185c0cee6832abb4d741154438c54e",
+        "d034a7950cf722021ba4b84df769a5de2060e259df4c9bb4a4268c0e935bbc7470a969c9d072a1ac",
+        "6e815badcf69d2978caf8b8bbaba941239f9847d1ff140062484cb57a0745bccf21c427705fdd30d",
+        "ed544940c86d67f250d232c30b7b3e5770e0c60c8cb9a4cafe3b11388af9920e1b99230b843c86a4",
+        "bdee37f4371e20646b8b0d862dda16292ae36f40965e8c8509e63d1dbddecc503e2b63eb9245bb66",
+    ];
+
+    for (message, expected) in long_messages().iter().zip(expected.iter()) {
+        assert_eq!(hex::encode(ripemd_320(message).unwrap()), *expected);
+    }
+}
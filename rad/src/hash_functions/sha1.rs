@@ -0,0 +1,16 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::sha1::Sha1;
+
+pub fn sha1_160(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Sha1::new(), input)
+}
+
+#[test]
+fn test_sha1_160() {
+    assert_eq!(
+        hex::encode(sha1_160(b"abc").unwrap()),
+        "a9993e364706816aba3e25717850c26c9cd0d89d"
+    );
+}
@@ -1,14 +1,30 @@
 use crate::error::RadError;
+use crate::hash_functions::digest;
 
-use crypto::{digest::Digest, sha2};
+use crypto::sha2;
+
+pub fn sha2_224(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(sha2::Sha224::new(), input)
+}
 
 pub fn sha2_256(input: &[u8]) -> Result<Vec<u8>, RadError> {
-    let mut hash_function = sha2::Sha256::new();
-    hash_function.input(input);
-    let mut digest = [0; 32];
-    hash_function.result(&mut digest);
+    digest(sha2::Sha256::new(), input)
+}
+
+pub fn sha2_384(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(sha2::Sha384::new(), input)
+}
+
+pub fn sha2_512(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(sha2::Sha512::new(), input)
+}
 
-    Ok(digest.to_vec())
+#[test]
+fn test_sha2_224() {
+    assert_eq!(
+        hex::encode(sha2_224(b"abc").unwrap()),
+        "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+    );
 }
 
 #[test]
@@ -23,3 +39,21 @@ fn test_sha2_256() {
 
     assert_eq!(output_slice, expected);
 }
+
+#[test]
+fn test_sha2_384() {
+    assert_eq!(
+        hex::encode(sha2_384(b"abc").unwrap()),
+        "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+         8086072ba1e7cc2358baeca134c825a7"
+    );
+}
+
+#[test]
+fn test_sha2_512() {
+    assert_eq!(
+        hex::encode(sha2_512(b"abc").unwrap()),
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+}
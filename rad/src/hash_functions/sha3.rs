@@ -0,0 +1,54 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::sha3::Sha3;
+
+pub fn sha3_224(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Sha3::sha3_224(), input)
+}
+
+pub fn sha3_256(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Sha3::sha3_256(), input)
+}
+
+pub fn sha3_384(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Sha3::sha3_384(), input)
+}
+
+pub fn sha3_512(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Sha3::sha3_512(), input)
+}
+
+#[test]
+fn test_sha3_224() {
+    assert_eq!(
+        hex::encode(sha3_224(b"abc").unwrap()),
+        "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"
+    );
+}
+
+#[test]
+fn test_sha3_256() {
+    assert_eq!(
+        hex::encode(sha3_256(b"abc").unwrap()),
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+    );
+}
+
+#[test]
+fn test_sha3_384() {
+    assert_eq!(
+        hex::encode(sha3_384(b"abc").unwrap()),
+        "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+         98d88cea927ac7f539f1edf228376d25"
+    );
+}
+
+#[test]
+fn test_sha3_512() {
+    assert_eq!(
+        hex::encode(sha3_512(b"abc").unwrap()),
+        "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+         10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+    );
+}
@@ -0,0 +1,17 @@
+use crate::error::RadError;
+use crate::hash_functions::digest;
+
+use crypto::whirlpool::Whirlpool;
+
+pub fn whirlpool_512(input: &[u8]) -> Result<Vec<u8>, RadError> {
+    digest(Whirlpool::new(), input)
+}
+
+#[test]
+fn test_whirlpool_512() {
+    assert_eq!(
+        hex::encode(whirlpool_512(b"abc").unwrap()),
+        "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c\
+         7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5"
+    );
+}
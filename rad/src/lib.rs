@@ -1,40 +1,434 @@
 //! # RAD Engine
 
-use std::convert::TryInto;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::io::{self, Read};
 use std::result;
+use std::time::{Duration, Instant};
 
-use reqwest;
+use futures::{future, Future, Stream};
+use reqwest::{
+    self,
+    header::{HeaderMap, CONTENT_TYPE},
+    r#async::Response as AsyncResponse,
+    RedirectPolicy,
+};
+use serde::Serialize;
 
 use crate::error::RadError;
-use crate::script::{execute_radon_script, unpack_radon_script};
-use crate::types::{array::RadonArray, string::RadonString, RadonTypes};
-use witnet_data_structures::chain::{RADAggregate, RADConsensus, RADRetrieve, RADType};
+use crate::script::{
+    execute_radon_script, execute_radon_script_with_survivors, execute_radon_script_with_trace,
+    unpack_radon_script, RadonCallTrace,
+};
+use crate::types::{
+    array::RadonArray,
+    bytes::RadonBytes,
+    string::{RadonString, RADON_STRING_TYPE_NAME},
+    RadonTypes,
+};
+use witnet_config::config::Rad as RadConfig;
+use witnet_crypto::hash::calculate_sha256;
+use witnet_data_structures::chain::{
+    CheckpointBeacon, Hash, Hashable, RADAggregate, RADConsensus, RADDeliver, RADRequest,
+    RADRetrieve, RADType,
+};
 
+pub mod assembly;
 pub mod error;
+pub mod filters;
 pub mod hash_functions;
 pub mod operators;
 pub mod reducers;
 pub mod script;
 pub mod types;
+pub mod typing;
+
+pub use reqwest::r#async::Client as AsyncClient;
 
 /// Result type where error is `RadError`.
 pub type Result<T> = result::Result<T, RadError>;
 
+/// Execution trace of every stage of a RAD request, as recorded by
+/// [`run_request_with_trace`](run_request_with_trace).
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RADRequestTrace {
+    /// Calls executed by the script of every retrieval, in the same order as the sources
+    pub retrieval: Vec<Vec<RadonCallTrace>>,
+    /// Calls executed by the aggregation script
+    pub aggregation: Vec<RadonCallTrace>,
+    /// Calls executed by the consensus script
+    pub consensus: Vec<RadonCallTrace>,
+}
+
+/// Data needed for resolving the sources of a data request, other than the sources themselves.
+#[derive(Clone, Debug, Default)]
+pub struct RetrievalContext {
+    /// Seed from which `RNG` sources are resolved, as derived by [`rng_seed`](rng_seed)
+    pub rng_seed: Option<Hash>,
+    /// Tallies of the finalized data requests read by `TALLY` sources, by data request hash
+    pub tallies: HashMap<Hash, Vec<u8>>,
+    /// Limits to the requests made to HTTP sources
+    pub limits: RetrievalLimits,
+}
+
+/// Limits to the requests made to HTTP sources, so that a malicious data request cannot make a
+/// witness hang or exhaust its memory. The default ones do not limit anything but redirects.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetrievalLimits {
+    /// Maximum time to wait for a response
+    pub timeout: Option<Duration>,
+    /// Maximum size in bytes of the body of a response
+    pub max_response_size: Option<u64>,
+    /// Maximum number of redirects followed
+    pub max_redirects: usize,
+    /// Media types accepted in the `Content-Type` of a response, or any of them if empty
+    pub content_types: Vec<String>,
+}
+
+impl Default for RetrievalLimits {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            max_response_size: None,
+            // The default of reqwest
+            max_redirects: 10,
+            content_types: vec![],
+        }
+    }
+}
+
+impl From<&RadConfig> for RetrievalLimits {
+    fn from(config: &RadConfig) -> Self {
+        Self {
+            timeout: Some(config.retrieval_timeout),
+            max_response_size: Some(config.max_response_size),
+            max_redirects: config.max_redirects,
+            content_types: config.content_types.clone(),
+        }
+    }
+}
+
+/// Seed of the `RNG` sources of a data request resolved on top of the block referenced by
+/// `beacon`. It is the same for all the witnesses of the data request in that epoch, and nobody
+/// can know it before that block is consolidated.
+pub fn rng_seed(beacon: CheckpointBeacon, dr_pointer: Hash) -> Hash {
+    let Hash::SHA256(beacon_hash) = beacon.hash();
+    let Hash::SHA256(dr_hash) = dr_pointer;
+
+    calculate_sha256(&[beacon_hash, dr_hash].concat()).into()
+}
+
 /// Run retrieval stage of a data request.
-pub fn run_retrieval(retrieve: &RADRetrieve) -> Result<RadonTypes> {
+pub fn run_retrieval(retrieve: &RADRetrieve, context: &RetrievalContext) -> Result<RadonTypes> {
+    let input = fetch(retrieve, context)?;
+    let radon_script = unpack_radon_script(&retrieve.script)?;
+
+    execute_radon_script(input, &radon_script)
+}
+
+/// Run retrieval stage of a data request, recording the execution of every call of its script.
+pub fn run_retrieval_with_trace(
+    retrieve: &RADRetrieve,
+    context: &RetrievalContext,
+) -> (Result<RadonTypes>, Vec<RadonCallTrace>) {
+    let input_and_script = fetch(retrieve, context).and_then(|input| {
+        unpack_radon_script(&retrieve.script).map(|radon_script| (input, radon_script))
+    });
+
+    match input_and_script {
+        Ok((input, radon_script)) => execute_radon_script_with_trace(input, &radon_script),
+        Err(error) => (Err(error), vec![]),
+    }
+}
+
+/// Run retrieval stage of a data request on an asynchronous HTTP client, so that many sources can
+/// be retrieved concurrently without blocking the calling thread. The client is meant to be
+/// shared by all the retrievals, and has to be built by
+/// [`async_http_client`](async_http_client) out of the limits of the context.
+pub fn run_retrieval_async(
+    retrieve: &RADRetrieve,
+    context: &RetrievalContext,
+    client: &AsyncClient,
+) -> impl Future<Item = RadonTypes, Error = RadError> {
+    let radon_script = unpack_radon_script(&retrieve.script);
+
+    fetch_async(retrieve, context, client).and_then(move |input| {
+        radon_script.and_then(|radon_script| execute_radon_script(input, &radon_script))
+    })
+}
+
+/// Fetch the data to be retrieved from a source.
+fn fetch(retrieve: &RADRetrieve, context: &RetrievalContext) -> Result<RadonTypes> {
     match retrieve.kind {
         RADType::HttpGet => {
-            let response = reqwest::get(&retrieve.url)
-                .map_err(RadError::from)?
-                .text()
-                .map_err(RadError::from)?;
+            let limits = &context.limits;
+            let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+            let response = http_client(limits)?
+                .get(&retrieve.url)
+                .send()
+                .map_err(|error| http_error(error, limits))?;
+
+            read_response(response, limits, deadline)
+        }
+        RADType::HttpPost => {
+            let limits = &context.limits;
+            let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+            let request = retrieve.headers.iter().fold(
+                http_client(limits)?
+                    .post(&retrieve.url)
+                    .body(retrieve.body.clone()),
+                |request, header| request.header(header.name.as_str(), header.value.as_str()),
+            );
+            let response = request.send().map_err(|error| http_error(error, limits))?;
+
+            read_response(response, limits, deadline)
+        }
+        RADType::Rng => {
+            let Hash::SHA256(seed) = context.rng_seed.ok_or(RadError::MissingRngSeed)?;
+
+            Ok(RadonTypes::from(RadonBytes::from(seed.to_vec())))
+        }
+        RADType::Tally => {
+            let dr_pointer =
+                retrieve
+                    .url
+                    .parse::<Hash>()
+                    .map_err(|_| RadError::InvalidTallySource {
+                        url: retrieve.url.clone(),
+                    })?;
+            let tally =
+                context
+                    .tallies
+                    .get(&dr_pointer)
+                    .ok_or_else(|| RadError::TallyNotFound {
+                        dr_pointer: dr_pointer.to_string(),
+                    })?;
+
+            RadonTypes::try_from(tally.as_slice())
+        }
+    }
+}
+
+/// Fetch the data to be retrieved from a source, as `fetch` does, but without blocking.
+fn fetch_async(
+    retrieve: &RADRetrieve,
+    context: &RetrievalContext,
+    client: &AsyncClient,
+) -> Box<dyn Future<Item = RadonTypes, Error = RadError>> {
+    let limits = context.limits.clone();
+    let request = match retrieve.kind {
+        RADType::HttpGet => client.get(&retrieve.url),
+        RADType::HttpPost => retrieve.headers.iter().fold(
+            client.post(&retrieve.url).body(retrieve.body.clone()),
+            |request, header| request.header(header.name.as_str(), header.value.as_str()),
+        ),
+        // The rest of sources do not fetch anything from the network
+        RADType::Rng | RADType::Tally => return Box::new(future::result(fetch(retrieve, context))),
+    };
+    let send_limits = limits.clone();
+
+    Box::new(
+        request
+            .send()
+            .map_err(move |error| http_error(error, &send_limits))
+            .and_then(move |response| read_response_async(response, limits)),
+    )
+}
+
+/// Build an HTTP client that enforces the timeout and redirect limits.
+fn http_client(limits: &RetrievalLimits) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(limits.timeout)
+        .redirect(RedirectPolicy::limited(limits.max_redirects))
+        .build()
+        .map_err(RadError::from)
+}
+
+/// Build an asynchronous HTTP client that enforces the timeout and redirect limits.
+pub fn async_http_client(limits: &RetrievalLimits) -> Result<AsyncClient> {
+    let builder = AsyncClient::builder().redirect(RedirectPolicy::limited(limits.max_redirects));
+    let builder = match limits.timeout {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    };
+
+    builder.build().map_err(RadError::from)
+}
+
+/// Tell apart the errors of an HTTP request that are caused by its limits.
+fn http_error(error: reqwest::Error, limits: &RetrievalLimits) -> RadError {
+    match limits.timeout {
+        Some(timeout) if error.is_timeout() => RadError::RetrievalTimeout {
+            millis: timeout.as_millis(),
+        },
+        _ if error.is_redirect() => RadError::TooManyRedirects {
+            limit: limits.max_redirects,
+        },
+        _ => RadError::from(error),
+    }
+}
+
+/// Read the body of the response of an HTTP source as a string, checking its content type and
+/// its size against the limits. The timeout of the blocking client only applies to every single
+/// read, so the body has to be read before the deadline of the whole request, if any.
+fn read_response(
+    response: reqwest::Response,
+    limits: &RetrievalLimits,
+    deadline: Option<Instant>,
+) -> Result<RadonTypes> {
+    check_response(response.headers(), response.content_length(), limits)?;
+
+    let mut response = DeadlineReader {
+        reader: response,
+        deadline,
+    };
+    let mut body = Vec::new();
+    let read = match limits.max_response_size {
+        Some(limit) => {
+            // Read one byte more than allowed to find out whether the body is too large
+            let read = response.by_ref().take(limit + 1).read_to_end(&mut body);
+            if body.len() as u64 > limit {
+                return Err(RadError::ResponseTooLarge { limit });
+            }
+
+            read
+        }
+        None => response.read_to_end(&mut body),
+    };
+    read.map_err(|error| match limits.timeout {
+        Some(timeout) if error.kind() == io::ErrorKind::TimedOut => RadError::RetrievalTimeout {
+            millis: timeout.as_millis(),
+        },
+        _ => RadError::Http {
+            message: error.to_string(),
+        },
+    })?;
+
+    decode_body(body)
+}
+
+/// Reader that fails with `TimedOut` once its deadline is over.
+struct DeadlineReader<R> {
+    reader: R,
+    deadline: Option<Instant>,
+}
+
+impl<R: Read> Read for DeadlineReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "the deadline of the request is over",
+            )),
+            _ => self.reader.read(buf),
+        }
+    }
+}
 
-            let input = RadonTypes::from(RadonString::from(response));
-            let radon_script = unpack_radon_script(&retrieve.script)?;
+/// Read the body of the response of an HTTP source as a string, as `read_response` does, but
+/// without blocking.
+fn read_response_async(
+    response: AsyncResponse,
+    limits: RetrievalLimits,
+) -> impl Future<Item = RadonTypes, Error = RadError> {
+    let checked = check_response(response.headers(), response.content_length(), &limits);
+
+    future::result(checked)
+        .and_then(move |()| {
+            let limit = limits.max_response_size;
+            response
+                .into_body()
+                .map_err(move |error| http_error(error, &limits))
+                .fold(Vec::new(), move |mut body, chunk| {
+                    body.extend_from_slice(&chunk);
+                    match limit {
+                        Some(limit) if body.len() as u64 > limit => {
+                            Err(RadError::ResponseTooLarge { limit })
+                        }
+                        _ => Ok(body),
+                    }
+                })
+        })
+        .and_then(decode_body)
+}
+
+/// Check the content type and the length of the response of an HTTP source, as declared in its
+/// headers, against the limits.
+fn check_response(
+    headers: &HeaderMap,
+    content_length: Option<u64>,
+    limits: &RetrievalLimits,
+) -> Result<()> {
+    if let Some(content_type) = headers.get(CONTENT_TYPE) {
+        let content_type = content_type
+            .to_str()
+            .map_err(|_| RadError::UnsupportedContentType {
+                content_type: String::from_utf8_lossy(content_type.as_bytes()).into_owned(),
+            })?;
+        check_content_type(content_type, &limits.content_types)?;
+    }
+
+    match (content_length, limits.max_response_size) {
+        (Some(length), Some(limit)) if length > limit => Err(RadError::ResponseTooLarge { limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Decode the body of the response of an HTTP source, which must be UTF-8.
+fn decode_body(body: Vec<u8>) -> Result<RadonTypes> {
+    let body = String::from_utf8(body).map_err(|_| RadError::Decode {
+        from: "response body".to_string(),
+        to: RADON_STRING_TYPE_NAME.to_string(),
+    })?;
+
+    Ok(RadonTypes::from(RadonString::from(body)))
+}
+
+/// Check that the media type of the `Content-Type` of a response is accepted, and that its
+/// charset, if any, is UTF-8 or a subset of it.
+fn check_content_type(content_type: &str, accepted: &[String]) -> Result<()> {
+    let mut parts = content_type.split(';').map(str::trim);
+    let media_type = parts.next().unwrap_or_default();
+    let is_accepted = accepted.is_empty()
+        || accepted
+            .iter()
+            .any(|accepted| accepted.eq_ignore_ascii_case(media_type));
+    if !is_accepted {
+        return Err(RadError::UnsupportedContentType {
+            content_type: content_type.to_string(),
+        });
+    }
 
-            execute_radon_script(input, &radon_script)
+    for parameter in parts {
+        let mut name_and_value = parameter.splitn(2, '=');
+        match (name_and_value.next(), name_and_value.next()) {
+            (Some(name), Some(value)) if name.trim().eq_ignore_ascii_case("charset") => {
+                let charset = value.trim().trim_matches('"');
+                let is_utf8 = ["utf-8", "utf8", "us-ascii"]
+                    .iter()
+                    .any(|utf8| utf8.eq_ignore_ascii_case(charset));
+                if !is_utf8 {
+                    return Err(RadError::UnsupportedCharset {
+                        charset: charset.to_string(),
+                    });
+                }
+            }
+            _ => {}
         }
     }
+
+    Ok(())
+}
+
+/// Hashes of the data requests whose tallies are read by the `TALLY` sources of a RAD request,
+/// which have to be put into its [`RetrievalContext`](RetrievalContext) before resolving it.
+pub fn tally_sources(request: &RADRequest) -> Vec<Hash> {
+    request
+        .retrieve
+        .iter()
+        .filter(|retrieve| retrieve.kind == RADType::Tally)
+        .filter_map(|retrieve| retrieve.url.parse().ok())
+        .collect()
 }
 
 /// Run aggregate stage of a data request.
@@ -67,8 +461,141 @@ pub fn run_consensus(
     rad_consensus.try_into().map_err(Into::into)
 }
 
-/// Run deliver clauses of a data request.
-pub fn run_delivery() {}
+/// Run aggregate stage of a data request, recording the execution of every call of its script.
+pub fn run_aggregation_with_trace(
+    radon_types_vec: Vec<RadonTypes>,
+    aggregate: &RADAggregate,
+) -> (Result<Vec<u8>>, Vec<RadonCallTrace>) {
+    run_tally_script_with_trace(radon_types_vec, &aggregate.script)
+}
+
+/// Run consensus stage of a data request, recording the execution of every call of its script.
+pub fn run_consensus_with_trace(
+    radon_types_vec: Vec<RadonTypes>,
+    consensus: &RADConsensus,
+) -> (Result<Vec<u8>>, Vec<RadonCallTrace>) {
+    run_tally_script_with_trace(radon_types_vec, &consensus.script)
+}
+
+/// Run a packed script on an array of values, as the aggregate and consensus stages do.
+fn run_tally_script_with_trace(
+    radon_types_vec: Vec<RadonTypes>,
+    packed_script: &[u8],
+) -> (Result<Vec<u8>>, Vec<RadonCallTrace>) {
+    let radon_script = match unpack_radon_script(packed_script) {
+        Ok(radon_script) => radon_script,
+        Err(error) => return (Err(error), vec![]),
+    };
+
+    let radon_array = RadonArray::from(radon_types_vec);
+
+    let (result, trace) =
+        execute_radon_script_with_trace(RadonTypes::from(radon_array), &radon_script);
+
+    (result.and_then(TryInto::try_into), trace)
+}
+
+/// Run all the stages of a RAD request up to the consensus, recording the execution of every call
+/// of its scripts so that request authors can debug them step by step. Retrieval stops at the
+/// first source that fails, and so does any other stage, but the trace of the stages that were
+/// run is returned anyway.
+pub fn run_request_with_trace(
+    request: &RADRequest,
+    context: &RetrievalContext,
+) -> (Result<RadonTypes>, RADRequestTrace) {
+    let mut trace = RADRequestTrace::default();
+
+    let mut retrievals = Vec::with_capacity(request.retrieve.len());
+    for retrieve in &request.retrieve {
+        let (result, retrieval_trace) = run_retrieval_with_trace(retrieve, context);
+        trace.retrieval.push(retrieval_trace);
+        match result {
+            Ok(retrieval) => retrievals.push(retrieval),
+            Err(error) => return (Err(error), trace),
+        }
+    }
+
+    let (result, aggregation_trace) = run_aggregation_with_trace(retrievals, &request.aggregate);
+    trace.aggregation = aggregation_trace;
+    let aggregated = match result.and_then(|aggregated| RadonTypes::try_from(aggregated.as_slice()))
+    {
+        Ok(aggregated) => aggregated,
+        Err(error) => return (Err(error), trace),
+    };
+
+    let (result, consensus_trace) = run_consensus_with_trace(vec![aggregated], &request.consensus);
+    trace.consensus = consensus_trace;
+    let result = result.and_then(|consensus| RadonTypes::try_from(consensus.as_slice()));
+
+    (result, trace)
+}
+
+/// Run consensus stage of a data request on the reveals of its witnesses. Along with the tally, it
+/// tells for each reveal whether it is honest, that is, whether it could be decoded and survived
+/// the filters of the consensus script.
+pub fn run_consensus_on_reveals(
+    reveals: &[&[u8]],
+    consensus: &RADConsensus,
+) -> Result<(Vec<u8>, Vec<bool>)> {
+    let radon_script = unpack_radon_script(consensus.script.as_slice())?;
+
+    let decoded: Vec<Option<RadonTypes>> = reveals
+        .iter()
+        .map(|&reveal| RadonTypes::try_from(reveal).ok())
+        .collect();
+    let radon_array = RadonArray::from(decoded.iter().flatten().cloned().collect::<Vec<_>>());
+
+    let (rad_consensus, survived) =
+        execute_radon_script_with_survivors(radon_array, &radon_script)?;
+    let tally = rad_consensus.try_into()?;
+
+    // Reveals that could not be decoded never made it into the consensus
+    let mut survived = survived.into_iter();
+    let honest = decoded
+        .iter()
+        .map(|reveal| reveal.is_some() && survived.next().unwrap_or(false))
+        .collect();
+
+    Ok((tally, honest))
+}
+
+/// Run a deliver clause of a data request, that is, send the JSON payload that proves its result
+/// to the endpoint in a single HTTP POST request. Any HTTP deliver clause is delivered through
+/// POST, as the payload goes in the body of the request.
+pub fn run_delivery(
+    deliver: &RADDeliver,
+    payload: &[u8],
+    limits: &RetrievalLimits,
+) -> impl Future<Item = (), Error = RadError> {
+    let request = match deliver.kind {
+        RADType::HttpGet | RADType::HttpPost => async_http_client(limits).map(|client| {
+            client
+                .post(&deliver.url)
+                .header(CONTENT_TYPE, "application/json")
+                .body(payload.to_vec())
+        }),
+        ref kind => Err(RadError::UnsupportedDelivery {
+            kind: format!("{:?}", kind),
+        }),
+    };
+    let limits = limits.clone();
+
+    future::result(request).and_then(move |request| {
+        request
+            .send()
+            .map_err(move |error| http_error(error, &limits))
+            .and_then(|response| {
+                let status = response.status();
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    Err(RadError::HttpStatus {
+                        status: status.as_u16(),
+                    })
+                }
+            })
+    })
+}
 
 #[test]
 fn test_run_retrieval() {
@@ -80,10 +607,11 @@ fn test_run_retrieval() {
     let retrieve = RADRetrieve {
         kind: RADType::HttpGet,
         url: "https://openweathermap.org/data/2.5/weather?id=2950159&appid=b6907d289e10d714a6e88b30761fae22".to_string(),
-        script
+        script,
+        ..RADRetrieve::default()
     };
 
-    let result = run_retrieval(&retrieve).unwrap();
+    let result = run_retrieval(&retrieve, &RetrievalContext::default()).unwrap();
 
     match result {
         RadonTypes::Float(_) => {}
@@ -91,6 +619,366 @@ fn test_run_retrieval() {
     }
 }
 
+#[test]
+fn test_run_retrieval_rng() {
+    use crate::types::bytes::RadonBytes;
+
+    let retrieve = RADRetrieve {
+        kind: RADType::Rng,
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+
+    let result = run_retrieval(&retrieve, &RetrievalContext::default());
+    assert_eq!(result, Err(RadError::MissingRngSeed));
+
+    let seed = rng_seed(CheckpointBeacon::default(), Hash::default());
+    let context = RetrievalContext {
+        rng_seed: Some(seed),
+        ..RetrievalContext::default()
+    };
+    let Hash::SHA256(seed_bytes) = seed;
+    let expected = RadonTypes::from(RadonBytes::from(seed_bytes.to_vec()));
+    assert_eq!(run_retrieval(&retrieve, &context), Ok(expected));
+
+    // Other data requests get different seeds out of the same beacon
+    let other_seed = rng_seed(CheckpointBeacon::default(), Hash::SHA256([1; 32]));
+    assert_ne!(seed, other_seed);
+}
+
+#[test]
+fn test_run_retrieval_tally() {
+    use crate::types::float::RadonFloat;
+
+    let dr_pointer = Hash::SHA256([1; 32]);
+    let retrieve = RADRetrieve {
+        kind: RADType::Tally,
+        url: dr_pointer.to_string(),
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+    let request = RADRequest {
+        retrieve: vec![retrieve.clone()],
+        ..RADRequest::default()
+    };
+    assert_eq!(tally_sources(&request), vec![dr_pointer]);
+
+    let result = run_retrieval(&retrieve, &RetrievalContext::default());
+    assert_eq!(
+        result,
+        Err(RadError::TallyNotFound {
+            dr_pointer: dr_pointer.to_string()
+        })
+    );
+
+    let tally = RadonTypes::from(RadonFloat::from(1f64));
+    let mut context = RetrievalContext::default();
+    context
+        .tallies
+        .insert(dr_pointer, tally.clone().try_into().unwrap());
+    assert_eq!(run_retrieval(&retrieve, &context), Ok(tally));
+}
+
+#[test]
+fn test_run_retrieval_async_rng() {
+    use crate::types::bytes::RadonBytes;
+
+    let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+    let client = async_http_client(&RetrievalLimits::default()).unwrap();
+    let retrieve = RADRetrieve {
+        kind: RADType::Rng,
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+
+    let result = runtime.block_on(run_retrieval_async(
+        &retrieve,
+        &RetrievalContext::default(),
+        &client,
+    ));
+    assert_eq!(result, Err(RadError::MissingRngSeed));
+
+    let seed = rng_seed(CheckpointBeacon::default(), Hash::default());
+    let context = RetrievalContext {
+        rng_seed: Some(seed),
+        ..RetrievalContext::default()
+    };
+    let Hash::SHA256(seed_bytes) = seed;
+    let expected = RadonTypes::from(RadonBytes::from(seed_bytes.to_vec()));
+    let result = runtime.block_on(run_retrieval_async(&retrieve, &context, &client));
+    assert_eq!(result, Ok(expected));
+}
+
+#[test]
+fn test_run_retrieval_async_tally() {
+    use crate::types::float::RadonFloat;
+
+    let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+    let client = async_http_client(&RetrievalLimits::default()).unwrap();
+    let dr_pointer = Hash::SHA256([1; 32]);
+    let retrieve = RADRetrieve {
+        kind: RADType::Tally,
+        url: dr_pointer.to_string(),
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+
+    let result = runtime.block_on(run_retrieval_async(
+        &retrieve,
+        &RetrievalContext::default(),
+        &client,
+    ));
+    assert_eq!(
+        result,
+        Err(RadError::TallyNotFound {
+            dr_pointer: dr_pointer.to_string()
+        })
+    );
+
+    let invalid = RADRetrieve {
+        url: "not a hash".to_string(),
+        ..retrieve.clone()
+    };
+    let result = runtime.block_on(run_retrieval_async(
+        &invalid,
+        &RetrievalContext::default(),
+        &client,
+    ));
+    assert_eq!(
+        result,
+        Err(RadError::InvalidTallySource {
+            url: "not a hash".to_string()
+        })
+    );
+
+    let tally = RadonTypes::from(RadonFloat::from(1f64));
+    let mut context = RetrievalContext::default();
+    context
+        .tallies
+        .insert(dr_pointer, tally.clone().try_into().unwrap());
+    let result = runtime.block_on(run_retrieval_async(&retrieve, &context, &client));
+    assert_eq!(result, Ok(tally));
+}
+
+/// Local HTTP stub that answers every request it receives by writing into the stream with the
+/// given function, which gets the number of requests received before.
+#[cfg(test)]
+fn http_stub<F>(answer: F) -> String
+where
+    F: Fn(&mut std::net::TcpStream, usize) -> io::Result<()> + Send + 'static,
+{
+    use std::net::TcpListener;
+    use std::thread;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for (count, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            stream.set_nodelay(true).unwrap();
+            // Read the head of the request, as these sources do not send any body
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                match stream.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => request.extend_from_slice(&buffer[..read]),
+                }
+            }
+            // The client may hang up before the whole answer is written
+            answer(&mut stream, count).ok();
+        }
+    });
+
+    url
+}
+
+#[test]
+fn test_run_retrieval_response_size() {
+    use std::io::Write;
+
+    let limits = RetrievalLimits {
+        max_response_size: Some(10),
+        ..RetrievalLimits::default()
+    };
+    let retrieve = |url| RADRetrieve {
+        kind: RADType::HttpGet,
+        url,
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+    let context = RetrievalContext {
+        limits,
+        ..RetrievalContext::default()
+    };
+
+    // The declared length is checked before reading the body
+    let url = http_stub(|stream, _| {
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: 11\r\n\r\n0123456789a"
+        )
+    });
+    assert_eq!(
+        run_retrieval(&retrieve(url), &context),
+        Err(RadError::ResponseTooLarge { limit: 10 })
+    );
+
+    // Bodies without a declared length are not read beyond the limit
+    let url = http_stub(|stream, _| {
+        write!(stream, "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n")?;
+        stream.write_all(&[b'a'; 1024])
+    });
+    assert_eq!(
+        run_retrieval(&retrieve(url), &context),
+        Err(RadError::ResponseTooLarge { limit: 10 })
+    );
+
+    let url = http_stub(|stream, _| {
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n0123456789"
+        )
+    });
+    assert_eq!(
+        run_retrieval(&retrieve(url), &context),
+        Ok(RadonTypes::from(RadonString::from("0123456789")))
+    );
+}
+
+#[test]
+fn test_run_retrieval_redirects() {
+    use std::io::Write;
+
+    let context = RetrievalContext {
+        limits: RetrievalLimits {
+            max_redirects: 2,
+            ..RetrievalLimits::default()
+        },
+        ..RetrievalContext::default()
+    };
+    // Redirect to a different path every time the given number of times, and then answer
+    let redirecting = |redirects| {
+        let url = http_stub(move |stream, count| {
+            if count < redirects {
+                write!(
+                    stream,
+                    "HTTP/1.1 302 Found\r\nLocation: /{}\r\nContent-Length: 0\r\n\r\n",
+                    count + 1
+                )
+            } else {
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+            }
+        });
+
+        RADRetrieve {
+            kind: RADType::HttpGet,
+            url,
+            // [ Identity ]
+            script: vec![145, 0],
+            ..RADRetrieve::default()
+        }
+    };
+
+    assert_eq!(
+        run_retrieval(&redirecting(1), &context),
+        Ok(RadonTypes::from(RadonString::from("ok")))
+    );
+    assert_eq!(
+        run_retrieval(&redirecting(3), &context),
+        Err(RadError::TooManyRedirects { limit: 2 })
+    );
+}
+
+#[test]
+fn test_run_retrieval_timeout() {
+    use std::io::Write;
+    use std::thread;
+
+    let context = RetrievalContext {
+        limits: RetrievalLimits {
+            timeout: Some(Duration::from_millis(300)),
+            ..RetrievalLimits::default()
+        },
+        ..RetrievalContext::default()
+    };
+    let retrieve = |url| RADRetrieve {
+        kind: RADType::HttpGet,
+        url,
+        // [ Identity ]
+        script: vec![145, 0],
+        ..RADRetrieve::default()
+    };
+
+    // No response at all
+    let url = http_stub(|_, _| {
+        thread::sleep(Duration::from_secs(2));
+        Ok(())
+    });
+    assert_eq!(
+        run_retrieval(&retrieve(url), &context),
+        Err(RadError::RetrievalTimeout { millis: 300 })
+    );
+
+    // A body sent slowly enough to take longer than the timeout, even if every single read
+    // takes less than that
+    let url = http_stub(|stream, _| {
+        write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n")?;
+        for _ in 0..10 {
+            thread::sleep(Duration::from_millis(100));
+            stream.write_all(b"a")?;
+        }
+
+        Ok(())
+    });
+    let started = Instant::now();
+    assert_eq!(
+        run_retrieval(&retrieve(url), &context),
+        Err(RadError::RetrievalTimeout { millis: 300 })
+    );
+    assert!(started.elapsed() < Duration::from_millis(900));
+}
+
+#[test]
+fn test_check_content_type() {
+    let accepted = vec!["application/json".to_string(), "text/plain".to_string()];
+
+    assert_eq!(check_content_type("application/json", &accepted), Ok(()));
+    assert_eq!(
+        check_content_type("Text/Plain; charset=\"UTF-8\"", &accepted),
+        Ok(())
+    );
+    assert_eq!(check_content_type("image/png", &[]), Ok(()));
+    assert_eq!(
+        check_content_type("image/png", &accepted),
+        Err(RadError::UnsupportedContentType {
+            content_type: "image/png".to_string()
+        })
+    );
+    assert_eq!(
+        check_content_type("text/plain; charset=ISO-8859-1", &accepted),
+        Err(RadError::UnsupportedCharset {
+            charset: "ISO-8859-1".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_retrieval_limits_from_config() {
+    let config = witnet_config::config::Config::default().rad;
+    let limits = RetrievalLimits::from(&config);
+
+    assert_eq!(limits.timeout, Some(config.retrieval_timeout));
+    assert_eq!(limits.max_response_size, Some(config.max_response_size));
+    assert_eq!(limits.max_redirects, config.max_redirects);
+    assert_eq!(limits.content_types, config.content_types);
+}
+
 #[test]
 fn test_run_consensus_and_aggregation() {
     use crate::types::float::RadonFloat;
@@ -123,6 +1011,110 @@ fn test_run_consensus_and_aggregation() {
     assert_eq!(output_aggregate, expected);
 }
 
+#[test]
+fn test_run_aggregation_filtering_outliers() {
+    use crate::types::float::RadonFloat;
+
+    let radon_types_vec = [1.0, 1.1, 0.9, 1.0, 10.0]
+        .iter()
+        .map(|price| RadonTypes::from(RadonFloat::from(*price)))
+        .collect();
+
+    // [ [ ArrayFilter, DeviationStandard, 1.5 ], [ Reduce, AverageMedian ] ]
+    let script = rmpv::Value::from(vec![
+        rmpv::Value::from(vec![
+            rmpv::Value::from(0x62),
+            rmpv::Value::from(0x30),
+            rmpv::Value::from(1.5),
+        ]),
+        rmpv::Value::from(vec![rmpv::Value::from(0x66), rmpv::Value::from(0x22)]),
+    ]);
+    let mut packed_script = Vec::new();
+    rmpv::encode::write_value(&mut packed_script, &script).unwrap();
+
+    let expected = RadonTypes::Float(RadonFloat::from(1f64)).try_into().ok();
+
+    let output = run_aggregation(
+        radon_types_vec,
+        &RADAggregate {
+            script: packed_script,
+        },
+    )
+    .ok();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_run_aggregation_with_trace() {
+    use crate::types::{float::RadonFloat, string::RadonString};
+
+    let aggregate = RADAggregate {
+        // [ [ Reduce, AverageMean ] ]
+        script: vec![145, 146, 102, 32],
+    };
+
+    let floats = vec![
+        RadonTypes::from(RadonFloat::from(1f64)),
+        RadonTypes::from(RadonFloat::from(3f64)),
+    ];
+    let (output, trace) = run_aggregation_with_trace(floats, &aggregate);
+
+    let expected: Vec<u8> = RadonTypes::from(RadonFloat::from(2f64)).try_into().unwrap();
+    assert_eq!(output.unwrap(), expected);
+    assert_eq!(trace.len(), 1);
+    assert_eq!(trace[0].operator, "Reduce");
+    assert_eq!(trace[0].args, Some(vec!["32".to_string()]));
+
+    let strings = vec![RadonTypes::from(RadonString::from("1"))];
+    let (output, trace) = run_aggregation_with_trace(strings, &aggregate);
+
+    assert!(output.is_err());
+    assert_eq!(trace.len(), 1);
+    assert!(trace[0].output.is_none());
+    assert!(trace[0].error.is_some());
+}
+
+#[test]
+fn test_run_consensus_on_reveals() {
+    use crate::types::float::RadonFloat;
+
+    let reveals: Vec<Vec<u8>> = [1.0, 1.1, 10.0, 0.9]
+        .iter()
+        .map(|price| {
+            RadonTypes::from(RadonFloat::from(*price))
+                .try_into()
+                .unwrap()
+        })
+        .chain(std::iter::once(vec![0xc1]))
+        .collect();
+    let reveals: Vec<&[u8]> = reveals.iter().map(Vec::as_slice).collect();
+
+    // [ [ ArrayFilter, DeviationStandard, 1 ], [ Reduce, AverageMean ] ]
+    let script = rmpv::Value::from(vec![
+        rmpv::Value::from(vec![
+            rmpv::Value::from(0x62),
+            rmpv::Value::from(0x30),
+            rmpv::Value::from(1),
+        ]),
+        rmpv::Value::from(vec![rmpv::Value::from(0x66), rmpv::Value::from(0x20)]),
+    ]);
+    let mut packed_script = Vec::new();
+    rmpv::encode::write_value(&mut packed_script, &script).unwrap();
+
+    let (tally, honest) = run_consensus_on_reveals(
+        &reveals,
+        &RADConsensus {
+            script: packed_script,
+        },
+    )
+    .unwrap();
+
+    let expected: Vec<u8> = RadonTypes::from(RadonFloat::from(1f64)).try_into().unwrap();
+    assert_eq!(tally, expected);
+    assert_eq!(honest, vec![true, true, false, true, false]);
+}
+
 #[test]
 #[ignore]
 fn test_run_retrieval_random_api() {
@@ -133,12 +1125,91 @@ fn test_run_retrieval_random_api() {
         kind: RADType::HttpGet,
         url: "http://qrng.anu.edu.au/API/jsonI.php?length=1&type=uint8".to_string(),
         script,
+        ..RADRetrieve::default()
     };
 
-    let result = run_retrieval(&retrieve).unwrap();
+    let result = run_retrieval(&retrieve, &RetrievalContext::default()).unwrap();
 
     match result {
         RadonTypes::Float(_) => {}
         err => panic!("Error in run_retrieval: {:?}", err),
     }
 }
+
+#[test]
+fn test_run_delivery() {
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    // Local HTTP stub that answers every request with the given status, and hands out the body
+    // of the requests it receives
+    fn stub(status: &'static str) -> (String, mpsc::Receiver<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/results", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            let body = loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let request = String::from_utf8_lossy(&request).into_owned();
+                if let Some(end) = request.find("\r\n\r\n") {
+                    let length: usize = request
+                        .lines()
+                        .find(|line| line.to_lowercase().starts_with("content-length:"))
+                        .and_then(|line| line[15..].trim().parse().ok())
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + length {
+                        break request.as_bytes()[end + 4..end + 4 + length].to_vec();
+                    }
+                }
+            };
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status).unwrap();
+            sender.send(body).unwrap();
+        });
+
+        (url, receiver)
+    }
+
+    let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+    let limits = RetrievalLimits {
+        timeout: Some(Duration::from_secs(5)),
+        ..RetrievalLimits::default()
+    };
+    let payload = br#"{"dr_pointer":"00"}"#;
+
+    let (url, received) = stub("200 OK");
+    let deliver = RADDeliver {
+        kind: RADType::HttpPost,
+        url,
+    };
+    runtime
+        .block_on(run_delivery(&deliver, payload, &limits))
+        .unwrap();
+    assert_eq!(received.recv().unwrap(), payload.to_vec());
+
+    let (url, _received) = stub("500 Internal Server Error");
+    let deliver = RADDeliver {
+        kind: RADType::HttpPost,
+        url,
+    };
+    assert_eq!(
+        runtime.block_on(run_delivery(&deliver, payload, &limits)),
+        Err(RadError::HttpStatus { status: 500 })
+    );
+
+    let deliver = RADDeliver {
+        kind: RADType::Rng,
+        url: String::new(),
+    };
+    assert_eq!(
+        runtime.block_on(run_delivery(&deliver, payload, &limits)),
+        Err(RadError::UnsupportedDelivery {
+            kind: "Rng".to_string()
+        })
+    );
+}
@@ -1,9 +1,245 @@
+use std::cmp::Ordering;
+
 use crate::error::RadError;
+use crate::filters::{self, RadonFilters};
 use crate::reducers::{self, RadonReducers};
-use crate::types::{array::RadonArray, RadonType, RadonTypes};
+use crate::script::{execute_radon_script, unpack_subscript, RadonCall};
+use crate::types::{
+    array::RadonArray, boolean::RadonBoolean, integer::RadonInteger, RadonType, RadonTypes,
+};
 use num_traits::FromPrimitive;
 use rmpv::Value;
 
+fn wrong_arguments(operator: &str, args: &[Value]) -> RadError {
+    RadError::WrongArguments {
+        input_type: "RadonArray".to_string(),
+        operator: operator.to_string(),
+        args: args.to_vec(),
+    }
+}
+
+/// Unpack the subscript passed as the first argument of an operator.
+fn subscript_argument(operator: &str, args: &[Value]) -> Result<Vec<RadonCall>, RadError> {
+    args.first()
+        .ok_or_else(|| wrong_arguments(operator, args))
+        .and_then(unpack_subscript)
+}
+
+/// Run a subscript on an item, requiring its output to be a boolean.
+fn predicate(operator: &str, item: RadonTypes, subscript: &[RadonCall]) -> Result<bool, RadError> {
+    match execute_radon_script(item, subscript)? {
+        RadonTypes::Boolean(boolean) => Ok(boolean.value()),
+        other => Err(RadError::WrongSubscriptOutput {
+            operator: operator.to_string(),
+            expected_type: "RadonBoolean".to_string(),
+            value: other.to_string(),
+        }),
+    }
+}
+
+pub fn count(input: &RadonArray) -> RadonInteger {
+    RadonInteger::from(input.value().len() as i64)
+}
+
+/// Whether the subscript given as argument returns `true` for every item.
+pub fn every(input: &RadonArray, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let subscript = subscript_argument("Every", args)?;
+    for item in input.value() {
+        if !predicate("Every", item, &subscript)? {
+            return Ok(RadonBoolean::from(false));
+        }
+    }
+
+    Ok(RadonBoolean::from(true))
+}
+
+/// Whether the subscript given as argument returns `true` for at least one item.
+pub fn some(input: &RadonArray, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let subscript = subscript_argument("Some", args)?;
+    for item in input.value() {
+        if predicate("Some", item, &subscript)? {
+            return Ok(RadonBoolean::from(true));
+        }
+    }
+
+    Ok(RadonBoolean::from(false))
+}
+
+/// Keep only the items for which the subscript given as argument returns `true`. Instead of a
+/// subscript, the code of one of the predefined filters can be given, followed by its argument.
+pub fn filter(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    filter_with_positions(input, args).map(|(output, _)| output)
+}
+
+/// Filter the input like `filter` does, also returning the positions that the items that are kept
+/// had in the input.
+pub fn filter_with_positions(
+    input: &RadonArray,
+    args: &[Value],
+) -> Result<(RadonArray, Vec<usize>), RadError> {
+    let items = input.value();
+    let positions = if let Some(filter_integer) = args.first().and_then(Value::as_i64) {
+        let filter_code = RadonFilters::from_i64(filter_integer)
+            .ok_or_else(|| wrong_arguments("Filter", args))?;
+        let factor = match args.get(1) {
+            Some(factor) => Some(
+                factor
+                    .as_f64()
+                    .or_else(|| factor.as_i64().map(|factor| factor as f64))
+                    .ok_or_else(|| wrong_arguments("Filter", args))?,
+            ),
+            None => None,
+        };
+
+        filters::filter_positions(input, filter_code, factor)?
+    } else {
+        let subscript = subscript_argument("Filter", args)?;
+        let mut positions = Vec::new();
+        for (position, item) in items.iter().enumerate() {
+            if predicate("Filter", item.clone(), &subscript)? {
+                positions.push(position);
+            }
+        }
+
+        positions
+    };
+    let output = positions
+        .iter()
+        .map(|position| items[*position].clone())
+        .collect::<Vec<_>>();
+
+    Ok((RadonArray::from(output), positions))
+}
+
+/// Concatenate the nested arrays into the input array, up to the depth given as argument, which
+/// is 1 by default.
+pub fn flatten(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    let depth = match args.first() {
+        Some(depth) => depth
+            .as_u64()
+            .ok_or_else(|| wrong_arguments("Flatten", args))?,
+        None => 1,
+    };
+
+    fn flatten_items(items: Vec<RadonTypes>, depth: u64, result: &mut Vec<RadonTypes>) {
+        for item in items {
+            match item {
+                RadonTypes::Array(array) if depth > 0 => {
+                    flatten_items(array.value(), depth - 1, result)
+                }
+                item => result.push(item),
+            }
+        }
+    }
+
+    let mut result = Vec::new();
+    flatten_items(input.value(), depth, &mut result);
+
+    Ok(RadonArray::from(result))
+}
+
+/// Apply the subscript given as argument to every item.
+pub fn map(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    let subscript = subscript_argument("Map", args)?;
+
+    input
+        .value()
+        .into_iter()
+        .map(|item| execute_radon_script(item, &subscript))
+        .collect::<Result<Vec<RadonTypes>, RadError>>()
+        .map(RadonArray::from)
+}
+
+/// Sort the items by the values returned by the subscript given as first argument, which must
+/// be booleans, integers, floats or strings of the same type. The optional second argument tells
+/// whether the order is ascending, which is the default.
+pub fn sort(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    let subscript = subscript_argument("Sort", args)?;
+    let ascending = match args.get(1) {
+        Some(ascending) => ascending
+            .as_bool()
+            .ok_or_else(|| wrong_arguments("Sort", args))?,
+        None => true,
+    };
+
+    let mut keyed = input
+        .value()
+        .into_iter()
+        .map(|item| execute_radon_script(item.clone(), &subscript).map(|key| (key, item)))
+        .collect::<Result<Vec<(RadonTypes, RadonTypes)>, RadError>>()?;
+
+    let mut error = None;
+    keyed.sort_by(|(a, _), (b, _)| {
+        let ordering = match (a, b) {
+            (RadonTypes::Boolean(a), RadonTypes::Boolean(b)) => Some(a.value().cmp(&b.value())),
+            (RadonTypes::Integer(a), RadonTypes::Integer(b)) => Some(a.value().cmp(&b.value())),
+            (RadonTypes::Float(a), RadonTypes::Float(b)) => a.value().partial_cmp(&b.value()),
+            (RadonTypes::String(a), RadonTypes::String(b)) => Some(a.value().cmp(&b.value())),
+            _ => None,
+        };
+
+        ordering.unwrap_or_else(|| {
+            error.get_or_insert_with(|| RadError::WrongSubscriptOutput {
+                operator: "Sort".to_string(),
+                expected_type: "comparable value".to_string(),
+                value: format!("{} and {}", a, b),
+            });
+            Ordering::Equal
+        })
+    });
+    if let Some(error) = error {
+        return Err(error);
+    }
+    if !ascending {
+        keyed.reverse();
+    }
+
+    Ok(RadonArray::from(
+        keyed.into_iter().map(|(_, item)| item).collect::<Vec<_>>(),
+    ))
+}
+
+/// Take at least `min` and at most `max` items from the beginning of the input. If `max` is not
+/// given, all the items are taken.
+pub fn take(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    let argument = |index: usize| -> Result<Option<usize>, RadError> {
+        args.get(index)
+            .map(|arg| {
+                arg.as_u64()
+                    .map(|arg| arg as usize)
+                    .ok_or_else(|| wrong_arguments("Take", args))
+            })
+            .transpose()
+    };
+    let min = argument(0)?.unwrap_or(0);
+    let max = argument(1)?;
+
+    let mut items = input.value();
+    if items.len() < min {
+        return Err(RadError::NotEnoughItems {
+            required: min,
+            found: items.len(),
+        });
+    }
+    if let Some(max) = max {
+        items.truncate(max);
+    }
+
+    Ok(RadonArray::from(items))
+}
+
+/// Drop the given number of items from the beginning of the input.
+pub fn skip(input: &RadonArray, args: &[Value]) -> Result<RadonArray, RadError> {
+    let skipped = args
+        .first()
+        .and_then(Value::as_u64)
+        .ok_or_else(|| wrong_arguments("Skip", args))? as usize;
+
+    Ok(RadonArray::from(
+        input.value().into_iter().skip(skipped).collect::<Vec<_>>(),
+    ))
+}
+
 pub fn reduce(input: &RadonArray, args: &[Value]) -> Result<RadonTypes, RadError> {
     let error = || RadError::WrongArguments {
         input_type: "RadonArray".to_string(),
@@ -14,7 +250,31 @@ pub fn reduce(input: &RadonArray, args: &[Value]) -> Result<RadonTypes, RadError
     let reducer_integer = args.first().ok_or_else(error)?.as_i64().ok_or_else(error)?;
     let reducer_code = RadonReducers::from_i64(reducer_integer).ok_or_else(error)?;
 
-    reducers::reduce(input, reducer_code)
+    // Weighted reducers take as second argument the weight of each one of the items
+    let weights = if reducer_code.is_weighted() {
+        let weights = args
+            .get(1)
+            .and_then(Value::as_array)
+            .ok_or_else(error)?
+            .iter()
+            .map(|weight| {
+                weight
+                    .as_f64()
+                    .or_else(|| weight.as_i64().map(|w| w as f64))
+            })
+            .collect::<Option<Vec<f64>>>()
+            .ok_or_else(error)?;
+        let is_valid = |weight: &f64| weight.is_finite() && *weight >= 0f64;
+        if weights.len() != input.value().len() || !weights.iter().all(is_valid) {
+            return Err(error());
+        }
+
+        Some(weights)
+    } else {
+        None
+    };
+
+    reducers::reduce(input, reducer_code, weights.as_deref())
 }
 
 pub fn get(input: &RadonArray, args: &[Value]) -> Result<RadonTypes, RadError> {
@@ -103,3 +363,228 @@ fn test_reduce_average_mean_float() {
 
     assert_eq!(output, expected);
 }
+
+#[test]
+fn test_reduce_average_median_weighted() {
+    use crate::types::float::RadonFloat;
+
+    let input = &RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+        RadonFloat::from(3f64).into(),
+    ]);
+    // This is RadonReducers::AverageMedianWeighted
+    let args = &[
+        Value::from(0x23),
+        Value::from(vec![Value::from(1), Value::from(1), Value::from(5)]),
+    ];
+    let expected = RadonTypes::from(RadonFloat::from(3f64));
+
+    let output = reduce(input, args).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_reduce_weighted_without_weights() {
+    use crate::types::float::RadonFloat;
+
+    let input = &RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+    ]);
+    let args = &[Value::from(0x21)]; // This is RadonReducers::AverageMeanWeighted
+
+    let result = reduce(input, args);
+
+    assert_eq!(
+        &result.unwrap_err().to_string(),
+        "Wrong `RadonArray::Reduce()` arguments: `[Integer(PosInt(33))]`"
+    );
+}
+
+#[test]
+fn test_reduce_weighted_invalid_weights() {
+    use crate::types::float::RadonFloat;
+
+    let input = &RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+    ]);
+
+    for weight in &[
+        -1f64,
+        std::f64::NAN,
+        std::f64::INFINITY,
+        std::f64::NEG_INFINITY,
+    ] {
+        // This is RadonReducers::AverageMedianWeighted
+        let args = &[
+            Value::from(0x23),
+            Value::from(vec![Value::from(1), Value::from(*weight)]),
+        ];
+
+        match reduce(input, args) {
+            Err(RadError::WrongArguments { .. }) => (),
+            result => panic!("Weight {} was not rejected: {:?}", weight, result),
+        }
+    }
+}
+
+#[cfg(test)]
+fn trades() -> RadonArray {
+    use crate::types::{map::RadonMap, mixed::RadonMixed};
+    use std::collections::HashMap;
+
+    let trade = |price: f64| -> RadonTypes {
+        let mut map = HashMap::new();
+        map.insert("price".to_string(), RadonMixed::from(Value::from(price)));
+        RadonMap::from(map).into()
+    };
+
+    RadonArray::from(vec![trade(3.0), trade(1.0), trade(2.0)])
+}
+
+#[cfg(test)]
+fn price_subscript() -> Value {
+    // [ [ Get, "price" ], ToFloat ]
+    Value::from(vec![
+        Value::from(vec![Value::from(0x01), Value::from("price")]),
+        Value::from(0x82),
+    ])
+}
+
+#[test]
+fn test_count() {
+    assert_eq!(count(&trades()), RadonInteger::from(3));
+}
+
+#[test]
+fn test_map() {
+    use crate::types::float::RadonFloat;
+
+    let output = map(&trades(), &[price_subscript()]).unwrap();
+    let expected = RadonArray::from(vec![
+        RadonFloat::from(3.0).into(),
+        RadonFloat::from(1.0).into(),
+        RadonFloat::from(2.0).into(),
+    ]);
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_filter_some_every() {
+    // [ [ Get, "price" ], ToFloat, [ FloatGreaterThan, 1.5 ] ]
+    let subscript = Value::from(vec![
+        Value::from(vec![Value::from(0x01), Value::from("price")]),
+        Value::from(0x82),
+        Value::from(vec![Value::from(0x3C), Value::from(1.5)]),
+    ]);
+
+    let filtered = filter(&trades(), &[subscript.clone()]).unwrap();
+
+    assert_eq!(count(&filtered), RadonInteger::from(2));
+    assert_eq!(
+        some(&trades(), &[subscript.clone()]).unwrap(),
+        RadonBoolean::from(true)
+    );
+    assert_eq!(
+        every(&trades(), &[subscript]).unwrap(),
+        RadonBoolean::from(false)
+    );
+    assert_eq!(
+        &filter(&trades(), &[price_subscript()])
+            .unwrap_err()
+            .to_string(),
+        "Subscript of `Filter()` was expected to return `RadonBoolean` but returned `RadonTypes::RadonFloat(3)`"
+    );
+}
+
+#[test]
+fn test_filter_predefined() {
+    use crate::types::float::RadonFloat;
+
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+    ]);
+    let expected = RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(1f64).into(),
+    ]);
+
+    // 0x10 is RadonFilters::Mode
+    let output = filter(&input, &[Value::from(0x10)]).unwrap();
+    // 0x30 is RadonFilters::DeviationStandard
+    let missing_argument = filter(&input, &[Value::from(0x30)]);
+
+    assert_eq!(output, expected);
+    assert_eq!(
+        &missing_argument.unwrap_err().to_string(),
+        "Filter `RadonFilters::DeviationStandard` needs an argument"
+    );
+}
+
+#[test]
+fn test_sort() {
+    let ascending = map(
+        &sort(&trades(), &[price_subscript()]).unwrap(),
+        &[price_subscript()],
+    )
+    .unwrap();
+    let descending = map(
+        &sort(&trades(), &[price_subscript(), Value::from(false)]).unwrap(),
+        &[price_subscript()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        ascending.to_string(),
+        "RadonArray([Float(RadonFloat { value: 1.0 }), Float(RadonFloat { value: 2.0 }), Float(RadonFloat { value: 3.0 })])"
+    );
+    assert_eq!(
+        descending.to_string(),
+        "RadonArray([Float(RadonFloat { value: 3.0 }), Float(RadonFloat { value: 2.0 }), Float(RadonFloat { value: 1.0 })])"
+    );
+}
+
+#[test]
+fn test_take_and_skip() {
+    let taken = take(&trades(), &[Value::from(1), Value::from(2)]).unwrap();
+    let not_enough = take(&trades(), &[Value::from(4)]);
+    let skipped = skip(&trades(), &[Value::from(2)]).unwrap();
+
+    assert_eq!(count(&taken), RadonInteger::from(2));
+    assert_eq!(
+        &not_enough.unwrap_err().to_string(),
+        "At least 4 items were required but the RadonArray only has 3"
+    );
+    assert_eq!(count(&skipped), RadonInteger::from(1));
+}
+
+#[test]
+fn test_flatten() {
+    let nested = RadonArray::from(vec![
+        RadonInteger::from(1).into(),
+        RadonArray::from(vec![
+            RadonInteger::from(2).into(),
+            RadonArray::from(vec![RadonInteger::from(3).into()]).into(),
+        ])
+        .into(),
+    ]);
+
+    let once = flatten(&nested, &[]).unwrap();
+    let twice = flatten(&nested, &[Value::from(2)]).unwrap();
+
+    assert_eq!(count(&once), RadonInteger::from(3));
+    assert_eq!(
+        twice,
+        RadonArray::from(vec![
+            RadonInteger::from(1).into(),
+            RadonInteger::from(2).into(),
+            RadonInteger::from(3).into(),
+        ])
+    );
+}
@@ -0,0 +1,56 @@
+use crate::error::RadError;
+use crate::operators;
+use crate::types::{boolean::RadonBoolean, string::RadonString, RadonType, RadonTypes};
+
+use rmpv::Value;
+
+pub fn match_value(input: &RadonBoolean, args: &[Value]) -> Result<RadonTypes, RadError> {
+    operators::match_value(&Value::from(input.value()), "RadonBoolean", "Match", args)
+}
+
+pub fn negate(input: &RadonBoolean) -> RadonBoolean {
+    RadonBoolean::from(!input.value())
+}
+
+pub fn to_string(input: &RadonBoolean) -> RadonString {
+    RadonString::from(input.value().to_string())
+}
+
+#[test]
+fn test_match_value() {
+    let input = RadonBoolean::from(false);
+    let categories = Value::from(vec![
+        Value::from(vec![Value::from(true), Value::from("Valid")]),
+        Value::from(vec![Value::from(false), Value::from("Invalid")]),
+    ]);
+
+    let output = match_value(&input, &[categories]).unwrap();
+
+    assert_eq!(output, RadonTypes::from(RadonString::from("Invalid")));
+}
+
+#[test]
+fn test_match_value_default() {
+    let input = RadonBoolean::from(false);
+    let categories = Value::from(vec![Value::from(vec![
+        Value::from(true),
+        Value::from("Valid"),
+    ])]);
+
+    let output = match_value(&input, &[categories.clone(), Value::from("Other")]).unwrap();
+    let unmatched = match_value(&input, &[categories]);
+
+    assert_eq!(output, RadonTypes::from(RadonString::from("Other")));
+    assert_eq!(
+        &unmatched.unwrap_err().to_string(),
+        "Value `false` does not match any category and no default value was given"
+    );
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!(
+        to_string(&RadonBoolean::from(true)),
+        RadonString::from("true")
+    );
+}
@@ -0,0 +1,41 @@
+use crate::error::RadError;
+use crate::hash_functions::{self, RadonHashFunctions};
+use crate::types::{bytes::RadonBytes, RadonType};
+
+use num_traits::FromPrimitive;
+use rmpv::Value;
+
+pub fn hash(input: &RadonBytes, args: &[Value]) -> Result<RadonBytes, RadError> {
+    let error = || RadError::WrongArguments {
+        input_type: "RadonBytes".to_string(),
+        operator: "Hash".to_string(),
+        args: args.to_vec(),
+    };
+
+    let hash_function_integer = args.first().ok_or_else(error)?.as_i64().ok_or_else(error)?;
+    let hash_function_code =
+        RadonHashFunctions::from_i64(hash_function_integer).ok_or_else(error)?;
+
+    let digest = hash_functions::hash(input.value().as_slice(), hash_function_code)?;
+
+    Ok(RadonBytes::from(digest))
+}
+
+#[test]
+fn test_hash() {
+    let input = RadonBytes::from(b"abc".to_vec());
+    let valid_args = [Value::from(0x06)]; // 0x06 is RadonHashFunctions::Ripemd160
+    let wrong_args = [Value::from(0xFF)]; // 0xFF is not a member of RadonHashFunctions
+
+    let valid_output = hash(&input, &valid_args).unwrap();
+    let wrong_output = hash(&input, &wrong_args);
+
+    let valid_expected =
+        RadonBytes::from(hex::decode("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc").unwrap());
+
+    assert_eq!(valid_output, valid_expected);
+    assert_eq!(
+        &wrong_output.unwrap_err().to_string(),
+        "Wrong `RadonBytes::Hash()` arguments: `[Integer(PosInt(255))]`"
+    );
+}
@@ -0,0 +1,239 @@
+use crate::error::RadError;
+use crate::types::{
+    boolean::RadonBoolean, float::RadonFloat, integer::RadonInteger, string::RadonString, RadonType,
+};
+
+use rmpv::Value;
+
+fn wrong_arguments(operator: &str, args: &[Value]) -> RadError {
+    RadError::WrongArguments {
+        input_type: "RadonFloat".to_string(),
+        operator: operator.to_string(),
+        args: args.to_vec(),
+    }
+}
+
+fn overflow(operator: &str) -> RadError {
+    RadError::Overflow {
+        input_type: "RadonFloat".to_string(),
+        operator: operator.to_string(),
+    }
+}
+
+/// Get the only argument of an operator as a float. Integer arguments are accepted too.
+fn float_argument(operator: &str, args: &[Value]) -> Result<f64, RadError> {
+    args.first()
+        .and_then(|arg| arg.as_f64().or_else(|| arg.as_i64().map(|arg| arg as f64)))
+        .ok_or_else(|| wrong_arguments(operator, args))
+}
+
+/// Wrap the result of an arithmetic operator, failing if it is infinite or not a number.
+fn finite(operator: &str, value: f64) -> Result<RadonFloat, RadError> {
+    if value.is_finite() {
+        Ok(RadonFloat::from(value))
+    } else {
+        Err(overflow(operator))
+    }
+}
+
+/// Convert an already integral float into an integer, failing if it does not fit.
+fn to_integer(operator: &str, value: f64) -> Result<RadonInteger, RadError> {
+    if value.is_finite() && value >= std::i64::MIN as f64 && value < std::i64::MAX as f64 {
+        Ok(RadonInteger::from(value as i64))
+    } else {
+        Err(overflow(operator))
+    }
+}
+
+pub fn absolute(input: &RadonFloat) -> RadonFloat {
+    RadonFloat::from(input.value().abs())
+}
+
+pub fn ceiling(input: &RadonFloat) -> Result<RadonInteger, RadError> {
+    to_integer("Ceiling", input.value().ceil())
+}
+
+pub fn floor(input: &RadonFloat) -> Result<RadonInteger, RadError> {
+    to_integer("Floor", input.value().floor())
+}
+
+pub fn round(input: &RadonFloat) -> Result<RadonInteger, RadError> {
+    to_integer("Round", input.value().round())
+}
+
+pub fn truncate(input: &RadonFloat) -> Result<RadonInteger, RadError> {
+    to_integer("Truncate", input.value().trunc())
+}
+
+pub fn negate(input: &RadonFloat) -> RadonFloat {
+    RadonFloat::from(-input.value())
+}
+
+/// The result takes the same sign as the input.
+pub fn modulo(input: &RadonFloat, args: &[Value]) -> Result<RadonFloat, RadError> {
+    let modulus = float_argument("Modulo", args)?;
+    if modulus == 0f64 {
+        return Err(RadError::DivisionByZero {
+            input_type: "RadonFloat".to_string(),
+            operator: "Modulo".to_string(),
+        });
+    }
+
+    finite("Modulo", input.value() % modulus)
+}
+
+pub fn multiply(input: &RadonFloat, args: &[Value]) -> Result<RadonFloat, RadError> {
+    let factor = float_argument("Multiply", args)?;
+
+    finite("Multiply", input.value() * factor)
+}
+
+pub fn power(input: &RadonFloat, args: &[Value]) -> Result<RadonFloat, RadError> {
+    let exponent = float_argument("Power", args)?;
+
+    finite("Power", input.value().powf(exponent))
+}
+
+pub fn reciprocal(input: &RadonFloat) -> Result<RadonFloat, RadError> {
+    if input.value() == 0f64 {
+        return Err(RadError::DivisionByZero {
+            input_type: "RadonFloat".to_string(),
+            operator: "Reciprocal".to_string(),
+        });
+    }
+
+    finite("Reciprocal", 1f64 / input.value())
+}
+
+pub fn sum(input: &RadonFloat, args: &[Value]) -> Result<RadonFloat, RadError> {
+    let addend = float_argument("Sum", args)?;
+
+    finite("Sum", input.value() + addend)
+}
+
+pub fn greater_than(input: &RadonFloat, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let other = float_argument("GreaterThan", args)?;
+
+    Ok(RadonBoolean::from(input.value() > other))
+}
+
+pub fn less_than(input: &RadonFloat, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let other = float_argument("LessThan", args)?;
+
+    Ok(RadonBoolean::from(input.value() < other))
+}
+
+#[allow(clippy::float_cmp)]
+pub fn equals(input: &RadonFloat, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let other = float_argument("Equals", args)?;
+
+    Ok(RadonBoolean::from(input.value() == other))
+}
+
+/// Represent the input with the given number of fractional digits, or with as few of them as
+/// possible if no number is given.
+pub fn to_string(input: &RadonFloat, args: &[Value]) -> Result<RadonString, RadError> {
+    let string = match args.first() {
+        Some(decimals) => {
+            let decimals = decimals
+                .as_u64()
+                .ok_or_else(|| wrong_arguments("ToString", args))?;
+            format!("{:.*}", decimals as usize, input.value())
+        }
+        None => input.value().to_string(),
+    };
+
+    Ok(RadonString::from(string))
+}
+
+#[test]
+fn test_rounding() {
+    let input = RadonFloat::from(-2.5);
+
+    assert_eq!(ceiling(&input).unwrap(), RadonInteger::from(-2));
+    assert_eq!(floor(&input).unwrap(), RadonInteger::from(-3));
+    assert_eq!(round(&input).unwrap(), RadonInteger::from(-3));
+    assert_eq!(truncate(&input).unwrap(), RadonInteger::from(-2));
+    assert_eq!(
+        &floor(&RadonFloat::from(1e30)).unwrap_err().to_string(),
+        "Arithmetic overflow in `RadonFloat::Floor()`"
+    );
+}
+
+#[test]
+fn test_kelvin_to_celsius() {
+    let input = RadonFloat::from(300.15);
+
+    let output = sum(&input, &[Value::from(-273.15)]).unwrap();
+
+    assert!((output.value() - 27f64).abs() < 1e-9);
+}
+
+#[test]
+fn test_cents_to_dollars() {
+    let input = RadonFloat::from(1234f64);
+
+    let output = multiply(&input, &[Value::from(0.01)]).unwrap();
+
+    assert!((output.value() - 12.34).abs() < 1e-9);
+}
+
+#[test]
+fn test_modulo() {
+    let input = RadonFloat::from(-7.5);
+
+    assert_eq!(
+        modulo(&input, &[Value::from(2)]).unwrap(),
+        RadonFloat::from(-1.5)
+    );
+    assert_eq!(
+        &modulo(&input, &[Value::from(0)]).unwrap_err().to_string(),
+        "Division by zero in `RadonFloat::Modulo()`"
+    );
+}
+
+#[test]
+fn test_power_and_reciprocal() {
+    let input = RadonFloat::from(4f64);
+
+    assert_eq!(
+        power(&input, &[Value::from(0.5)]).unwrap(),
+        RadonFloat::from(2f64)
+    );
+    assert_eq!(reciprocal(&input).unwrap(), RadonFloat::from(0.25));
+    assert!(reciprocal(&RadonFloat::from(0f64)).is_err());
+    assert!(power(&RadonFloat::from(1e300), &[Value::from(2)]).is_err());
+}
+
+#[test]
+fn test_comparisons() {
+    let input = RadonFloat::from(1.5);
+
+    assert_eq!(
+        greater_than(&input, &[Value::from(1)]).unwrap(),
+        RadonBoolean::from(true)
+    );
+    assert_eq!(
+        less_than(&input, &[Value::from(1)]).unwrap(),
+        RadonBoolean::from(false)
+    );
+    assert_eq!(
+        equals(&input, &[Value::from(1.5)]).unwrap(),
+        RadonBoolean::from(true)
+    );
+    assert!(equals(&input, &[Value::from("1.5")]).is_err());
+}
+
+#[test]
+fn test_to_string() {
+    let input = RadonFloat::from(3.14159);
+
+    assert_eq!(
+        to_string(&input, &[]).unwrap(),
+        RadonString::from("3.14159")
+    );
+    assert_eq!(
+        to_string(&input, &[Value::from(2)]).unwrap(),
+        RadonString::from("3.14")
+    );
+}
@@ -0,0 +1,223 @@
+use crate::error::RadError;
+use crate::operators;
+use crate::types::{
+    float::RadonFloat, integer::RadonInteger, string::RadonString, RadonType, RadonTypes,
+};
+
+use rmpv::Value;
+
+fn wrong_arguments(operator: &str, args: &[Value]) -> RadError {
+    RadError::WrongArguments {
+        input_type: "RadonInteger".to_string(),
+        operator: operator.to_string(),
+        args: args.to_vec(),
+    }
+}
+
+fn overflow(operator: &str) -> RadError {
+    RadError::Overflow {
+        input_type: "RadonInteger".to_string(),
+        operator: operator.to_string(),
+    }
+}
+
+fn division_by_zero(operator: &str) -> RadError {
+    RadError::DivisionByZero {
+        input_type: "RadonInteger".to_string(),
+        operator: operator.to_string(),
+    }
+}
+
+/// Get the only argument of an operator as an integer.
+fn integer_argument(operator: &str, args: &[Value]) -> Result<i64, RadError> {
+    args.first()
+        .and_then(Value::as_i64)
+        .ok_or_else(|| wrong_arguments(operator, args))
+}
+
+pub fn absolute(input: &RadonInteger) -> Result<RadonInteger, RadError> {
+    input
+        .value()
+        .checked_abs()
+        .map(RadonInteger::from)
+        .ok_or_else(|| overflow("Absolute"))
+}
+
+pub fn match_value(input: &RadonInteger, args: &[Value]) -> Result<RadonTypes, RadError> {
+    operators::match_value(&Value::from(input.value()), "RadonInteger", "Match", args)
+}
+
+/// The result takes the same sign as the input.
+pub fn modulo(input: &RadonInteger, args: &[Value]) -> Result<RadonInteger, RadError> {
+    let modulus = integer_argument("Modulo", args)?;
+    if modulus == 0 {
+        return Err(division_by_zero("Modulo"));
+    }
+
+    input
+        .value()
+        .checked_rem(modulus)
+        .map(RadonInteger::from)
+        .ok_or_else(|| overflow("Modulo"))
+}
+
+pub fn multiply(input: &RadonInteger, args: &[Value]) -> Result<RadonInteger, RadError> {
+    let factor = integer_argument("Multiply", args)?;
+
+    input
+        .value()
+        .checked_mul(factor)
+        .map(RadonInteger::from)
+        .ok_or_else(|| overflow("Multiply"))
+}
+
+pub fn negate(input: &RadonInteger) -> Result<RadonInteger, RadError> {
+    input
+        .value()
+        .checked_neg()
+        .map(RadonInteger::from)
+        .ok_or_else(|| overflow("Negate"))
+}
+
+pub fn power(input: &RadonInteger, args: &[Value]) -> Result<RadonFloat, RadError> {
+    let exponent = args
+        .first()
+        .and_then(|exponent| {
+            exponent
+                .as_f64()
+                .or_else(|| exponent.as_i64().map(|exponent| exponent as f64))
+        })
+        .ok_or_else(|| wrong_arguments("Power", args))?;
+    let result = (input.value() as f64).powf(exponent);
+
+    if result.is_finite() {
+        Ok(RadonFloat::from(result))
+    } else {
+        Err(overflow("Power"))
+    }
+}
+
+pub fn reciprocal(input: &RadonInteger) -> Result<RadonFloat, RadError> {
+    match input.value() {
+        0 => Err(division_by_zero("Reciprocal")),
+        value => Ok(RadonFloat::from(1f64 / value as f64)),
+    }
+}
+
+pub fn sum(input: &RadonInteger, args: &[Value]) -> Result<RadonInteger, RadError> {
+    let addend = integer_argument("Sum", args)?;
+
+    input
+        .value()
+        .checked_add(addend)
+        .map(RadonInteger::from)
+        .ok_or_else(|| overflow("Sum"))
+}
+
+pub fn to_float(input: &RadonInteger) -> RadonFloat {
+    RadonFloat::from(input.value() as f64)
+}
+
+/// Represent the input in base 2, 8, 10 or 16. The default base is 10.
+pub fn to_string(input: &RadonInteger, args: &[Value]) -> Result<RadonString, RadError> {
+    let base = match args.first() {
+        Some(base) => base
+            .as_u64()
+            .ok_or_else(|| wrong_arguments("ToString", args))?,
+        None => 10,
+    };
+    let value = input.value();
+    let sign = if value < 0 { "-" } else { "" };
+    let magnitude = (i128::from(value)).abs();
+
+    let string = match base {
+        2 => format!("{}{:b}", sign, magnitude),
+        8 => format!("{}{:o}", sign, magnitude),
+        10 => value.to_string(),
+        16 => format!("{}{:x}", sign, magnitude),
+        _ => return Err(wrong_arguments("ToString", args)),
+    };
+
+    Ok(RadonString::from(string))
+}
+
+#[test]
+fn test_absolute() {
+    assert_eq!(
+        absolute(&RadonInteger::from(-42)).unwrap(),
+        RadonInteger::from(42)
+    );
+    assert_eq!(
+        &absolute(&RadonInteger::from(std::i64::MIN))
+            .unwrap_err()
+            .to_string(),
+        "Arithmetic overflow in `RadonInteger::Absolute()`"
+    );
+}
+
+#[test]
+fn test_match_value() {
+    let categories = Value::from(vec![
+        Value::from(vec![Value::from(1), Value::from("One")]),
+        Value::from(vec![Value::from(2), Value::from("Two")]),
+    ]);
+
+    let output = match_value(&RadonInteger::from(2), &[categories.clone()]).unwrap();
+    let default = match_value(&RadonInteger::from(3), &[categories, Value::from("Other")]).unwrap();
+
+    assert_eq!(output, RadonTypes::from(RadonString::from("Two")));
+    assert_eq!(default, RadonTypes::from(RadonString::from("Other")));
+}
+
+#[test]
+fn test_modulo() {
+    let input = RadonInteger::from(-7);
+
+    assert_eq!(
+        modulo(&input, &[Value::from(3)]).unwrap(),
+        RadonInteger::from(-1)
+    );
+    assert_eq!(
+        &modulo(&input, &[Value::from(0)]).unwrap_err().to_string(),
+        "Division by zero in `RadonInteger::Modulo()`"
+    );
+}
+
+#[test]
+fn test_multiply_overflow() {
+    let input = RadonInteger::from(std::i64::MAX);
+
+    assert_eq!(
+        &multiply(&input, &[Value::from(2)]).unwrap_err().to_string(),
+        "Arithmetic overflow in `RadonInteger::Multiply()`"
+    );
+}
+
+#[test]
+fn test_power() {
+    assert_eq!(
+        power(&RadonInteger::from(4), &[Value::from(0.5)]).unwrap(),
+        RadonFloat::from(2f64)
+    );
+}
+
+#[test]
+fn test_reciprocal() {
+    assert_eq!(
+        reciprocal(&RadonInteger::from(4)).unwrap(),
+        RadonFloat::from(0.25)
+    );
+    assert!(reciprocal(&RadonInteger::from(0)).is_err());
+}
+
+#[test]
+fn test_to_string() {
+    let input = RadonInteger::from(-255);
+
+    assert_eq!(to_string(&input, &[]).unwrap(), RadonString::from("-255"));
+    assert_eq!(
+        to_string(&input, &[Value::from(16)]).unwrap(),
+        RadonString::from("-ff")
+    );
+    assert!(to_string(&input, &[Value::from(3)]).is_err());
+}
@@ -1,8 +1,30 @@
 use crate::error::RadError;
-use crate::types::{map::RadonMap, mixed::RadonMixed, RadonType};
+use crate::types::{
+    array::RadonArray, boolean::RadonBoolean, map::RadonMap, mixed::RadonMixed,
+    string::RadonString, RadonType, RadonTypes,
+};
 
 use rmpv::Value;
 
+/// A step of the path given to `GetPath`.
+#[derive(Debug, PartialEq)]
+enum PathSegment<'a> {
+    /// Get the value of a key of a map
+    Key(&'a str),
+    /// Get the item at a position of an array
+    Index(usize),
+}
+
+impl<'a> PathSegment<'a> {
+    /// Name of the segment, as used in the error raised when it cannot be found.
+    fn name(&self) -> String {
+        match self {
+            PathSegment::Key(key) => key.to_string(),
+            PathSegment::Index(index) => format!("[{}]", index),
+        }
+    }
+}
+
 pub fn get(input: &RadonMap, args: &[Value]) -> Result<RadonMixed, RadError> {
     let key = args.first().map(|ref value| value.as_str()).unwrap_or(None);
     match key {
@@ -20,6 +42,137 @@ pub fn get(input: &RadonMap, args: &[Value]) -> Result<RadonMixed, RadError> {
     }
 }
 
+/// Entries of a map sorted by key, so that the operators that list them are deterministic.
+fn sorted_entries(input: &RadonMap) -> Vec<(String, RadonMixed)> {
+    let mut entries: Vec<_> = input.value().into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    entries
+}
+
+/// Keys of a map, in lexicographical order.
+pub fn keys(input: &RadonMap) -> RadonArray {
+    RadonArray::from(
+        sorted_entries(input)
+            .into_iter()
+            .map(|(key, _)| RadonTypes::from(RadonString::from(key)))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Values of a map, in the lexicographical order of their keys.
+pub fn values(input: &RadonMap) -> RadonArray {
+    RadonArray::from(
+        sorted_entries(input)
+            .into_iter()
+            .map(|(_, value)| RadonTypes::from(value))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// `[key, value]` pairs of a map, in lexicographical order of their keys.
+pub fn entries(input: &RadonMap) -> RadonArray {
+    RadonArray::from(
+        sorted_entries(input)
+            .into_iter()
+            .map(|(key, value)| {
+                RadonTypes::from(RadonArray::from(vec![
+                    RadonTypes::from(RadonString::from(key)),
+                    RadonTypes::from(value),
+                ]))
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Whether a map contains the key given as argument.
+pub fn has_key(input: &RadonMap, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let key = args
+        .first()
+        .and_then(Value::as_str)
+        .ok_or_else(|| RadError::WrongArguments {
+            input_type: input.to_string(),
+            operator: "HasKey".to_string(),
+            args: args.to_vec(),
+        })?;
+
+    Ok(RadonBoolean::from(input.value().contains_key(key)))
+}
+
+/// Get a nested value by following a path of keys separated by dots, in which the items of arrays
+/// are selected by their position between brackets, e.g. `data.quotes[0].USD.price`.
+pub fn get_path(input: &RadonMap, args: &[Value]) -> Result<RadonMixed, RadError> {
+    let wrong_arguments = || RadError::WrongArguments {
+        input_type: input.to_string(),
+        operator: "GetPath".to_string(),
+        args: args.to_vec(),
+    };
+    let segments = args
+        .first()
+        .and_then(Value::as_str)
+        .and_then(parse_path)
+        .ok_or_else(wrong_arguments)?;
+
+    let mut segments = segments.iter();
+    let first = match segments.next() {
+        Some(PathSegment::Key(key)) => key,
+        _ => return Err(wrong_arguments()),
+    };
+    let mut value = input
+        .value()
+        .remove(*first)
+        .ok_or_else(|| RadError::MapKeyNotFound {
+            key: first.to_string(),
+        })?
+        .value();
+
+    for segment in segments {
+        let next = match (segment, &value) {
+            (PathSegment::Key(key), Value::Map(entries)) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key.as_str() == Some(*key))
+                .map(|(_, entry_value)| entry_value.clone()),
+            (PathSegment::Index(index), Value::Array(items)) => items.get(*index).cloned(),
+            _ => None,
+        };
+        value = next.ok_or_else(|| RadError::MapKeyNotFound {
+            key: segment.name(),
+        })?;
+    }
+
+    Ok(RadonMixed::from(value))
+}
+
+/// Split a path into its segments, or return `None` if it is not well formed.
+fn parse_path(path: &str) -> Option<Vec<PathSegment>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut indexes) = match part.find('[') {
+            Some(position) => part.split_at(position),
+            None => (part, ""),
+        };
+        if key.is_empty() {
+            return None;
+        }
+        segments.push(PathSegment::Key(key));
+
+        while !indexes.is_empty() {
+            let end = indexes.find(']')?;
+            let index = &indexes[1..end];
+            if !index.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            segments.push(PathSegment::Index(index.parse().ok()?));
+            indexes = &indexes[end + 1..];
+            if !indexes.is_empty() && !indexes.starts_with('[') {
+                return None;
+            }
+        }
+    }
+
+    Some(segments)
+}
+
 #[test]
 fn test_map_get() {
     use std::collections::HashMap;
@@ -54,3 +207,119 @@ fn test_map_get_error() {
 
     assert!(not_found_object.is_err());
 }
+
+#[cfg(test)]
+fn quotes_map() -> RadonMap {
+    use std::convert::TryFrom;
+
+    // { "data": { "quotes": [ { "USD": { "price": 10532.25 } } ] }, "status": "ok" }
+    RadonMap::try_from(Value::Map(vec![
+        (
+            Value::from("data"),
+            Value::Map(vec![(
+                Value::from("quotes"),
+                Value::Array(vec![Value::Map(vec![(
+                    Value::from("USD"),
+                    Value::Map(vec![(Value::from("price"), Value::from(10532.25))]),
+                )])]),
+            )]),
+        ),
+        (Value::from("status"), Value::from("ok")),
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_map_keys_values_entries() {
+    let input = quotes_map();
+    let status = RadonMixed::from(Value::from("ok"));
+    let data = input.value()["data"].clone();
+
+    assert_eq!(
+        keys(&input),
+        RadonArray::from(vec![
+            RadonString::from("data").into(),
+            RadonString::from("status").into(),
+        ])
+    );
+    assert_eq!(
+        values(&input),
+        RadonArray::from(vec![data.clone().into(), status.clone().into()])
+    );
+    assert_eq!(
+        entries(&input),
+        RadonArray::from(vec![
+            RadonArray::from(vec![RadonString::from("data").into(), data.into()]).into(),
+            RadonArray::from(vec![RadonString::from("status").into(), status.into()]).into(),
+        ])
+    );
+}
+
+#[test]
+fn test_map_has_key() {
+    let input = quotes_map();
+
+    assert_eq!(
+        has_key(&input, &[Value::from("status")]).unwrap(),
+        RadonBoolean::from(true)
+    );
+    assert_eq!(
+        has_key(&input, &[Value::from("price")]).unwrap(),
+        RadonBoolean::from(false)
+    );
+    assert!(has_key(&input, &[Value::from(0)]).is_err());
+}
+
+#[test]
+fn test_map_get_path() {
+    let input = quotes_map();
+    let get_path_with = |path: &str| get_path(&input, &[Value::from(path)]);
+
+    assert_eq!(
+        get_path_with("data.quotes[0].USD.price").unwrap(),
+        RadonMixed::from(Value::from(10532.25))
+    );
+    assert_eq!(
+        get_path_with("status").unwrap(),
+        RadonMixed::from(Value::from("ok"))
+    );
+    assert_eq!(
+        get_path_with("data.quotes[0].EUR.price").unwrap_err(),
+        RadError::MapKeyNotFound {
+            key: "EUR".to_string()
+        }
+    );
+    assert_eq!(
+        get_path_with("data.quotes[1].USD").unwrap_err(),
+        RadError::MapKeyNotFound {
+            key: "[1]".to_string()
+        }
+    );
+    assert_eq!(
+        get_path_with("status.code").unwrap_err(),
+        RadError::MapKeyNotFound {
+            key: "code".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(
+        parse_path("data.quotes[0][12].USD"),
+        Some(vec![
+            PathSegment::Key("data"),
+            PathSegment::Key("quotes"),
+            PathSegment::Index(0),
+            PathSegment::Index(12),
+            PathSegment::Key("USD"),
+        ])
+    );
+    assert_eq!(parse_path(""), None);
+    assert_eq!(parse_path("data..USD"), None);
+    assert_eq!(parse_path("[0].USD"), None);
+    assert_eq!(parse_path("quotes[x]"), None);
+    assert_eq!(parse_path("quotes[0"), None);
+    assert_eq!(parse_path("quotes[0]USD"), None);
+    assert_eq!(parse_path("quotes[]"), None);
+}
@@ -2,13 +2,26 @@ use std::convert::TryFrom;
 
 use crate::error::RadError;
 use crate::types::{
-    array::RadonArray, float::RadonFloat, map::RadonMap, mixed::RadonMixed, RadonType,
+    array::RadonArray, boolean::RadonBoolean, bytes::RadonBytes, float::RadonFloat,
+    integer::RadonInteger, map::RadonMap, mixed::RadonMixed, RadonType,
 };
 
+pub fn to_boolean(input: RadonMixed) -> Result<RadonBoolean, RadError> {
+    RadonBoolean::try_from(input.value())
+}
+
+pub fn to_bytes(input: RadonMixed) -> Result<RadonBytes, RadError> {
+    RadonBytes::try_from(input.value())
+}
+
 pub fn to_float(input: RadonMixed) -> Result<RadonFloat, RadError> {
     RadonFloat::try_from(input.value())
 }
 
+pub fn to_integer(input: RadonMixed) -> Result<RadonInteger, RadError> {
+    RadonInteger::try_from(input.value())
+}
+
 pub fn to_map(input: RadonMixed) -> Result<RadonMap, RadError> {
     RadonMap::try_from(input.value())
 }
@@ -30,3 +43,42 @@ fn test_as_float() {
         "Failed to decode RadonFloat from rmpv::Value"
     );
 }
+
+#[test]
+fn test_as_integer() {
+    use rmpv::Value;
+
+    let radon_integer = RadonInteger::from(-42);
+    let radon_mixed_error = RadonMixed::from(Value::from(std::f64::consts::PI));
+    let radon_mixed = RadonMixed::from(Value::from(-42));
+
+    assert_eq!(to_integer(radon_mixed).unwrap(), radon_integer);
+    assert_eq!(
+        &to_integer(radon_mixed_error).unwrap_err().to_string(),
+        "Failed to decode RadonInteger from rmpv::Value"
+    );
+}
+
+#[test]
+fn test_as_boolean() {
+    use rmpv::Value;
+
+    let radon_mixed = RadonMixed::from(Value::from(true));
+
+    assert_eq!(to_boolean(radon_mixed).unwrap(), RadonBoolean::from(true));
+}
+
+#[test]
+fn test_parse_json_to_boolean() {
+    use crate::operators::string::parse_json;
+    use crate::types::string::RadonString;
+
+    let radon_true = parse_json(&RadonString::from("true")).unwrap();
+    let radon_false = parse_json(&RadonString::from("false")).unwrap();
+    let radon_null = parse_json(&RadonString::from("null")).unwrap();
+
+    assert_eq!(to_boolean(radon_true).unwrap(), RadonBoolean::from(true));
+    assert_eq!(to_boolean(radon_false).unwrap(), RadonBoolean::from(false));
+    assert_eq!(radon_null.value(), rmpv::Value::Nil);
+    assert!(to_boolean(radon_null).is_err());
+}
@@ -6,14 +6,20 @@ use crate::script::RadonCall;
 use crate::types::RadonTypes;
 
 use num_derive::FromPrimitive;
-use std::fmt;
+use num_traits::FromPrimitive;
+use rmpv::Value;
+use std::{convert::TryFrom, fmt};
 
 pub mod array;
+pub mod boolean;
+pub mod bytes;
+pub mod float;
+pub mod integer;
 pub mod map;
 pub mod mixed;
 pub mod string;
 
-#[derive(Debug, FromPrimitive, PartialEq)]
+#[derive(Clone, Copy, Debug, FromPrimitive, PartialEq)]
 pub enum RadonOpCodes {
     /// Only for the sake of allowing catch-alls when matching
     Fail = -1,
@@ -23,24 +29,142 @@ pub enum RadonOpCodes {
     /// Array::get, Map::get, Result::get
     Get = 0x01,
     // Boolean operator codes start at 0x10
+    /// Classify a boolean into categories
+    BooleanMatch = 0x10,
+    /// Negate a boolean
+    BooleanNegate = 0x11,
+    /// Represent a boolean as a string
+    BooleanToString = 0x12,
     // Integer operator codes start at 0x20
+    /// Absolute value of an integer
+    IntegerAbsolute = 0x20,
+    /// Classify an integer into categories
+    IntegerMatch = 0x21,
+    /// Remainder of the division of an integer by a modulus
+    IntegerModulo = 0x22,
+    /// Multiply an integer by a factor
+    IntegerMultiply = 0x23,
+    /// Additive inverse of an integer
+    IntegerNegate = 0x24,
+    /// Exponentiate an integer to a float power
+    IntegerPower = 0x25,
+    /// Multiplicative inverse of an integer
+    IntegerReciprocal = 0x26,
+    /// Add an addend to an integer
+    IntegerSum = 0x27,
+    /// Convert an integer into a float
+    IntegerToFloat = 0x28,
+    /// Represent an integer as a string in a given base
+    IntegerToString = 0x29,
     // Float operator codes start at 0x30
+    /// Absolute value of a float
+    FloatAbsolute = 0x30,
+    /// Smallest integer greater than or equal to a float
+    FloatCeiling = 0x31,
+    /// Largest integer less than or equal to a float
+    FloatFloor = 0x32,
+    /// Remainder of the division of a float by a modulus
+    FloatModulo = 0x33,
+    /// Multiply a float by a factor
+    FloatMultiply = 0x34,
+    /// Additive inverse of a float
+    FloatNegate = 0x35,
+    /// Exponentiate a float to a power
+    FloatPower = 0x36,
+    /// Multiplicative inverse of a float
+    FloatReciprocal = 0x37,
+    /// Round a float to the nearest integer
+    FloatRound = 0x38,
+    /// Add an addend to a float
+    FloatSum = 0x39,
+    /// Represent a float as a string
+    FloatToString = 0x3A,
+    /// Integer part of a float
+    FloatTruncate = 0x3B,
+    /// Whether a float is greater than another
+    FloatGreaterThan = 0x3C,
+    /// Whether a float is less than another
+    FloatLessThan = 0x3D,
+    /// Whether a float equals another
+    FloatEquals = 0x3E,
     // Null operator codes start at 0x40
     // String operator codes start at 0x50
-    /// Compute the hash of a string
+    /// Compute the hash of a string or bytes
     Hash = 0x50,
+    /// Number of bytes of a string
+    StringLength = 0x51,
+    /// Whether a regular expression matches some part of a string
+    StringMatch = 0x52,
     /// Parse Mixed from JSON string
     ParseJson = 0x53,
+    /// Parse Mixed from XML string
+    ParseXml = 0x54,
+    /// Parse Mixed from CSV string
+    ParseCsv = 0x55,
+    /// Groups captured by the first match of a regular expression in a string
+    StringCaptures = 0x56,
+    /// Replace the matches of a regular expression in a string
+    StringReplace = 0x57,
+    /// Take a range of characters of a string
+    StringSlice = 0x58,
+    /// Split a string around the occurrences of a separator
+    StringSplit = 0x59,
+    /// Convert a string to lowercase
+    StringToLowerCase = 0x5A,
+    /// Convert a string to uppercase
+    StringToUpperCase = 0x5B,
+    /// Remove the leading and trailing whitespace of a string
+    StringTrim = 0x5C,
     // Array operator codes start at 0x60
+    /// Number of items in an array
+    ArrayCount = 0x60,
+    /// Whether a subscript returns true for every item of an array
+    ArrayEvery = 0x61,
+    /// Keep the items of an array for which a subscript returns true
+    ArrayFilter = 0x62,
+    /// Concatenate the nested arrays of an array
+    ArrayFlatten = 0x63,
+    /// Apply a subscript to every item of an array
+    ArrayMap = 0x65,
     Reduce = 0x66,
+    /// Whether a subscript returns true for some item of an array
+    ArraySome = 0x67,
+    /// Sort the items of an array by the output of a subscript
+    ArraySort = 0x68,
+    /// Take a range of items from the beginning of an array
+    ArrayTake = 0x69,
+    /// Drop a number of items from the beginning of an array
+    ArraySkip = 0x6A,
     // Map operator codes start at 0x70
+    /// `[key, value]` pairs of a map
+    MapEntries = 0x70,
+    /// Get a nested value of a map by following a path
+    MapGetPath = 0x71,
+    /// Whether a map contains a key
+    MapHasKey = 0x72,
+    /// Keys of a map
+    MapKeys = 0x73,
+    /// Values of a map
+    MapValues = 0x74,
     // Mixed operator codes start at 0x80
     ToArray = 0x80,
+    ToBoolean = 0x81,
     ToFloat = 0x82,
+    ToInteger = 0x83,
     ToMap = 0x84,
+    ToBytes = 0x85,
     // Result operator codes start at 0x90
 }
 
+impl RadonOpCodes {
+    /// Find the operator code with the given name, as displayed.
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..=0xFF)
+            .filter_map(Self::from_u8)
+            .find(|op_code| op_code.to_string() == name)
+    }
+}
+
 impl fmt::Display for RadonOpCodes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -54,7 +178,10 @@ pub trait Operable {
 pub fn operate(input: RadonTypes, call: &RadonCall) -> Result<RadonTypes, RadError> {
     match input {
         RadonTypes::Array(radon_array) => radon_array.operate(call),
+        RadonTypes::Boolean(radon_boolean) => radon_boolean.operate(call),
+        RadonTypes::Bytes(radon_bytes) => radon_bytes.operate(call),
         RadonTypes::Float(radon_float) => radon_float.operate(call),
+        RadonTypes::Integer(radon_integer) => radon_integer.operate(call),
         RadonTypes::Map(radon_map) => radon_map.operate(call),
         RadonTypes::String(radon_string) => radon_string.operate(call),
         RadonTypes::Mixed(radon_mixed) => radon_mixed.operate(call),
@@ -65,6 +192,43 @@ pub fn identity(input: RadonTypes) -> Result<RadonTypes, RadError> {
     Ok(input)
 }
 
+/// Classify an input value into categories. The first argument is an array of `[key, value]`
+/// pairs, and the value of the first pair whose key equals the input is returned. The optional
+/// second argument is the value returned if none of the keys match.
+pub fn match_value(
+    input: &Value,
+    input_type: &str,
+    operator: &str,
+    args: &[Value],
+) -> Result<RadonTypes, RadError> {
+    let error = || RadError::WrongArguments {
+        input_type: input_type.to_string(),
+        operator: operator.to_string(),
+        args: args.to_vec(),
+    };
+
+    let categories = args.first().and_then(Value::as_array).ok_or_else(error)?;
+    let mut matched = None;
+    for category in categories {
+        match category.as_array().map(Vec::as_slice) {
+            Some([key, value]) => {
+                if key == input {
+                    matched = Some(value);
+                    break;
+                }
+            }
+            _ => return Err(error()),
+        }
+    }
+
+    matched
+        .or_else(|| args.get(1))
+        .ok_or_else(|| RadError::UnmatchedValue {
+            value: input.to_string(),
+        })
+        .and_then(|value| RadonTypes::try_from(value.clone()))
+}
+
 #[test]
 pub fn test_identity() {
     use crate::types::string::RadonString;
@@ -76,6 +240,17 @@ pub fn test_identity() {
     assert_eq!(output, expected);
 }
 
+#[test]
+pub fn test_op_code_from_name() {
+    assert_eq!(
+        RadonOpCodes::from_name("ParseJson"),
+        Some(RadonOpCodes::ParseJson)
+    );
+    assert_eq!(RadonOpCodes::from_name("Get"), Some(RadonOpCodes::Get));
+    assert_eq!(RadonOpCodes::from_name("Fail"), None);
+    assert_eq!(RadonOpCodes::from_name("parsejson"), None);
+}
+
 #[test]
 pub fn test_operate() {
     use crate::types::string::RadonString;
@@ -1,12 +1,141 @@
 use crate::error::RadError;
 use crate::hash_functions::{self, RadonHashFunctions};
-use crate::types::{float::RadonFloat, mixed::RadonMixed, string::RadonString, RadonType};
+use crate::types::{
+    array::RadonArray, boolean::RadonBoolean, float::RadonFloat, integer::RadonInteger,
+    mixed::RadonMixed, string::RadonString, RadonType, RadonTypes,
+};
 
 use json;
 use num_traits::FromPrimitive;
+use regex::{Regex, RegexBuilder};
 use rmpv::{self, Value};
 use std::{error::Error, str::FromStr};
 
+/// Maximum size in bytes of the compiled regular expressions, so that scripts cannot make the
+/// nodes run out of memory
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+fn wrong_arguments(operator: &str, args: &[Value]) -> RadError {
+    RadError::WrongArguments {
+        input_type: "RadonString".to_string(),
+        operator: operator.to_string(),
+        args: args.to_vec(),
+    }
+}
+
+/// Read the argument at the given position as a string.
+fn string_argument<'a>(
+    operator: &str,
+    args: &'a [Value],
+    index: usize,
+) -> Result<&'a str, RadError> {
+    args.get(index)
+        .and_then(Value::as_str)
+        .ok_or_else(|| wrong_arguments(operator, args))
+}
+
+/// Compile the regular expression given as the first argument.
+fn regex_argument(operator: &str, args: &[Value]) -> Result<Regex, RadError> {
+    let pattern = string_argument(operator, args, 0)?;
+
+    RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|error| RadError::InvalidRegex {
+            pattern: pattern.to_string(),
+            description: error.to_string(),
+        })
+}
+
+pub fn length(input: &RadonString) -> RadonInteger {
+    RadonInteger::from(input.value().len() as i64)
+}
+
+pub fn to_lowercase(input: &RadonString) -> RadonString {
+    RadonString::from(input.value().to_lowercase())
+}
+
+pub fn to_uppercase(input: &RadonString) -> RadonString {
+    RadonString::from(input.value().to_uppercase())
+}
+
+pub fn trim(input: &RadonString) -> RadonString {
+    RadonString::from(input.value().trim())
+}
+
+/// Take the characters between the start position given as the first argument and the optional
+/// end position given as the second one, which defaults to the end of the string. Positions count
+/// characters, not bytes, and are clamped to the length of the string.
+pub fn slice(input: &RadonString, args: &[Value]) -> Result<RadonString, RadError> {
+    let position = |index| {
+        args.get(index)
+            .map(|arg: &Value| arg.as_u64().ok_or_else(|| wrong_arguments("Slice", args)))
+            .transpose()
+    };
+    let start = position(0)?.ok_or_else(|| wrong_arguments("Slice", args))? as usize;
+    let end = position(1)?.map(|end| end as usize);
+
+    let string = input.value();
+    let length = end.unwrap_or(std::usize::MAX).saturating_sub(start);
+
+    Ok(RadonString::from(
+        string.chars().skip(start).take(length).collect::<String>(),
+    ))
+}
+
+/// Split the string around the occurrences of the separator given as argument.
+pub fn split(input: &RadonString, args: &[Value]) -> Result<RadonArray, RadError> {
+    let separator = string_argument("Split", args, 0)?;
+
+    Ok(RadonArray::from(
+        input
+            .value()
+            .split(separator)
+            .map(|part| RadonTypes::from(RadonString::from(part)))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Whether the regular expression given as argument matches some part of the string.
+pub fn string_match(input: &RadonString, args: &[Value]) -> Result<RadonBoolean, RadError> {
+    let regex = regex_argument("Match", args)?;
+
+    Ok(RadonBoolean::from(regex.is_match(&input.value())))
+}
+
+/// Groups captured by the first match of the regular expression given as argument, starting with
+/// the whole match. Groups that did not participate in the match are empty strings, and the
+/// array is empty if the regular expression does not match at all.
+pub fn captures(input: &RadonString, args: &[Value]) -> Result<RadonArray, RadError> {
+    let regex = regex_argument("Captures", args)?;
+    let string = input.value();
+
+    let groups: Vec<RadonTypes> = regex
+        .captures(&string)
+        .map(|captures| {
+            captures
+                .iter()
+                .map(|group| {
+                    RadonTypes::from(RadonString::from(group.map_or("", |group| group.as_str())))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(RadonArray::from(groups))
+}
+
+/// Replace every match of the regular expression given as the first argument with the
+/// replacement given as the second one, which can refer to captured groups as `$1` or `$name`.
+pub fn replace(input: &RadonString, args: &[Value]) -> Result<RadonString, RadError> {
+    let regex = regex_argument("Replace", args)?;
+    let replacement = string_argument("Replace", args, 1)?;
+
+    Ok(RadonString::from(
+        regex.replace_all(&input.value(), replacement).into_owned(),
+    ))
+}
+
 pub fn parse_json(input: &RadonString) -> Result<RadonMixed, RadError> {
     match json::parse(&input.value()) {
         Ok(json_value) => {
@@ -18,6 +147,42 @@ pub fn parse_json(input: &RadonString) -> Result<RadonMixed, RadError> {
         }),
     }
 }
+
+/// Parse an XML document into a map with a single entry for its root element. Elements with
+/// neither attributes nor child elements are decoded as their text. Otherwise, they are decoded
+/// as maps in which attributes are prefixed by `@`, the text is found under `#text`, and repeated
+/// child elements are gathered into arrays.
+pub fn parse_xml(input: &RadonString) -> Result<RadonMixed, RadError> {
+    let string = input.value();
+    let document = roxmltree::Document::parse(&string).map_err(|error| RadError::XmlParse {
+        description: error.to_string(),
+    })?;
+    let root = document.root_element();
+
+    Ok(RadonMixed::from(Value::Map(vec![(
+        Value::from(root.tag_name().name()),
+        xml_to_rmp(root),
+    )])))
+}
+
+/// Parse a CSV document into an array with the fields of every record. The first record is not
+/// treated as a header, and records may have different numbers of fields.
+pub fn parse_csv(input: &RadonString) -> Result<RadonMixed, RadError> {
+    let string = input.value();
+    let records = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(string.as_bytes())
+        .records()
+        .map(|record| record.map(|record| Value::Array(record.iter().map(Value::from).collect())))
+        .collect::<Result<Vec<Value>, csv::Error>>()
+        .map_err(|error| RadError::CsvParse {
+            description: error.to_string(),
+        })?;
+
+    Ok(RadonMixed::from(Value::Array(records)))
+}
+
 pub fn to_float(input: &RadonString) -> Result<RadonFloat, RadError> {
     f64::from_str(&input.value())
         .map(RadonFloat::from)
@@ -61,8 +226,64 @@ fn json_to_rmp(value: &json::JsonValue) -> rmpv::ValueRef {
         json::JsonValue::String(value) => {
             rmpv::ValueRef::String(rmpv::Utf8StringRef::from(value.as_str()))
         }
-        json::JsonValue::Number(value) => rmpv::ValueRef::F64((*value).into()),
-        _ => rmpv::ValueRef::Nil,
+        json::JsonValue::Number(value) => match value.as_parts() {
+            // Numbers without decimals nor exponent are decoded as integers so they do not lose
+            // precision
+            (positive, mantissa, 0) if mantissa <= std::i64::MAX as u64 => {
+                let integer = mantissa as i64;
+                rmpv::ValueRef::Integer(rmpv::Integer::from(if positive {
+                    integer
+                } else {
+                    -integer
+                }))
+            }
+            _ => rmpv::ValueRef::F64((*value).into()),
+        },
+        json::JsonValue::Boolean(value) => rmpv::ValueRef::Boolean(*value),
+        json::JsonValue::Null => rmpv::ValueRef::Nil,
+    }
+}
+
+fn xml_to_rmp(element: roxmltree::Node) -> Value {
+    let text: String = element
+        .children()
+        .filter(roxmltree::Node::is_text)
+        .filter_map(|node| node.text())
+        .collect();
+    let text = text.trim();
+
+    let mut entries: Vec<(Value, Value)> = element
+        .attributes()
+        .iter()
+        .map(|attribute| {
+            (
+                Value::from(format!("@{}", attribute.name())),
+                Value::from(attribute.value()),
+            )
+        })
+        .collect();
+
+    for child in element.children().filter(roxmltree::Node::is_element) {
+        let key = Value::from(child.tag_name().name());
+        let value = xml_to_rmp(child);
+        match entries.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, Value::Array(values))) => values.push(value),
+            Some((_, existing)) => {
+                let first = std::mem::replace(existing, Value::Nil);
+                *existing = Value::Array(vec![first, value]);
+            }
+            None => entries.push((key, value)),
+        }
+    }
+
+    if entries.is_empty() {
+        Value::from(text)
+    } else {
+        if !text.is_empty() {
+            entries.push((Value::from("#text"), Value::from(text)));
+        }
+
+        Value::Map(entries)
     }
 }
 
@@ -115,3 +336,132 @@ fn test_hash() {
         "Hash function `RadonHashFunctions::Fail` is not implemented"
     );
 }
+
+#[test]
+fn test_parse_xml() {
+    let input = RadonString::from(
+        r#"<?xml version="1.0"?>
+        <weather city="Madrid">
+            <temperature unit="C">21.5</temperature>
+            <wind>5</wind>
+            <alert>Heat</alert>
+            <alert>Dust</alert>
+        </weather>"#,
+    );
+    let expected = Value::Map(vec![(
+        Value::from("weather"),
+        Value::Map(vec![
+            (Value::from("@city"), Value::from("Madrid")),
+            (
+                Value::from("temperature"),
+                Value::Map(vec![
+                    (Value::from("@unit"), Value::from("C")),
+                    (Value::from("#text"), Value::from("21.5")),
+                ]),
+            ),
+            (Value::from("wind"), Value::from("5")),
+            (
+                Value::from("alert"),
+                Value::Array(vec![Value::from("Heat"), Value::from("Dust")]),
+            ),
+        ]),
+    )]);
+
+    assert_eq!(parse_xml(&input).unwrap().value(), expected);
+    assert!(parse_xml(&RadonString::from("<weather>")).is_err());
+}
+
+#[test]
+fn test_parse_csv() {
+    let input = RadonString::from("date,price\n2019-07-01,\"10,5\"\n2019-07-02");
+    let expected = Value::Array(vec![
+        Value::Array(vec![Value::from("date"), Value::from("price")]),
+        Value::Array(vec![Value::from("2019-07-01"), Value::from("10,5")]),
+        Value::Array(vec![Value::from("2019-07-02")]),
+    ]);
+
+    assert_eq!(parse_csv(&input).unwrap().value(), expected);
+}
+
+#[test]
+fn test_slice() {
+    let input = RadonString::from("¡Hola mundo!");
+
+    let slice_with = |args: Vec<Value>| slice(&input, &args);
+
+    assert_eq!(
+        slice_with(vec![Value::from(1), Value::from(5)]).unwrap(),
+        RadonString::from("Hola")
+    );
+    assert_eq!(
+        slice_with(vec![Value::from(6)]).unwrap(),
+        RadonString::from("mundo!")
+    );
+    assert_eq!(
+        slice_with(vec![Value::from(6), Value::from(100)]).unwrap(),
+        RadonString::from("mundo!")
+    );
+    assert_eq!(
+        slice_with(vec![Value::from(5), Value::from(1)]).unwrap(),
+        RadonString::from("")
+    );
+    assert!(slice_with(vec![Value::from(-1)]).is_err());
+    assert!(slice_with(vec![]).is_err());
+}
+
+#[test]
+fn test_split() {
+    let input = RadonString::from("a;b;;c");
+    let expected = RadonArray::from(
+        ["a", "b", "", "c"]
+            .iter()
+            .map(|part| RadonTypes::from(RadonString::from(*part)))
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(split(&input, &[Value::from(";")]).unwrap(), expected);
+    assert!(split(&input, &[Value::from(1)]).is_err());
+}
+
+#[test]
+fn test_regex_operators() {
+    let input = RadonString::from("BTC/USD: 10532.25");
+    let pattern = Value::from(r"(\w+)/(\w+): ([0-9.]+)");
+
+    assert_eq!(
+        string_match(&input, &[pattern.clone()]).unwrap(),
+        RadonBoolean::from(true)
+    );
+    assert_eq!(
+        string_match(&input, &[Value::from("^ETH")]).unwrap(),
+        RadonBoolean::from(false)
+    );
+
+    let groups = captures(&input, &[pattern.clone()]).unwrap();
+    assert_eq!(
+        groups.value(),
+        vec![
+            RadonTypes::from(RadonString::from("BTC/USD: 10532.25")),
+            RadonTypes::from(RadonString::from("BTC")),
+            RadonTypes::from(RadonString::from("USD")),
+            RadonTypes::from(RadonString::from("10532.25")),
+        ]
+    );
+    assert!(captures(&input, &[Value::from("^ETH")])
+        .unwrap()
+        .value()
+        .is_empty());
+
+    assert_eq!(
+        replace(&input, &[pattern, Value::from("$3 $2")]).unwrap(),
+        RadonString::from("10532.25 USD")
+    );
+
+    assert_eq!(
+        string_match(&input, &[Value::from("(")]).unwrap_err(),
+        RadError::InvalidRegex {
+            pattern: "(".to_string(),
+            description: RegexBuilder::new("(").build().unwrap_err().to_string(),
+        }
+    );
+}
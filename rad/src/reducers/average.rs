@@ -11,8 +11,7 @@ pub fn mean(input: &RadonArray) -> Result<RadonTypes, RadError> {
         .iter()
         .fold((0f64, 0f64), |(sum, count), item| match item {
             RadonTypes::Float(f64_value) => (sum + f64_value.value(), count + 1f64),
-            // TODO: implement RadonInteger branch here
-            // RadonTypes::Integer(i64_value) => (sum + acc + i64_value.value() as f64, count + 1f64),
+            RadonTypes::Integer(i64_value) => (sum + i64_value.value() as f64, count + 1f64),
             // Skip any non-numeric RadonType
             _ => (sum, count),
         });
@@ -22,3 +21,158 @@ pub fn mean(input: &RadonArray) -> Result<RadonTypes, RadError> {
 
     Ok(RadonTypes::from(RadonFloat::from(mean_value)))
 }
+
+pub fn mean_weighted(input: &RadonArray, weights: &[f64]) -> Result<RadonTypes, RadError> {
+    super::check_weights("AverageMeanWeighted", weights)?;
+    let values = super::numbers(input);
+
+    let (sum, total_weight) = values
+        .iter()
+        .zip(weights)
+        .fold((0f64, 0f64), |(sum, total_weight), (value, weight)| {
+            (sum + value * weight, total_weight + weight)
+        });
+    if total_weight == 0f64 {
+        return Err(super::division_by_zero("AverageMeanWeighted"));
+    }
+
+    Ok(RadonTypes::from(RadonFloat::from(sum.div(total_weight))))
+}
+
+pub fn median(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    Ok(RadonTypes::from(RadonFloat::from(median_of(
+        super::numbers(input),
+    ))))
+}
+
+pub fn median_weighted(input: &RadonArray, weights: &[f64]) -> Result<RadonTypes, RadError> {
+    super::check_weights("AverageMedianWeighted", weights)?;
+    let mut weighted: Vec<(f64, f64)> = super::numbers(input)
+        .into_iter()
+        .zip(weights.iter().cloned())
+        .collect();
+    weighted.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let total_weight: f64 = weighted.iter().map(|(_, weight)| weight).sum();
+    if total_weight == 0f64 {
+        return Err(super::division_by_zero("AverageMedianWeighted"));
+    }
+
+    // The weighted median is the first value at which the accumulated weight reaches half of the
+    // total weight. If it reaches exactly half, it is averaged with the next value.
+    let half = total_weight / 2f64;
+    let mut accumulated = 0f64;
+    let mut median_value = 0f64;
+    for (index, (value, weight)) in weighted.iter().enumerate() {
+        accumulated += weight;
+        if accumulated > half {
+            median_value = *value;
+            break;
+        }
+        if accumulated == half {
+            median_value = weighted
+                .iter()
+                .skip(index + 1)
+                .find(|(_, weight)| *weight > 0f64)
+                .map_or(*value, |(next, _)| (value + next) / 2f64);
+            break;
+        }
+    }
+
+    Ok(RadonTypes::from(RadonFloat::from(median_value)))
+}
+
+/// Median of a non-empty list of numbers. For an even number of values, the two central ones are
+/// averaged.
+pub(super) fn median_of(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let middle = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[middle - 1] + values[middle]) / 2f64
+    } else {
+        values[middle]
+    }
+}
+
+#[test]
+fn test_median_odd() {
+    use crate::types::integer::RadonInteger;
+
+    let input = RadonArray::from(vec![
+        RadonInteger::from(3).into(),
+        RadonInteger::from(1).into(),
+        RadonInteger::from(1000).into(),
+    ]);
+    let expected = RadonTypes::from(RadonFloat::from(3f64));
+
+    assert_eq!(median(&input).unwrap(), expected);
+}
+
+#[test]
+fn test_median_even() {
+    let input = RadonArray::from(vec![
+        RadonFloat::from(4f64).into(),
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+        RadonFloat::from(100f64).into(),
+    ]);
+    let expected = RadonTypes::from(RadonFloat::from(3f64));
+
+    assert_eq!(median(&input).unwrap(), expected);
+}
+
+#[test]
+fn test_mean_weighted() {
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(4f64).into(),
+    ]);
+    let expected = RadonTypes::from(RadonFloat::from(3.25f64));
+
+    assert_eq!(mean_weighted(&input, &[1f64, 3f64]).unwrap(), expected);
+    assert_eq!(
+        &mean_weighted(&input, &[0f64, 0f64])
+            .unwrap_err()
+            .to_string(),
+        "Division by zero in `RadonArray::AverageMeanWeighted()`"
+    );
+}
+
+#[test]
+fn test_median_weighted() {
+    let input = RadonArray::from(vec![
+        RadonFloat::from(3f64).into(),
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+    ]);
+
+    assert_eq!(
+        median_weighted(&input, &[1f64, 5f64, 1f64]).unwrap(),
+        RadonTypes::from(RadonFloat::from(1f64))
+    );
+    assert_eq!(
+        median_weighted(&input, &[2f64, 1f64, 1f64]).unwrap(),
+        RadonTypes::from(RadonFloat::from(2.5f64))
+    );
+}
+
+#[test]
+fn test_weighted_invalid_weights() {
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+    ]);
+
+    for weight in &[-1f64, std::f64::NAN, std::f64::INFINITY] {
+        let weights = [1f64, *weight];
+        match median_weighted(&input, &weights) {
+            Err(RadError::WrongArguments { .. }) => (),
+            result => panic!("Weight {} was not rejected: {:?}", weight, result),
+        }
+        match mean_weighted(&input, &weights) {
+            Err(RadError::WrongArguments { .. }) => (),
+            result => panic!("Weight {} was not rejected: {:?}", weight, result),
+        }
+    }
+}
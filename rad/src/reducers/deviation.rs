@@ -0,0 +1,90 @@
+use crate::error::RadError;
+use crate::reducers::average::median_of;
+use crate::types::{array::RadonArray, float::RadonFloat, RadonTypes};
+
+fn mean_of(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Population standard deviation.
+pub fn standard(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    let values = super::numbers(input);
+    let mean = mean_of(&values);
+    let squares: Vec<f64> = values.iter().map(|value| (value - mean).powi(2)).collect();
+
+    Ok(RadonTypes::from(RadonFloat::from(mean_of(&squares).sqrt())))
+}
+
+/// Mean of the absolute deviations from the mean.
+pub fn average_absolute(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    let values = super::numbers(input);
+    let mean = mean_of(&values);
+    let deviations: Vec<f64> = values.iter().map(|value| (value - mean).abs()).collect();
+
+    Ok(RadonTypes::from(RadonFloat::from(mean_of(&deviations))))
+}
+
+/// Median of the absolute deviations from the median.
+pub fn median_absolute(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    let values = super::numbers(input);
+    let median = median_of(values.clone());
+    let deviations: Vec<f64> = values.iter().map(|value| (value - median).abs()).collect();
+
+    Ok(RadonTypes::from(RadonFloat::from(median_of(deviations))))
+}
+
+/// Maximum of the absolute deviations from the mean.
+pub fn maximum_absolute(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    let values = super::numbers(input);
+    let mean = mean_of(&values);
+    let maximum = values
+        .iter()
+        .map(|value| (value - mean).abs())
+        .fold(0f64, f64::max);
+
+    Ok(RadonTypes::from(RadonFloat::from(maximum)))
+}
+
+#[cfg(test)]
+fn sample() -> RadonArray {
+    use crate::types::integer::RadonInteger;
+
+    RadonArray::from(vec![
+        RadonInteger::from(2).into(),
+        RadonInteger::from(4).into(),
+        RadonInteger::from(4).into(),
+        RadonInteger::from(4).into(),
+        RadonInteger::from(5).into(),
+        RadonInteger::from(5).into(),
+        RadonInteger::from(7).into(),
+        RadonInteger::from(9).into(),
+    ])
+}
+
+#[test]
+fn test_standard() {
+    let expected = RadonTypes::from(RadonFloat::from(2f64));
+
+    assert_eq!(standard(&sample()).unwrap(), expected);
+}
+
+#[test]
+fn test_average_absolute() {
+    let expected = RadonTypes::from(RadonFloat::from(1.5f64));
+
+    assert_eq!(average_absolute(&sample()).unwrap(), expected);
+}
+
+#[test]
+fn test_median_absolute() {
+    let expected = RadonTypes::from(RadonFloat::from(0.5f64));
+
+    assert_eq!(median_absolute(&sample()).unwrap(), expected);
+}
+
+#[test]
+fn test_maximum_absolute() {
+    let expected = RadonTypes::from(RadonFloat::from(4f64));
+
+    assert_eq!(maximum_absolute(&sample()).unwrap(), expected);
+}
@@ -2,12 +2,18 @@
 #![allow(clippy::useless_attribute)]
 
 use crate::error::RadError;
-use crate::types::{array::RadonArray, RadonTypes};
+use crate::types::{
+    array::RadonArray, float::RadonFloat, integer::RadonInteger, string::RadonString, RadonType,
+    RadonTypes,
+};
 
 mod average;
+mod deviation;
+mod mode;
 
 use num_derive::FromPrimitive;
-use std::fmt;
+use rmpv::Value;
+use std::{fmt, mem::discriminant};
 
 #[derive(Debug, FromPrimitive, PartialEq)]
 pub enum RadonReducers {
@@ -29,7 +35,23 @@ impl fmt::Display for RadonReducers {
     }
 }
 
-pub fn reduce(input: &RadonArray, reducer_code: RadonReducers) -> Result<RadonTypes, RadError> {
+impl RadonReducers {
+    /// Whether the reducer needs a list of weights, one for each item in the input.
+    pub fn is_weighted(&self) -> bool {
+        matches!(
+            self,
+            RadonReducers::AverageMeanWeighted | RadonReducers::AverageMedianWeighted
+        )
+    }
+}
+
+/// Reduce a homogeneous array into a single value. Weighted reducers use the given `weights`,
+/// which must have the same length as the input.
+pub fn reduce(
+    input: &RadonArray,
+    reducer_code: RadonReducers,
+    weights: Option<&[f64]>,
+) -> Result<RadonTypes, RadError> {
     let error = || {
         Err(RadError::UnsupportedReducer {
             inner_type: format!("{:?}", input.inner_type()),
@@ -37,12 +59,71 @@ pub fn reduce(input: &RadonArray, reducer_code: RadonReducers) -> Result<RadonTy
         })
     };
 
-    if input.is_homogeneous() {
-        match reducer_code {
-            RadonReducers::AverageMean => average::mean(input),
-            _ => error(),
+    if !input.is_homogeneous() {
+        return error();
+    }
+
+    let inner_type = input.inner_type();
+    let is_numeric = inner_type == discriminant(&RadonTypes::from(RadonFloat::default()))
+        || inner_type == discriminant(&RadonTypes::from(RadonInteger::default()));
+    let is_string = inner_type == discriminant(&RadonTypes::from(RadonString::default()));
+
+    match (&reducer_code, weights) {
+        (RadonReducers::Mode, _) if is_numeric || is_string => mode::mode(input),
+        (RadonReducers::AverageMean, _) if is_numeric => average::mean(input),
+        (RadonReducers::AverageMeanWeighted, Some(weights)) if is_numeric => {
+            average::mean_weighted(input, weights)
+        }
+        (RadonReducers::AverageMedian, _) if is_numeric => average::median(input),
+        (RadonReducers::AverageMedianWeighted, Some(weights)) if is_numeric => {
+            average::median_weighted(input, weights)
+        }
+        (RadonReducers::DeviationStandard, _) if is_numeric => deviation::standard(input),
+        (RadonReducers::DeviationAverageAbsolute, _) if is_numeric => {
+            deviation::average_absolute(input)
+        }
+        (RadonReducers::DeviationMedianAbsolute, _) if is_numeric => {
+            deviation::median_absolute(input)
+        }
+        (RadonReducers::DeviationMaximumAbsolute, _) if is_numeric => {
+            deviation::maximum_absolute(input)
         }
+        _ => error(),
+    }
+}
+
+/// Numeric values of an array of floats or integers.
+fn numbers(input: &RadonArray) -> Vec<f64> {
+    input
+        .value()
+        .iter()
+        .filter_map(|item| match item {
+            RadonTypes::Float(float) => Some(float.value()),
+            RadonTypes::Integer(integer) => Some(integer.value() as f64),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check that every one of the weights of a weighted reducer is a finite, non-negative number.
+fn check_weights(reducer: &str, weights: &[f64]) -> Result<(), RadError> {
+    if weights
+        .iter()
+        .all(|weight| weight.is_finite() && *weight >= 0f64)
+    {
+        Ok(())
     } else {
-        error()
+        Err(RadError::WrongArguments {
+            input_type: "RadonArray".to_string(),
+            operator: reducer.to_string(),
+            args: weights.iter().cloned().map(Value::F64).collect(),
+        })
+    }
+}
+
+fn division_by_zero(reducer: &str) -> RadError {
+    RadError::DivisionByZero {
+        input_type: "RadonArray".to_string(),
+        operator: reducer.to_string(),
     }
 }
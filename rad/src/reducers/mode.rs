@@ -0,0 +1,61 @@
+use crate::error::RadError;
+use crate::types::{array::RadonArray, RadonType, RadonTypes};
+
+/// The value that appears the most times in the input. If several values appear that same number
+/// of times, there is no single mode and an error is returned.
+pub fn mode(input: &RadonArray) -> Result<RadonTypes, RadError> {
+    let mut counts: Vec<(RadonTypes, usize)> = Vec::new();
+    for item in input.value() {
+        match counts.iter_mut().find(|(value, _)| *value == item) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((item, 1)),
+        }
+    }
+
+    let max_count = counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let mut modes: Vec<RadonTypes> = counts
+        .into_iter()
+        .filter(|(_, count)| *count == max_count)
+        .map(|(value, _)| value)
+        .collect();
+
+    if modes.len() == 1 {
+        Ok(modes.remove(0))
+    } else {
+        Err(RadError::ModeTie {
+            values: RadonArray::from(modes).to_string(),
+            max_count,
+        })
+    }
+}
+
+#[test]
+fn test_mode_string() {
+    use crate::types::string::RadonString;
+
+    let input = RadonArray::from(vec![
+        RadonString::from("sunny").into(),
+        RadonString::from("rainy").into(),
+        RadonString::from("sunny").into(),
+    ]);
+    let expected = RadonTypes::from(RadonString::from("sunny"));
+
+    assert_eq!(mode(&input).unwrap(), expected);
+}
+
+#[test]
+fn test_mode_tie() {
+    use crate::types::integer::RadonInteger;
+
+    let input = RadonArray::from(vec![
+        RadonInteger::from(1).into(),
+        RadonInteger::from(2).into(),
+        RadonInteger::from(2).into(),
+        RadonInteger::from(1).into(),
+    ]);
+
+    assert_eq!(
+        &mode(&input).unwrap_err().to_string(),
+        "There is no single mode: values `RadonArray([Integer(RadonInteger { value: 1 }), Integer(RadonInteger { value: 2 })])` appear 2 times each"
+    );
+}
@@ -1,11 +1,16 @@
 use crate::error::RadError;
-use crate::operators::{operate, RadonOpCodes};
-use crate::types::RadonTypes;
+use crate::operators::{array as array_operators, operate, RadonOpCodes};
+use crate::types::{array::RadonArray, RadonType, RadonTypes};
 
 use log::error;
 use num_traits::FromPrimitive;
 use rmpv::{self, Value};
-use std::{error::Error, io::Cursor};
+use serde::Serialize;
+use std::{
+    error::Error,
+    io::Cursor,
+    time::{Duration, Instant},
+};
 
 pub type RadonCall = (RadonOpCodes, Option<Vec<Value>>);
 
@@ -19,6 +24,162 @@ pub fn execute_radon_script(
     script.iter().try_fold(input, operate)
 }
 
+/// Record of the execution of a single call of a RADON script.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RadonCallTrace {
+    /// Value on which the call operated
+    pub input: RadonTypes,
+    /// Name of the operator
+    pub operator: String,
+    /// Arguments of the call, if any
+    pub args: Option<Vec<String>>,
+    /// Value returned by the call, unless it failed
+    pub output: Option<RadonTypes>,
+    /// Error raised by the call, if it failed
+    pub error: Option<String>,
+    /// Time spent in the call
+    pub elapsed: Duration,
+    /// Traces of the subscript passed as argument to a higher-order operator such as `Map`,
+    /// `Filter` or `Sort`, one for each item of the input on which the subscript was run
+    pub subscript: Vec<Vec<RadonCallTrace>>,
+}
+
+/// Run any RADON script on given input data, recording the execution of every one of its calls,
+/// including those of the subscripts of higher-order operators. The trace ends with the call that
+/// failed, if any.
+pub fn execute_radon_script_with_trace(
+    input: RadonTypes,
+    script: &[RadonCall],
+) -> (Result<RadonTypes, RadError>, Vec<RadonCallTrace>) {
+    let mut trace = Vec::with_capacity(script.len());
+    let mut value = input;
+
+    for call in script {
+        let start = Instant::now();
+        let result = operate(value.clone(), call);
+        let elapsed = start.elapsed();
+        let subscript = trace_subscript(&value, call);
+
+        let (output, error) = match &result {
+            Ok(output) => (Some(output.clone()), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+        trace.push(RadonCallTrace {
+            input: value,
+            operator: call.0.to_string(),
+            args: call
+                .1
+                .as_ref()
+                .map(|args| args.iter().map(Value::to_string).collect()),
+            output,
+            error,
+            elapsed,
+            subscript,
+        });
+
+        match result {
+            Ok(output) => value = output,
+            Err(error) => return (Err(error), trace),
+        }
+    }
+
+    (Ok(value), trace)
+}
+
+/// Trace the runs of the subscript passed as argument to a higher-order operator, in the same
+/// order as the operator runs it on the items of the input and stopping at the same item: the
+/// first one on which it fails or, for predicates, the one that decides the output.
+fn trace_subscript(input: &RadonTypes, (op_code, args): &RadonCall) -> Vec<Vec<RadonCallTrace>> {
+    // Whether the subscript is a predicate, and the value of it that stops the operator, if any
+    let (is_predicate, stop_at) = match op_code {
+        RadonOpCodes::ArrayMap | RadonOpCodes::ArraySort => (false, None),
+        RadonOpCodes::ArrayFilter => (true, None),
+        RadonOpCodes::ArraySome => (true, Some(true)),
+        RadonOpCodes::ArrayEvery => (true, Some(false)),
+        _ => return vec![],
+    };
+    // Filters can also be given the code of a predefined filter instead of a subscript
+    let (items, subscript) = match (input, args.as_ref().and_then(|args| args.first())) {
+        (RadonTypes::Array(array), Some(subscript @ Value::Array(_))) => {
+            match unpack_subscript(subscript) {
+                Ok(subscript) => (array.value(), subscript),
+                Err(_) => return vec![],
+            }
+        }
+        _ => return vec![],
+    };
+
+    let mut traces = Vec::new();
+    for item in items {
+        let (result, trace) = execute_radon_script_with_trace(item, &subscript);
+        traces.push(trace);
+
+        match result {
+            Ok(RadonTypes::Boolean(boolean)) if is_predicate => {
+                if stop_at == Some(boolean.value()) {
+                    break;
+                }
+            }
+            Ok(_) if !is_predicate => {}
+            _ => break,
+        }
+    }
+
+    traces
+}
+
+/// Run a RADON script on an array, also telling for each one of its items whether it survived the
+/// filters applied to the array before it was reduced or otherwise transformed.
+pub fn execute_radon_script_with_survivors(
+    input: RadonArray,
+    script: &[RadonCall],
+) -> Result<(RadonTypes, Vec<bool>), RadError> {
+    // Positions in the input array of the items that are still in the array being operated
+    let mut survivors: Vec<usize> = (0..input.value().len()).collect();
+    let mut survived = vec![false; survivors.len()];
+    let mut value = RadonTypes::from(input);
+
+    for (index, call) in script.iter().enumerate() {
+        let (output, tracking) = match (&value, call) {
+            (RadonTypes::Array(before), (RadonOpCodes::ArrayFilter, Some(args))) => {
+                let (after, positions) = array_operators::filter_with_positions(before, args)?;
+                survivors = positions
+                    .into_iter()
+                    .map(|position| survivors[position])
+                    .collect();
+
+                (RadonTypes::from(after), true)
+            }
+            _ => {
+                let output = operate(value.clone(), call)?;
+                let tracking = match (&value, &output, &call.0) {
+                    // Mapping keeps every item in its position
+                    (
+                        RadonTypes::Array(before),
+                        RadonTypes::Array(after),
+                        RadonOpCodes::ArrayMap,
+                    ) => before.value().len() == after.value().len(),
+                    _ => false,
+                };
+
+                (output, tracking)
+            }
+        };
+        value = output;
+
+        if !tracking {
+            value = execute_radon_script(value, &script[index + 1..])?;
+            break;
+        }
+    }
+
+    for position in survivors {
+        survived[position] = true;
+    }
+
+    Ok((value, survived))
+}
+
 pub fn unpack_radon_script(packed: &[u8]) -> Result<RadonScript, RadError> {
     let reader = &mut Cursor::new(packed);
 
@@ -36,6 +197,46 @@ pub fn unpack_radon_script(packed: &[u8]) -> Result<RadonScript, RadError> {
     }
 }
 
+/// Pack a RADON script into MessagePack, as expected by `unpack_radon_script`.
+pub fn pack_radon_script(script: &[RadonCall]) -> Result<Vec<u8>, RadError> {
+    let mut packed = Vec::new();
+    rmpv::encode::write_value(&mut packed, &pack_subscript(script)).map_err(|error| {
+        RadError::MessagePack {
+            description: error.to_string(),
+        }
+    })?;
+
+    Ok(packed)
+}
+
+/// Pack a script to be passed as the argument of an operator, as expected by `unpack_subscript`.
+pub fn pack_subscript(script: &[RadonCall]) -> Value {
+    Value::Array(script.iter().map(pack_radon_call).collect())
+}
+
+fn pack_radon_call((op_code, args): &RadonCall) -> Value {
+    let code = Value::from(*op_code as i64);
+
+    match args {
+        Some(args) => Value::Array(std::iter::once(code).chain(args.iter().cloned()).collect()),
+        None => code,
+    }
+}
+
+/// Unpack a script passed as the argument of an operator, i.e. a subscript. Unlike top-level
+/// scripts, subscripts are already decoded from MessagePack as part of the arguments.
+pub fn unpack_subscript(value: &Value) -> Result<RadonScript, RadError> {
+    match value {
+        Value::Array(array) => array
+            .iter()
+            .map(unpack_radon_call)
+            .collect::<Result<RadonScript, RadError>>(),
+        other => Err(errorify(RadError::ScriptNotArray {
+            input_type: other.to_string(),
+        })),
+    }
+}
+
 fn unpack_radon_call(packed_call: &Value) -> Result<RadonCall, RadError> {
     match packed_call {
         Value::Array(array) => unpack_compound_call(array),
@@ -117,3 +318,204 @@ fn test_unpack_radon_script() {
 
     assert_eq!(output, expected)
 }
+
+#[test]
+fn test_pack_radon_script() {
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("main")])),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("temp")])),
+        (RadonOpCodes::ToFloat, None),
+    ];
+    let expected = vec![
+        150, 83, 204, 132, 146, 1, 164, 109, 97, 105, 110, 204, 132, 146, 1, 164, 116, 101, 109,
+        112, 204, 130,
+    ];
+
+    let packed = pack_radon_script(&script).unwrap();
+
+    assert_eq!(packed, expected);
+    assert_eq!(unpack_radon_script(&packed).unwrap(), script);
+}
+
+#[test]
+fn test_unpack_subscript() {
+    let packed = Value::from(vec![
+        Value::from(vec![Value::from(0x01), Value::from("price")]),
+        Value::from(0x82),
+    ]);
+    let expected = vec![
+        (RadonOpCodes::Get, Some(vec![Value::from("price")])),
+        (RadonOpCodes::ToFloat, None),
+    ];
+
+    let output = unpack_subscript(&packed).unwrap();
+
+    assert_eq!(output, expected);
+    assert!(unpack_subscript(&Value::from(0x82)).is_err());
+}
+
+#[test]
+fn test_execute_radon_script_with_survivors() {
+    use crate::types::float::RadonFloat;
+
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1.0).into(),
+        RadonFloat::from(10.0).into(),
+        RadonFloat::from(1.1).into(),
+        RadonFloat::from(0.9).into(),
+        RadonFloat::from(1.0).into(),
+    ]);
+    let script = vec![
+        // Filter by DeviationStandard
+        (
+            RadonOpCodes::ArrayFilter,
+            Some(vec![Value::from(0x30), Value::from(1.5)]),
+        ),
+        // Reduce by AverageMedian
+        (RadonOpCodes::Reduce, Some(vec![Value::from(0x22)])),
+    ];
+
+    let (output, survived) = execute_radon_script_with_survivors(input, &script).unwrap();
+
+    assert_eq!(output, RadonTypes::from(RadonFloat::from(1.0)));
+    assert_eq!(survived, vec![true, false, true, true, true]);
+}
+
+#[test]
+fn test_execute_radon_script_with_survivors_nan() {
+    use crate::types::float::RadonFloat;
+
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1.0).into(),
+        RadonFloat::from(std::f64::NAN).into(),
+        RadonFloat::from(2.0).into(),
+        RadonFloat::from(std::f64::NAN).into(),
+    ]);
+    let script = vec![
+        // Filter by [ [ FloatGreaterThan, 1.5 ], BooleanNegate ], which keeps NaN
+        (
+            RadonOpCodes::ArrayFilter,
+            Some(vec![Value::from(vec![
+                Value::from(vec![Value::from(0x3C), Value::from(1.5)]),
+                Value::from(0x11),
+            ])]),
+        ),
+        (RadonOpCodes::ArrayCount, None),
+    ];
+
+    let (output, survived) = execute_radon_script_with_survivors(input, &script).unwrap();
+
+    assert_eq!(
+        output,
+        RadonTypes::from(crate::types::integer::RadonInteger::from(3))
+    );
+    assert_eq!(survived, vec![true, true, false, true]);
+}
+
+#[test]
+fn test_execute_radon_script_with_trace() {
+    use crate::types::{float::RadonFloat, string::RadonString};
+
+    let input = RadonString::from(r#"{"main":{"temp":-4}}"#).into();
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("main")])),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("temp")])),
+        (RadonOpCodes::ToFloat, None),
+    ];
+
+    let (output, trace) = execute_radon_script_with_trace(input, &script);
+
+    assert_eq!(output.unwrap(), RadonTypes::from(RadonFloat::from(-4f64)));
+    assert_eq!(trace.len(), script.len());
+    assert_eq!(trace[2].operator, "Get");
+    assert_eq!(trace[2].args, Some(vec![r#""main""#.to_string()]));
+    assert_eq!(
+        trace[5].output,
+        Some(RadonTypes::from(RadonFloat::from(-4f64)))
+    );
+    assert!(trace.iter().all(|call| call.error.is_none()));
+}
+
+#[test]
+fn test_execute_radon_script_with_trace_error() {
+    use crate::types::string::RadonString;
+
+    let input = RadonString::from(r#"{"main":{"temp":-4}}"#).into();
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("wind")])),
+        (RadonOpCodes::ToFloat, None),
+    ];
+
+    let (output, trace) = execute_radon_script_with_trace(input, &script);
+
+    let error = output.unwrap_err();
+    assert_eq!(trace.len(), 3);
+    assert_eq!(trace[2].output, None);
+    assert_eq!(trace[2].error, Some(error.to_string()));
+    assert_eq!(trace[2].input, trace[1].output.clone().unwrap());
+}
+
+#[test]
+fn test_execute_radon_script_with_trace_subscripts() {
+    use crate::types::{float::RadonFloat, string::RadonString};
+
+    let input = RadonArray::from(vec![
+        RadonFloat::from(1f64).into(),
+        RadonFloat::from(2f64).into(),
+        RadonFloat::from(3f64).into(),
+    ]);
+    let double = pack_subscript(&[(RadonOpCodes::FloatMultiply, Some(vec![Value::from(2f64)]))]);
+    let greater_than_three = pack_subscript(&[(
+        RadonOpCodes::FloatGreaterThan,
+        Some(vec![Value::from(3f64)]),
+    )]);
+    let script = vec![
+        (RadonOpCodes::ArrayMap, Some(vec![double])),
+        (RadonOpCodes::ArraySome, Some(vec![greater_than_three])),
+    ];
+
+    let (output, trace) = execute_radon_script_with_trace(input.into(), &script);
+
+    assert!(output.is_ok());
+    let doubled: Vec<_> = trace[0]
+        .subscript
+        .iter()
+        .map(|run| run[0].output.clone().unwrap())
+        .collect();
+    assert_eq!(
+        doubled,
+        vec![
+            RadonTypes::from(RadonFloat::from(2f64)),
+            RadonTypes::from(RadonFloat::from(4f64)),
+            RadonTypes::from(RadonFloat::from(6f64)),
+        ]
+    );
+    // `Some` stops at the first item for which the subscript returns `true`
+    assert_eq!(trace[1].subscript.len(), 2);
+
+    // Subscripts stop at the first item on which they fail
+    let input = RadonArray::from(vec![
+        RadonString::from("1").into(),
+        RadonString::from("one").into(),
+        RadonString::from("3").into(),
+    ]);
+    let to_float = pack_subscript(&[(RadonOpCodes::ToFloat, None)]);
+    let script = vec![(RadonOpCodes::ArrayMap, Some(vec![to_float]))];
+
+    let (output, trace) = execute_radon_script_with_trace(input.into(), &script);
+
+    assert_eq!(trace[0].subscript.len(), 2);
+    assert!(trace[0].subscript[0][0].error.is_none());
+    assert_eq!(
+        trace[0].subscript[1][0].error,
+        Some(output.unwrap_err().to_string())
+    );
+}
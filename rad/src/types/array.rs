@@ -11,7 +11,8 @@ use crate::error::RadError;
 use crate::operators::{array as array_operators, identity, Operable, RadonOpCodes};
 use crate::script::RadonCall;
 use crate::types::{
-    float::RadonFloat, map::RadonMap, mixed::RadonMixed, string::RadonString, RadonType, RadonTypes,
+    boolean::RadonBoolean, bytes::RadonBytes, float::RadonFloat, integer::RadonInteger,
+    map::RadonMap, mixed::RadonMixed, string::RadonString, RadonType, RadonTypes,
 };
 
 fn mixed_discriminant() -> Discriminant<RadonTypes> {
@@ -45,8 +46,14 @@ impl Serialize for RadonArray {
 
         state.serialize_field("value", &self.value)?;
 
-        if self.inner_type() == discriminant(&RadonTypes::Float(RadonFloat::default())) {
+        if self.inner_type() == discriminant(&RadonTypes::Boolean(RadonBoolean::default())) {
+            state.serialize_field("inner_type", "RadonBoolean")?;
+        } else if self.inner_type() == discriminant(&RadonTypes::Bytes(RadonBytes::default())) {
+            state.serialize_field("inner_type", "RadonBytes")?;
+        } else if self.inner_type() == discriminant(&RadonTypes::Float(RadonFloat::default())) {
             state.serialize_field("inner_type", "RadonFloat")?;
+        } else if self.inner_type() == discriminant(&RadonTypes::Integer(RadonInteger::default())) {
+            state.serialize_field("inner_type", "RadonInteger")?;
         } else if self.inner_type() == discriminant(&RadonTypes::Map(RadonMap::default())) {
             state.serialize_field("inner_type", "RadonMap")?;
         } else if self.inner_type() == discriminant(&RadonTypes::Mixed(RadonMixed::default()))
@@ -138,7 +145,34 @@ impl Operable for RadonArray {
     fn operate(self, call: &RadonCall) -> Result<RadonTypes, RadError> {
         match call {
             (RadonOpCodes::Identity, None) => identity(self.into()),
+            (RadonOpCodes::ArrayCount, None) => Ok(array_operators::count(&self).into()),
+            (RadonOpCodes::ArrayEvery, Some(args)) => {
+                array_operators::every(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::ArrayFilter, Some(args)) => {
+                array_operators::filter(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::ArrayFlatten, args) => {
+                array_operators::flatten(&self, args.as_ref().map_or(&[][..], Vec::as_slice))
+                    .map(Into::into)
+            }
+            (RadonOpCodes::ArrayMap, Some(args)) => {
+                array_operators::map(&self, args.as_slice()).map(Into::into)
+            }
             (RadonOpCodes::Reduce, Some(args)) => array_operators::reduce(&self, args.as_slice()),
+            (RadonOpCodes::ArraySome, Some(args)) => {
+                array_operators::some(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::ArraySort, Some(args)) => {
+                array_operators::sort(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::ArrayTake, args) => {
+                array_operators::take(&self, args.as_ref().map_or(&[][..], Vec::as_slice))
+                    .map(Into::into)
+            }
+            (RadonOpCodes::ArraySkip, Some(args)) => {
+                array_operators::skip(&self, args.as_slice()).map(Into::into)
+            }
             (RadonOpCodes::Get, Some(args)) => array_operators::get(&self, args.as_slice()),
             (op_code, args) => Err(RadError::UnsupportedOperator {
                 input_type: RADON_ARRAY_TYPE_NAME.to_string(),
@@ -178,6 +212,22 @@ fn test_operate_reduce_average_mean_float() {
     assert_eq!(output, expected);
 }
 
+#[test]
+fn test_operate_count() {
+    use crate::types::integer::RadonInteger;
+
+    let input = RadonArray::from(vec![
+        RadonInteger::from(1).into(),
+        RadonInteger::from(2).into(),
+    ]);
+    let call = (RadonOpCodes::ArrayCount, None);
+    let expected = RadonTypes::from(RadonInteger::from(2));
+
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
 #[test]
 fn test_operate_unimplemented() {
     let input = RadonArray::from(vec![]);
@@ -0,0 +1,137 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+use rmpv::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RadError;
+use crate::operators::{boolean as boolean_operators, identity, Operable, RadonOpCodes};
+use crate::script::RadonCall;
+use crate::types::{RadonType, RadonTypes};
+
+pub const RADON_BOOLEAN_TYPE_NAME: &str = "RadonBoolean";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct RadonBoolean {
+    value: bool,
+}
+
+impl RadonType<bool> for RadonBoolean {
+    fn value(&self) -> bool {
+        self.value
+    }
+
+    fn radon_type_name() -> String {
+        RADON_BOOLEAN_TYPE_NAME.to_string()
+    }
+}
+
+impl TryFrom<Value> for RadonBoolean {
+    type Error = RadError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value
+            .as_bool()
+            .map(Self::from)
+            .ok_or_else(|| RadError::Decode {
+                from: "rmpv::Value".to_string(),
+                to: RADON_BOOLEAN_TYPE_NAME.to_string(),
+            })
+    }
+}
+
+impl TryInto<Value> for RadonBoolean {
+    type Error = RadError;
+
+    fn try_into(self) -> Result<Value, Self::Error> {
+        Ok(Value::from(self.value()))
+    }
+}
+
+impl From<bool> for RadonBoolean {
+    fn from(value: bool) -> Self {
+        RadonBoolean { value }
+    }
+}
+
+impl Operable for RadonBoolean {
+    fn operate(self, call: &RadonCall) -> Result<RadonTypes, RadError> {
+        match call {
+            (RadonOpCodes::Identity, None) => identity(RadonTypes::Boolean(self)),
+            (RadonOpCodes::BooleanMatch, Some(args)) => {
+                boolean_operators::match_value(&self, args.as_slice())
+            }
+            (RadonOpCodes::BooleanNegate, None) => Ok(boolean_operators::negate(&self).into()),
+            (RadonOpCodes::BooleanToString, None) => Ok(boolean_operators::to_string(&self).into()),
+            (op_code, args) => Err(RadError::UnsupportedOperator {
+                input_type: RADON_BOOLEAN_TYPE_NAME.to_string(),
+                operator: op_code.to_string(),
+                args: args.to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for RadonBoolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", RADON_BOOLEAN_TYPE_NAME, self.value)
+    }
+}
+
+#[test]
+fn test_operate_identity() {
+    let input = RadonBoolean::from(true);
+    let expected = RadonBoolean::from(true).into();
+
+    let call = (RadonOpCodes::Identity, None);
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_negate() {
+    let input = RadonBoolean::from(true);
+    let expected = RadonBoolean::from(false).into();
+
+    let call = (RadonOpCodes::BooleanNegate, None);
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_unimplemented() {
+    let input = RadonBoolean::from(true);
+
+    let call = (RadonOpCodes::Fail, None);
+    let result = input.operate(&call);
+
+    assert!(if let Err(_error) = result {
+        true
+    } else {
+        false
+    });
+}
+
+#[test]
+fn test_from_vector() {
+    let input: &[u8] = &[0xc3]; // true
+
+    let expected = RadonTypes::from(RadonBoolean::from(true));
+    let result = RadonTypes::try_from(input).unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_serialize_radon_boolean() {
+    let input = RadonTypes::from(RadonBoolean::from(false));
+    let expected: Vec<u8> = vec![0xc2];
+
+    let output: Vec<u8> = input.try_into().unwrap();
+
+    assert_eq!(output, expected);
+}
@@ -0,0 +1,122 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+use rmpv::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RadError;
+use crate::operators::{bytes as bytes_operators, identity, Operable, RadonOpCodes};
+use crate::script::RadonCall;
+use crate::types::{RadonType, RadonTypes};
+
+pub const RADON_BYTES_TYPE_NAME: &str = "RadonBytes";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct RadonBytes {
+    value: Vec<u8>,
+}
+
+impl RadonType<Vec<u8>> for RadonBytes {
+    fn value(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    fn radon_type_name() -> String {
+        RADON_BYTES_TYPE_NAME.to_string()
+    }
+}
+
+impl TryFrom<Value> for RadonBytes {
+    type Error = RadError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value
+            .as_slice()
+            .map(Self::from)
+            .ok_or_else(|| RadError::Decode {
+                from: "rmpv::Value".to_string(),
+                to: RADON_BYTES_TYPE_NAME.to_string(),
+            })
+    }
+}
+
+impl TryInto<Value> for RadonBytes {
+    type Error = RadError;
+
+    fn try_into(self) -> Result<Value, Self::Error> {
+        Ok(Value::from(self.value))
+    }
+}
+
+impl From<Vec<u8>> for RadonBytes {
+    fn from(value: Vec<u8>) -> Self {
+        RadonBytes { value }
+    }
+}
+
+impl<'a> From<&'a [u8]> for RadonBytes {
+    fn from(value: &'a [u8]) -> Self {
+        Self::from(value.to_vec())
+    }
+}
+
+impl Operable for RadonBytes {
+    fn operate(self, call: &RadonCall) -> Result<RadonTypes, RadError> {
+        match call {
+            (RadonOpCodes::Identity, None) => identity(RadonTypes::Bytes(self)),
+            (RadonOpCodes::Hash, Some(args)) => {
+                bytes_operators::hash(&self, args.as_slice()).map(Into::into)
+            }
+            (op_code, args) => Err(RadError::UnsupportedOperator {
+                input_type: RADON_BYTES_TYPE_NAME.to_string(),
+                operator: op_code.to_string(),
+                args: args.to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for RadonBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", RADON_BYTES_TYPE_NAME, hex::encode(&self.value))
+    }
+}
+
+#[test]
+fn test_operate_identity() {
+    let input = RadonBytes::from(vec![1, 2, 3]);
+    let expected = RadonBytes::from(vec![1, 2, 3]).into();
+
+    let call = (RadonOpCodes::Identity, None);
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_unimplemented() {
+    let input = RadonBytes::from(vec![1, 2, 3]);
+
+    let call = (RadonOpCodes::Fail, None);
+    let result = input.operate(&call);
+
+    assert!(if let Err(_error) = result {
+        true
+    } else {
+        false
+    });
+}
+
+#[test]
+fn test_try_from_and_into_vector() {
+    let input: &[u8] = &[0xc4, 3, 1, 2, 3];
+
+    let expected = RadonTypes::from(RadonBytes::from(vec![1, 2, 3]));
+    let result = RadonTypes::try_from(input).unwrap();
+    assert_eq!(result, expected);
+
+    let output: Vec<u8> = result.try_into().unwrap();
+    assert_eq!(output.as_slice(), input);
+}
@@ -7,7 +7,7 @@ use rmpv::Value;
 use serde::{Deserialize, Serialize};
 
 use crate::error::RadError;
-use crate::operators::{identity, Operable, RadonOpCodes};
+use crate::operators::{float as float_operators, identity, Operable, RadonOpCodes};
 use crate::script::RadonCall;
 use crate::types::{RadonType, RadonTypes};
 
@@ -64,6 +64,43 @@ impl<'a> Operable for RadonFloat {
         match call {
             // Identity
             (RadonOpCodes::Identity, None) => identity(RadonTypes::Float(self)),
+            // Arithmetic
+            (RadonOpCodes::FloatAbsolute, None) => Ok(float_operators::absolute(&self).into()),
+            (RadonOpCodes::FloatCeiling, None) => float_operators::ceiling(&self).map(Into::into),
+            (RadonOpCodes::FloatFloor, None) => float_operators::floor(&self).map(Into::into),
+            (RadonOpCodes::FloatModulo, Some(args)) => {
+                float_operators::modulo(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatMultiply, Some(args)) => {
+                float_operators::multiply(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatNegate, None) => Ok(float_operators::negate(&self).into()),
+            (RadonOpCodes::FloatPower, Some(args)) => {
+                float_operators::power(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatReciprocal, None) => {
+                float_operators::reciprocal(&self).map(Into::into)
+            }
+            (RadonOpCodes::FloatRound, None) => float_operators::round(&self).map(Into::into),
+            (RadonOpCodes::FloatSum, Some(args)) => {
+                float_operators::sum(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatTruncate, None) => float_operators::truncate(&self).map(Into::into),
+            // Comparison
+            (RadonOpCodes::FloatGreaterThan, Some(args)) => {
+                float_operators::greater_than(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatLessThan, Some(args)) => {
+                float_operators::less_than(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::FloatEquals, Some(args)) => {
+                float_operators::equals(&self, args.as_slice()).map(Into::into)
+            }
+            // To String
+            (RadonOpCodes::FloatToString, args) => {
+                float_operators::to_string(&self, args.as_ref().map_or(&[][..], Vec::as_slice))
+                    .map(Into::into)
+            }
             // Unsupported / unimplemented
             (op_code, args) => Err(RadError::UnsupportedOperator {
                 input_type: RADON_FLOAT_TYPE_NAME.to_string(),
@@ -94,6 +131,30 @@ fn test_operate_unimplemented() {
     });
 }
 
+#[test]
+fn test_operate_sum() {
+    let input = RadonFloat::from(1.5);
+    let expected = RadonFloat::from(4f64).into();
+
+    let call = (RadonOpCodes::FloatSum, Some(vec![Value::from(2.5)]));
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_round() {
+    use crate::types::integer::RadonInteger;
+
+    let input = RadonFloat::from(2.7);
+    let expected = RadonInteger::from(3).into();
+
+    let call = (RadonOpCodes::FloatRound, None);
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
 #[test]
 fn test_from_vector() {
     let input: &[u8] = &[203, 64, 9, 33, 251, 84, 68, 45, 24]; // 3.141592653589793
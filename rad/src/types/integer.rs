@@ -0,0 +1,159 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+use rmpv::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RadError;
+use crate::operators::{identity, integer as integer_operators, Operable, RadonOpCodes};
+use crate::script::RadonCall;
+use crate::types::{RadonType, RadonTypes};
+
+pub const RADON_INTEGER_TYPE_NAME: &str = "RadonInteger";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct RadonInteger {
+    value: i64,
+}
+
+impl RadonType<i64> for RadonInteger {
+    fn value(&self) -> i64 {
+        self.value
+    }
+
+    fn radon_type_name() -> String {
+        RADON_INTEGER_TYPE_NAME.to_string()
+    }
+}
+
+impl TryFrom<Value> for RadonInteger {
+    type Error = RadError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value
+            .as_i64()
+            .map(Self::from)
+            .ok_or_else(|| RadError::Decode {
+                from: "rmpv::Value".to_string(),
+                to: RADON_INTEGER_TYPE_NAME.to_string(),
+            })
+    }
+}
+
+impl TryInto<Value> for RadonInteger {
+    type Error = RadError;
+
+    fn try_into(self) -> Result<Value, Self::Error> {
+        Ok(Value::from(self.value()))
+    }
+}
+
+impl From<i64> for RadonInteger {
+    fn from(value: i64) -> Self {
+        RadonInteger { value }
+    }
+}
+
+impl Operable for RadonInteger {
+    fn operate(self, call: &RadonCall) -> Result<RadonTypes, RadError> {
+        match call {
+            (RadonOpCodes::Identity, None) => identity(RadonTypes::Integer(self)),
+            (RadonOpCodes::IntegerAbsolute, None) => {
+                integer_operators::absolute(&self).map(Into::into)
+            }
+            (RadonOpCodes::IntegerMatch, Some(args)) => {
+                integer_operators::match_value(&self, args.as_slice())
+            }
+            (RadonOpCodes::IntegerModulo, Some(args)) => {
+                integer_operators::modulo(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::IntegerMultiply, Some(args)) => {
+                integer_operators::multiply(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::IntegerNegate, None) => integer_operators::negate(&self).map(Into::into),
+            (RadonOpCodes::IntegerPower, Some(args)) => {
+                integer_operators::power(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::IntegerReciprocal, None) => {
+                integer_operators::reciprocal(&self).map(Into::into)
+            }
+            (RadonOpCodes::IntegerSum, Some(args)) => {
+                integer_operators::sum(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::IntegerToFloat, None) => Ok(integer_operators::to_float(&self).into()),
+            (RadonOpCodes::IntegerToString, args) => {
+                integer_operators::to_string(&self, args.as_ref().map_or(&[][..], Vec::as_slice))
+                    .map(Into::into)
+            }
+            (op_code, args) => Err(RadError::UnsupportedOperator {
+                input_type: RADON_INTEGER_TYPE_NAME.to_string(),
+                operator: op_code.to_string(),
+                args: args.to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for RadonInteger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", RADON_INTEGER_TYPE_NAME, self.value)
+    }
+}
+
+#[test]
+fn test_operate_identity() {
+    let input = RadonInteger::from(42);
+    let expected = RadonInteger::from(42).into();
+
+    let call = (RadonOpCodes::Identity, None);
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_sum() {
+    let input = RadonInteger::from(40);
+    let expected = RadonInteger::from(42).into();
+
+    let call = (RadonOpCodes::IntegerSum, Some(vec![Value::from(2)]));
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_operate_unimplemented() {
+    let input = RadonInteger::from(42);
+
+    let call = (RadonOpCodes::Fail, None);
+    let result = input.operate(&call);
+
+    assert!(if let Err(_error) = result {
+        true
+    } else {
+        false
+    });
+}
+
+#[test]
+fn test_from_vector() {
+    let input: &[u8] = &[0xcf, 0, 0, 0, 2, 84, 11, 228, 0]; // 10_000_000_000 as u64
+
+    let expected = RadonTypes::from(RadonInteger::from(10_000_000_000));
+    let result = RadonTypes::try_from(input).unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_serialize_radon_integer() {
+    let input = RadonTypes::from(RadonInteger::from(-4));
+    let expected: Vec<u8> = vec![0xfc];
+
+    let output: Vec<u8> = input.try_into().unwrap();
+
+    assert_eq!(output, expected);
+}
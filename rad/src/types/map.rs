@@ -92,6 +92,15 @@ impl Operable for RadonMap {
             (RadonOpCodes::Get, Some(args)) => {
                 map_operators::get(&self, args.as_slice()).map(Into::into)
             }
+            (RadonOpCodes::MapEntries, None) => Ok(map_operators::entries(&self).into()),
+            (RadonOpCodes::MapGetPath, Some(args)) => {
+                map_operators::get_path(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::MapHasKey, Some(args)) => {
+                map_operators::has_key(&self, args.as_slice()).map(Into::into)
+            }
+            (RadonOpCodes::MapKeys, None) => Ok(map_operators::keys(&self).into()),
+            (RadonOpCodes::MapValues, None) => Ok(map_operators::values(&self).into()),
             (op_code, args) => Err(RadError::UnsupportedOperator {
                 input_type: RADON_MAP_TYPE_NAME.to_string(),
                 operator: op_code.to_string(),
@@ -59,10 +59,22 @@ impl Operable for RadonMixed {
         match call {
             // Identity
             (RadonOpCodes::Identity, None) => identity(RadonTypes::Mixed(self)),
+            // To Boolean
+            (RadonOpCodes::ToBoolean, None) => mixed_operators::to_boolean(self)
+                .map(RadonTypes::from)
+                .map_err(Into::into),
+            // To Bytes
+            (RadonOpCodes::ToBytes, None) => mixed_operators::to_bytes(self)
+                .map(RadonTypes::from)
+                .map_err(Into::into),
             // To Float
             (RadonOpCodes::ToFloat, None) => mixed_operators::to_float(self)
                 .map(RadonTypes::from)
                 .map_err(Into::into),
+            // To Integer
+            (RadonOpCodes::ToInteger, None) => mixed_operators::to_integer(self)
+                .map(RadonTypes::from)
+                .map_err(Into::into),
             // To Array
             (RadonOpCodes::ToArray, None) => mixed_operators::to_array(self)
                 .map(RadonTypes::from)
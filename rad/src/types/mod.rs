@@ -9,7 +9,10 @@ use serde::Serialize;
 
 use crate::error::RadError;
 use crate::types::array::RadonArray;
+use crate::types::boolean::RadonBoolean;
+use crate::types::bytes::RadonBytes;
 use crate::types::float::RadonFloat;
+use crate::types::integer::RadonInteger;
 use crate::types::map::RadonMap;
 use crate::types::mixed::RadonMixed;
 use crate::types::string::RadonString;
@@ -17,7 +20,10 @@ use witnet_crypto::hash::calculate_sha256;
 use witnet_data_structures::chain::Hash;
 
 pub mod array;
+pub mod boolean;
+pub mod bytes;
 pub mod float;
+pub mod integer;
 pub mod map;
 pub mod mixed;
 pub mod string;
@@ -34,7 +40,10 @@ where
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum RadonTypes {
     Array(RadonArray),
+    Boolean(RadonBoolean),
+    Bytes(RadonBytes),
     Float(RadonFloat),
+    Integer(RadonInteger),
     Map(RadonMap),
     Mixed(RadonMixed),
     String(RadonString),
@@ -51,7 +60,10 @@ impl RadonTypes {
     pub fn radon_type_name(self) -> String {
         match self {
             RadonTypes::Array(_) => RadonArray::radon_type_name(),
+            RadonTypes::Boolean(_) => RadonBoolean::radon_type_name(),
+            RadonTypes::Bytes(_) => RadonBytes::radon_type_name(),
             RadonTypes::Float(_) => RadonFloat::radon_type_name(),
+            RadonTypes::Integer(_) => RadonInteger::radon_type_name(),
             RadonTypes::Map(_) => RadonMap::radon_type_name(),
             RadonTypes::Mixed(_) => RadonMixed::radon_type_name(),
             RadonTypes::String(_) => RadonString::radon_type_name(),
@@ -63,7 +75,10 @@ impl fmt::Display for RadonTypes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             RadonTypes::Array(inner) => write!(f, "RadonTypes::{}", inner),
+            RadonTypes::Boolean(inner) => write!(f, "RadonTypes::{}", inner),
+            RadonTypes::Bytes(inner) => write!(f, "RadonTypes::{}", inner),
             RadonTypes::Float(inner) => write!(f, "RadonTypes::{}", inner),
+            RadonTypes::Integer(inner) => write!(f, "RadonTypes::{}", inner),
             RadonTypes::Map(inner) => write!(f, "RadonTypes::{}", inner),
             RadonTypes::Mixed(inner) => write!(f, "RadonTypes::{}", inner),
             RadonTypes::String(inner) => write!(f, "RadonTypes::{}", inner),
@@ -77,12 +92,30 @@ impl From<RadonArray> for RadonTypes {
     }
 }
 
+impl From<RadonBoolean> for RadonTypes {
+    fn from(boolean: RadonBoolean) -> Self {
+        RadonTypes::Boolean(boolean)
+    }
+}
+
+impl From<RadonBytes> for RadonTypes {
+    fn from(bytes: RadonBytes) -> Self {
+        RadonTypes::Bytes(bytes)
+    }
+}
+
 impl From<RadonFloat> for RadonTypes {
     fn from(float: RadonFloat) -> Self {
         RadonTypes::Float(float)
     }
 }
 
+impl From<RadonInteger> for RadonTypes {
+    fn from(integer: RadonInteger) -> Self {
+        RadonTypes::Integer(integer)
+    }
+}
+
 impl From<RadonMap> for RadonTypes {
     fn from(map: RadonMap) -> Self {
         RadonTypes::Map(map)
@@ -107,7 +140,12 @@ impl TryFrom<Value> for RadonTypes {
     fn try_from(value: Value) -> Result<RadonTypes, Self::Error> {
         match value {
             Value::Array(_) => RadonArray::try_from(value).map(Into::into),
+            Value::Binary(_) => RadonBytes::try_from(value).map(Into::into),
+            Value::Boolean(_) => RadonBoolean::try_from(value).map(Into::into),
             Value::F64(_) => RadonFloat::try_from(value).map(Into::into),
+            Value::Integer(integer) if integer.as_i64().is_some() => {
+                RadonInteger::try_from(value).map(Into::into)
+            }
             Value::Map(_) => RadonMap::try_from(value).map(Into::into),
             Value::String(_) => RadonString::try_from(value).map(Into::into),
             _ => Ok(RadonMixed::from(value).into()),
@@ -121,7 +159,10 @@ impl TryInto<Value> for RadonTypes {
     fn try_into(self) -> Result<Value, Self::Error> {
         match self {
             RadonTypes::Array(radon_array) => radon_array.try_into(),
+            RadonTypes::Boolean(radon_boolean) => radon_boolean.try_into(),
+            RadonTypes::Bytes(radon_bytes) => radon_bytes.try_into(),
             RadonTypes::Float(radon_float) => radon_float.try_into(),
+            RadonTypes::Integer(radon_integer) => radon_integer.try_into(),
             RadonTypes::Map(radon_map) => radon_map.try_into(),
             RadonTypes::Mixed(radon_mixed) => radon_mixed.try_into(),
             RadonTypes::String(radon_string) => radon_string.try_into(),
@@ -66,9 +66,43 @@ impl Operable for RadonString {
     fn operate(self, call: &RadonCall) -> Result<RadonTypes, RadError> {
         match call {
             (RadonOpCodes::Identity, None) => identity(RadonTypes::String(self)),
+            (RadonOpCodes::Hash, Some(args)) => {
+                string_operators::hash(&self, args.as_slice()).map(RadonTypes::from)
+            }
             (RadonOpCodes::ParseJson, None) => {
                 string_operators::parse_json(&self).map(RadonTypes::Mixed)
             }
+            (RadonOpCodes::ParseXml, None) => {
+                string_operators::parse_xml(&self).map(RadonTypes::Mixed)
+            }
+            (RadonOpCodes::ParseCsv, None) => {
+                string_operators::parse_csv(&self).map(RadonTypes::Mixed)
+            }
+            (RadonOpCodes::StringCaptures, Some(args)) => {
+                string_operators::captures(&self, args.as_slice()).map(RadonTypes::from)
+            }
+            (RadonOpCodes::StringLength, None) => {
+                Ok(RadonTypes::from(string_operators::length(&self)))
+            }
+            (RadonOpCodes::StringMatch, Some(args)) => {
+                string_operators::string_match(&self, args.as_slice()).map(RadonTypes::from)
+            }
+            (RadonOpCodes::StringReplace, Some(args)) => {
+                string_operators::replace(&self, args.as_slice()).map(RadonTypes::from)
+            }
+            (RadonOpCodes::StringSlice, Some(args)) => {
+                string_operators::slice(&self, args.as_slice()).map(RadonTypes::from)
+            }
+            (RadonOpCodes::StringSplit, Some(args)) => {
+                string_operators::split(&self, args.as_slice()).map(RadonTypes::from)
+            }
+            (RadonOpCodes::StringToLowerCase, None) => {
+                Ok(RadonTypes::from(string_operators::to_lowercase(&self)))
+            }
+            (RadonOpCodes::StringToUpperCase, None) => {
+                Ok(RadonTypes::from(string_operators::to_uppercase(&self)))
+            }
+            (RadonOpCodes::StringTrim, None) => Ok(RadonTypes::from(string_operators::trim(&self))),
             (RadonOpCodes::ToFloat, None) => string_operators::to_float(&self)
                 .map(RadonTypes::from)
                 .map_err(Into::into),
@@ -98,6 +132,20 @@ fn test_operate_identity() {
     assert_eq!(output, expected);
 }
 
+#[test]
+fn test_operate_hash() {
+    let input = RadonString::from("abc");
+    // 0x0E is RadonHashFunctions::SHA3_256
+    let call = (RadonOpCodes::Hash, Some(vec![rmpv::Value::from(0x0E)]));
+    let expected =
+        RadonString::from("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")
+            .into();
+
+    let output = input.operate(&call).unwrap();
+
+    assert_eq!(output, expected);
+}
+
 #[test]
 fn test_operate_parsejson() {
     let valid_string = RadonString::from(r#"{ "Hello": "world" }"#);
@@ -128,6 +176,32 @@ fn test_operate_parsejson() {
     });
 }
 
+#[test]
+fn test_operate_string_operators() {
+    let input = RadonString::from("  Temperature: 21.5 C ");
+    let script = [
+        (RadonOpCodes::StringTrim, None),
+        (RadonOpCodes::StringToUpperCase, None),
+        (
+            RadonOpCodes::StringReplace,
+            Some(vec![Value::from(r"^\D*([0-9.]+).*$"), Value::from("$1")]),
+        ),
+    ];
+    let output = script
+        .iter()
+        .try_fold(RadonTypes::from(input), |input, call| {
+            crate::operators::operate(input, call)
+        })
+        .unwrap();
+
+    assert_eq!(output, RadonString::from("21.5").into());
+
+    let call = (RadonOpCodes::StringLength, None);
+    let output = RadonString::from("21.5").operate(&call).unwrap();
+
+    assert_eq!(output, crate::types::integer::RadonInteger::from(4).into());
+}
+
 #[test]
 fn test_operate_unimplemented() {
     let input = RadonString::from("Hello world!");
@@ -0,0 +1,516 @@
+//! Static type checking of RADON scripts.
+//!
+//! The types of the values flowing between the calls of a script are inferred without running
+//! it, so that scripts calling operators that are not supported for their input type can be
+//! rejected before anyone spends resources on them.
+
+use std::fmt;
+
+use num_traits::FromPrimitive;
+use rmpv::Value;
+
+use crate::error::RadError;
+use crate::filters::RadonFilters;
+use crate::operators::RadonOpCodes;
+use crate::reducers::RadonReducers;
+use crate::script::{unpack_subscript, RadonCall};
+use crate::types::{
+    array::RADON_ARRAY_TYPE_NAME, boolean::RADON_BOOLEAN_TYPE_NAME, bytes::RADON_BYTES_TYPE_NAME,
+    float::RADON_FLOAT_TYPE_NAME, integer::RADON_INTEGER_TYPE_NAME, map::RADON_MAP_TYPE_NAME,
+    mixed::RADON_MIXED_TYPE_NAME, string::RADON_STRING_TYPE_NAME,
+};
+
+/// Type of a RADON value, as far as it can be known before running a script.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RadonStaticType {
+    /// The type depends on the data being processed
+    Any,
+    /// Array whose items are of the given type
+    Array(Box<RadonStaticType>),
+    Boolean,
+    Bytes,
+    Float,
+    Integer,
+    Map,
+    Mixed,
+    String,
+}
+
+impl RadonStaticType {
+    /// Array whose items are of the given type.
+    pub fn array_of(item: RadonStaticType) -> Self {
+        RadonStaticType::Array(Box::new(item))
+    }
+
+    /// The type that the given types have in common, which is `Any` if they differ.
+    pub fn common(types: &[RadonStaticType]) -> Self {
+        match types.split_first() {
+            Some((first, rest)) if rest.iter().all(|other| other == first) => first.clone(),
+            _ => RadonStaticType::Any,
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            RadonStaticType::Any | RadonStaticType::Float | RadonStaticType::Integer
+        )
+    }
+
+    /// Every type a value of type `Any` may turn out to have.
+    fn candidates() -> Vec<RadonStaticType> {
+        vec![
+            RadonStaticType::array_of(RadonStaticType::Any),
+            RadonStaticType::Boolean,
+            RadonStaticType::Bytes,
+            RadonStaticType::Float,
+            RadonStaticType::Integer,
+            RadonStaticType::Map,
+            RadonStaticType::Mixed,
+            RadonStaticType::String,
+        ]
+    }
+}
+
+impl fmt::Display for RadonStaticType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadonStaticType::Any => write!(f, "Any"),
+            RadonStaticType::Array(item) => write!(f, "{}<{}>", RADON_ARRAY_TYPE_NAME, item),
+            RadonStaticType::Boolean => write!(f, "{}", RADON_BOOLEAN_TYPE_NAME),
+            RadonStaticType::Bytes => write!(f, "{}", RADON_BYTES_TYPE_NAME),
+            RadonStaticType::Float => write!(f, "{}", RADON_FLOAT_TYPE_NAME),
+            RadonStaticType::Integer => write!(f, "{}", RADON_INTEGER_TYPE_NAME),
+            RadonStaticType::Map => write!(f, "{}", RADON_MAP_TYPE_NAME),
+            RadonStaticType::Mixed => write!(f, "{}", RADON_MIXED_TYPE_NAME),
+            RadonStaticType::String => write!(f, "{}", RADON_STRING_TYPE_NAME),
+        }
+    }
+}
+
+/// Infer the type of the output of a script given the type of its input, failing if any of its
+/// calls is not supported for the type of its input.
+pub fn check_script(
+    input: RadonStaticType,
+    script: &[RadonCall],
+) -> Result<RadonStaticType, RadError> {
+    script
+        .iter()
+        .try_fold(input, |input, call| check_call(&input, call))
+}
+
+/// Infer the type of the output of a call given the type of its input.
+pub fn check_call(input: &RadonStaticType, call: &RadonCall) -> Result<RadonStaticType, RadError> {
+    if let RadonStaticType::Any = input {
+        let outputs: Vec<RadonStaticType> = RadonStaticType::candidates()
+            .iter()
+            .filter_map(|candidate| check_call(candidate, call).ok())
+            .collect();
+
+        return if outputs.is_empty() {
+            Err(unsupported(input, call))
+        } else {
+            Ok(RadonStaticType::common(&outputs))
+        };
+    }
+
+    let output = match (input, call) {
+        (input, (RadonOpCodes::Identity, None)) => input.clone(),
+        // Array
+        (RadonStaticType::Array(_), (RadonOpCodes::ArrayCount, None)) => RadonStaticType::Integer,
+        (RadonStaticType::Array(item), (RadonOpCodes::ArrayEvery, Some(args)))
+        | (RadonStaticType::Array(item), (RadonOpCodes::ArraySome, Some(args))) => {
+            check_predicate(call.0, item, args)?;
+            RadonStaticType::Boolean
+        }
+        (RadonStaticType::Array(item), (RadonOpCodes::ArrayFilter, Some(args))) => {
+            check_filter(item, args)?;
+            input.clone()
+        }
+        (RadonStaticType::Array(item), (RadonOpCodes::ArrayFlatten, args)) => {
+            let depth = match args.as_ref().and_then(|args| args.first()) {
+                Some(depth) => depth.as_u64().ok_or_else(|| wrong_arguments(input, call))?,
+                None => 1,
+            };
+            RadonStaticType::array_of(flattened(item, depth))
+        }
+        (RadonStaticType::Array(item), (RadonOpCodes::ArrayMap, Some(args))) => {
+            RadonStaticType::array_of(check_script(
+                (**item).clone(),
+                &subscript(input, call, args)?,
+            )?)
+        }
+        (RadonStaticType::Array(item), (RadonOpCodes::Reduce, Some(args))) => {
+            check_reducer(item, args)?
+        }
+        (RadonStaticType::Array(item), (RadonOpCodes::ArraySort, Some(args))) => {
+            check_script((**item).clone(), &subscript(input, call, args)?)?;
+            input.clone()
+        }
+        (RadonStaticType::Array(_), (RadonOpCodes::ArrayTake, _))
+        | (RadonStaticType::Array(_), (RadonOpCodes::ArraySkip, Some(_))) => input.clone(),
+        (RadonStaticType::Array(item), (RadonOpCodes::Get, Some(_))) => (**item).clone(),
+        // Boolean
+        (RadonStaticType::Boolean, (RadonOpCodes::BooleanMatch, Some(_))) => RadonStaticType::Any,
+        (RadonStaticType::Boolean, (RadonOpCodes::BooleanNegate, None)) => RadonStaticType::Boolean,
+        (RadonStaticType::Boolean, (RadonOpCodes::BooleanToString, None)) => {
+            RadonStaticType::String
+        }
+        // Bytes
+        (RadonStaticType::Bytes, (RadonOpCodes::Hash, Some(_))) => RadonStaticType::Bytes,
+        // Float
+        (RadonStaticType::Float, (RadonOpCodes::FloatAbsolute, None))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatNegate, None))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatReciprocal, None)) => RadonStaticType::Float,
+        (RadonStaticType::Float, (RadonOpCodes::FloatCeiling, None))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatFloor, None))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatRound, None))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatTruncate, None)) => RadonStaticType::Integer,
+        (RadonStaticType::Float, (RadonOpCodes::FloatModulo, Some(_)))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatMultiply, Some(_)))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatPower, Some(_)))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatSum, Some(_))) => RadonStaticType::Float,
+        (RadonStaticType::Float, (RadonOpCodes::FloatGreaterThan, Some(_)))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatLessThan, Some(_)))
+        | (RadonStaticType::Float, (RadonOpCodes::FloatEquals, Some(_))) => {
+            RadonStaticType::Boolean
+        }
+        (RadonStaticType::Float, (RadonOpCodes::FloatToString, _)) => RadonStaticType::String,
+        // Integer
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerAbsolute, None))
+        | (RadonStaticType::Integer, (RadonOpCodes::IntegerNegate, None)) => {
+            RadonStaticType::Integer
+        }
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerMatch, Some(_))) => RadonStaticType::Any,
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerModulo, Some(_)))
+        | (RadonStaticType::Integer, (RadonOpCodes::IntegerMultiply, Some(_)))
+        | (RadonStaticType::Integer, (RadonOpCodes::IntegerSum, Some(_))) => {
+            RadonStaticType::Integer
+        }
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerPower, Some(_))) => RadonStaticType::Float,
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerReciprocal, None))
+        | (RadonStaticType::Integer, (RadonOpCodes::IntegerToFloat, None)) => {
+            RadonStaticType::Float
+        }
+        (RadonStaticType::Integer, (RadonOpCodes::IntegerToString, _)) => RadonStaticType::String,
+        // Map
+        (RadonStaticType::Map, (RadonOpCodes::Get, Some(_)))
+        | (RadonStaticType::Map, (RadonOpCodes::MapGetPath, Some(_))) => RadonStaticType::Mixed,
+        (RadonStaticType::Map, (RadonOpCodes::MapEntries, None)) => {
+            RadonStaticType::array_of(RadonStaticType::array_of(RadonStaticType::Any))
+        }
+        (RadonStaticType::Map, (RadonOpCodes::MapHasKey, Some(_))) => RadonStaticType::Boolean,
+        (RadonStaticType::Map, (RadonOpCodes::MapKeys, None)) => {
+            RadonStaticType::array_of(RadonStaticType::String)
+        }
+        (RadonStaticType::Map, (RadonOpCodes::MapValues, None)) => {
+            RadonStaticType::array_of(RadonStaticType::Mixed)
+        }
+        // Mixed
+        (RadonStaticType::Mixed, (RadonOpCodes::ToArray, None)) => {
+            RadonStaticType::array_of(RadonStaticType::Any)
+        }
+        (RadonStaticType::Mixed, (RadonOpCodes::ToBoolean, None)) => RadonStaticType::Boolean,
+        (RadonStaticType::Mixed, (RadonOpCodes::ToBytes, None)) => RadonStaticType::Bytes,
+        (RadonStaticType::Mixed, (RadonOpCodes::ToFloat, None)) => RadonStaticType::Float,
+        (RadonStaticType::Mixed, (RadonOpCodes::ToInteger, None)) => RadonStaticType::Integer,
+        (RadonStaticType::Mixed, (RadonOpCodes::ToMap, None)) => RadonStaticType::Map,
+        // String
+        (RadonStaticType::String, (RadonOpCodes::Hash, Some(_))) => RadonStaticType::String,
+        (RadonStaticType::String, (RadonOpCodes::ParseJson, None))
+        | (RadonStaticType::String, (RadonOpCodes::ParseXml, None))
+        | (RadonStaticType::String, (RadonOpCodes::ParseCsv, None)) => RadonStaticType::Mixed,
+        (RadonStaticType::String, (RadonOpCodes::StringCaptures, Some(_)))
+        | (RadonStaticType::String, (RadonOpCodes::StringSplit, Some(_))) => {
+            RadonStaticType::array_of(RadonStaticType::String)
+        }
+        (RadonStaticType::String, (RadonOpCodes::StringLength, None)) => RadonStaticType::Integer,
+        (RadonStaticType::String, (RadonOpCodes::StringMatch, Some(_))) => RadonStaticType::Boolean,
+        (RadonStaticType::String, (RadonOpCodes::StringReplace, Some(_)))
+        | (RadonStaticType::String, (RadonOpCodes::StringSlice, Some(_)))
+        | (RadonStaticType::String, (RadonOpCodes::StringToLowerCase, None))
+        | (RadonStaticType::String, (RadonOpCodes::StringToUpperCase, None))
+        | (RadonStaticType::String, (RadonOpCodes::StringTrim, None)) => RadonStaticType::String,
+        (RadonStaticType::String, (RadonOpCodes::ToFloat, None)) => RadonStaticType::Float,
+        (input, call) => return Err(unsupported(input, call)),
+    };
+
+    Ok(output)
+}
+
+/// Check that the subscript given as the first argument returns booleans for the items.
+fn check_predicate(
+    operator: RadonOpCodes,
+    item: &RadonStaticType,
+    args: &[Value],
+) -> Result<(), RadError> {
+    let input = RadonStaticType::array_of(item.clone());
+    let subscript = subscript(&input, &(operator, Some(args.to_vec())), args)?;
+
+    match check_script(item.clone(), &subscript)? {
+        RadonStaticType::Any | RadonStaticType::Boolean => Ok(()),
+        other => Err(RadError::WrongSubscriptOutput {
+            operator: operator.to_string(),
+            expected_type: RADON_BOOLEAN_TYPE_NAME.to_string(),
+            value: other.to_string(),
+        }),
+    }
+}
+
+/// Check the arguments of `ArrayFilter`, which are either a predicate or a predefined filter.
+fn check_filter(item: &RadonStaticType, args: &[Value]) -> Result<(), RadError> {
+    let filter_integer = match args.first().and_then(Value::as_i64) {
+        Some(filter_integer) => filter_integer,
+        None => return check_predicate(RadonOpCodes::ArrayFilter, item, args),
+    };
+
+    let input = RadonStaticType::array_of(item.clone());
+    let call = (RadonOpCodes::ArrayFilter, Some(args.to_vec()));
+    match RadonFilters::from_i64(filter_integer) {
+        Some(RadonFilters::Mode) => Ok(()),
+        Some(_) if item.is_numeric() => Ok(()),
+        Some(_) => Err(unsupported(&input, &call)),
+        None => Err(wrong_arguments(&input, &call)),
+    }
+}
+
+/// Infer the type of the output of the reducer given as the first argument.
+fn check_reducer(item: &RadonStaticType, args: &[Value]) -> Result<RadonStaticType, RadError> {
+    let input = RadonStaticType::array_of(item.clone());
+    let call = (RadonOpCodes::Reduce, Some(args.to_vec()));
+    let reducer_code = args
+        .first()
+        .and_then(Value::as_i64)
+        .and_then(RadonReducers::from_i64)
+        .ok_or_else(|| wrong_arguments(&input, &call))?;
+
+    let unsupported_reducer = || RadError::UnsupportedReducer {
+        inner_type: item.to_string(),
+        reducer: reducer_code.to_string(),
+    };
+
+    match reducer_code {
+        RadonReducers::Mode => match item {
+            RadonStaticType::String => Ok(RadonStaticType::String),
+            item if item.is_numeric() => Ok(item.clone()),
+            _ => Err(unsupported_reducer()),
+        },
+        _ if item.is_numeric() => Ok(RadonStaticType::Float),
+        _ => Err(unsupported_reducer()),
+    }
+}
+
+/// Type of the items of an array of items of the given type once flattened up to some depth.
+fn flattened(item: &RadonStaticType, depth: u64) -> RadonStaticType {
+    match item {
+        RadonStaticType::Array(inner) if depth > 0 => flattened(inner, depth - 1),
+        RadonStaticType::Any if depth > 0 => RadonStaticType::Any,
+        item => item.clone(),
+    }
+}
+
+/// Unpack the subscript passed as the first argument of a call.
+fn subscript(
+    input: &RadonStaticType,
+    call: &RadonCall,
+    args: &[Value],
+) -> Result<Vec<RadonCall>, RadError> {
+    args.first()
+        .ok_or_else(|| wrong_arguments(input, call))
+        .and_then(unpack_subscript)
+}
+
+fn unsupported(input: &RadonStaticType, (op_code, args): &RadonCall) -> RadError {
+    RadError::UnsupportedOperator {
+        input_type: input.to_string(),
+        operator: op_code.to_string(),
+        args: args.to_owned(),
+    }
+}
+
+fn wrong_arguments(input: &RadonStaticType, (op_code, args): &RadonCall) -> RadError {
+    RadError::WrongArguments {
+        input_type: input.to_string(),
+        operator: op_code.to_string(),
+        args: args.to_owned().unwrap_or_default(),
+    }
+}
+
+#[test]
+fn test_check_script() {
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("main")])),
+        (RadonOpCodes::ToMap, None),
+        (RadonOpCodes::Get, Some(vec![Value::from("temp")])),
+        (RadonOpCodes::ToFloat, None),
+    ];
+
+    let output = check_script(RadonStaticType::String, &script).unwrap();
+
+    assert_eq!(output, RadonStaticType::Float);
+}
+
+#[test]
+fn test_check_script_unsupported_operator() {
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToFloat, None),
+        (RadonOpCodes::ParseJson, None),
+    ];
+
+    let error = check_script(RadonStaticType::String, &script).unwrap_err();
+
+    assert_eq!(
+        error,
+        RadError::UnsupportedOperator {
+            input_type: "RadonFloat".to_string(),
+            operator: "ParseJson".to_string(),
+            args: None,
+        }
+    );
+}
+
+#[test]
+fn test_check_script_strings() {
+    let script = vec![
+        (RadonOpCodes::StringTrim, None),
+        (RadonOpCodes::StringSplit, Some(vec![Value::from(",")])),
+        (RadonOpCodes::Get, Some(vec![Value::from(1)])),
+        (RadonOpCodes::StringLength, None),
+    ];
+
+    let output = check_script(RadonStaticType::String, &script).unwrap();
+
+    assert_eq!(output, RadonStaticType::Integer);
+
+    let script = vec![
+        (RadonOpCodes::ParseCsv, None),
+        (RadonOpCodes::ToArray, None),
+    ];
+
+    let output = check_script(RadonStaticType::String, &script).unwrap();
+
+    assert_eq!(output, RadonStaticType::array_of(RadonStaticType::Any));
+}
+
+#[test]
+fn test_check_script_maps() {
+    let script = vec![
+        (RadonOpCodes::ParseJson, None),
+        (RadonOpCodes::ToMap, None),
+        (
+            RadonOpCodes::MapGetPath,
+            Some(vec![Value::from("data.quotes[0].USD.price")]),
+        ),
+        (RadonOpCodes::ToFloat, None),
+    ];
+
+    let output = check_script(RadonStaticType::String, &script).unwrap();
+
+    assert_eq!(output, RadonStaticType::Float);
+
+    let script = vec![
+        (RadonOpCodes::MapKeys, None),
+        (RadonOpCodes::ArrayCount, None),
+    ];
+
+    let output = check_script(RadonStaticType::Map, &script).unwrap();
+
+    assert_eq!(output, RadonStaticType::Integer);
+}
+
+#[test]
+fn test_check_script_arrays() {
+    // [ [ ArrayMap, [ [ Get, "price" ], ToFloat ] ], [ ArrayFilter, DeviationStandard, 1.5 ],
+    //   [ Reduce, AverageMedian ] ]
+    let script = vec![
+        (
+            RadonOpCodes::ArrayMap,
+            Some(vec![Value::from(vec![
+                Value::from(vec![Value::from(0x01), Value::from("price")]),
+                Value::from(0x82),
+            ])]),
+        ),
+        (
+            RadonOpCodes::ArrayFilter,
+            Some(vec![Value::from(0x30), Value::from(1.5)]),
+        ),
+        (RadonOpCodes::Reduce, Some(vec![Value::from(0x22)])),
+    ];
+
+    let input = RadonStaticType::array_of(RadonStaticType::Map);
+    assert_eq!(
+        check_script(input, &script[..1]).unwrap(),
+        RadonStaticType::array_of(RadonStaticType::Float)
+    );
+
+    let input = RadonStaticType::array_of(RadonStaticType::Map);
+    assert_eq!(
+        check_script(input, &script).unwrap(),
+        RadonStaticType::Float
+    );
+
+    // Reducing a map is not possible
+    let input = RadonStaticType::Map;
+    assert!(check_script(input, &script[2..]).is_err());
+
+    // Neither is averaging strings
+    let input = RadonStaticType::array_of(RadonStaticType::String);
+    assert_eq!(
+        check_script(input, &script[2..]).unwrap_err(),
+        RadError::UnsupportedReducer {
+            inner_type: "RadonString".to_string(),
+            reducer: "RadonReducers::AverageMedian".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_check_script_any() {
+    // Items of arrays coming from JSON may be of any type, so only operators that are not
+    // supported by any type are rejected
+    let input = RadonStaticType::array_of(RadonStaticType::Any);
+    let script = vec![
+        (RadonOpCodes::Get, Some(vec![Value::from(0)])),
+        (RadonOpCodes::ToMap, None),
+    ];
+    assert_eq!(check_script(input, &script).unwrap(), RadonStaticType::Map);
+
+    let input = RadonStaticType::array_of(RadonStaticType::Any);
+    let script = vec![
+        (RadonOpCodes::Get, Some(vec![Value::from(0)])),
+        (RadonOpCodes::Identity, None),
+    ];
+    assert_eq!(check_script(input, &script).unwrap(), RadonStaticType::Any);
+
+    let input = RadonStaticType::array_of(RadonStaticType::Any);
+    let script = vec![
+        (RadonOpCodes::Get, Some(vec![Value::from(0)])),
+        (RadonOpCodes::ParseJson, Some(vec![Value::from(0)])),
+    ];
+    assert!(check_script(input, &script).is_err());
+}
+
+#[test]
+fn test_check_script_wrong_subscript_output() {
+    // [ [ ArrayFilter, [ [ FloatSum, 1 ] ] ] ]
+    let script = vec![(
+        RadonOpCodes::ArrayFilter,
+        Some(vec![Value::from(vec![Value::from(vec![
+            Value::from(0x39),
+            Value::from(1),
+        ])])]),
+    )];
+
+    let input = RadonStaticType::array_of(RadonStaticType::Float);
+    assert_eq!(
+        check_script(input, &script).unwrap_err(),
+        RadError::WrongSubscriptOutput {
+            operator: "ArrayFilter".to_string(),
+            expected_type: "RadonBoolean".to_string(),
+            value: "RadonFloat".to_string(),
+        }
+    );
+}
@@ -34,7 +34,7 @@ fn test_radon_types_display() {
     let radon_array = RadonTypes::try_from(Value::from(vec![Value::from(123)])).unwrap();
     let radon_array_type_display = radon_array.to_string();
     let radon_array_expected =
-        "RadonTypes::RadonArray([Mixed(RadonMixed { value: Integer(PosInt(123)) })])".to_string();
+        "RadonTypes::RadonArray([Integer(RadonInteger { value: 123 })])".to_string();
     assert_eq!(radon_array_type_display, radon_array_expected);
 
     let radon_float = RadonTypes::try_from(Value::from(std::f64::consts::PI)).unwrap();
@@ -7,6 +7,7 @@ use terminal_size as term;
 use witnet_config as config;
 
 mod node;
+mod rad;
 mod wallet;
 
 pub fn from_args() -> Cli {
@@ -56,6 +57,7 @@ pub fn exec(command: Cli) -> Result<(), failure::Error> {
 fn exec_cmd(command: Command, config: config::config::Config) -> Result<(), failure::Error> {
     match command {
         Command::Node(cmd) => node::exec_cmd(cmd, config),
+        Command::Rad(cmd) => rad::exec_cmd(cmd, config),
         Command::Wallet(cmd) => wallet::exec_cmd(cmd, config),
     }
 }
@@ -113,6 +115,8 @@ pub struct Cli {
 enum Command {
     #[structopt(name = "node", about = "Witnet full node.")]
     Node(node::Command),
+    #[structopt(name = "rad", about = "RADON scripts tooling.")]
+    Rad(rad::Command),
     #[structopt(name = "wallet", about = "Witnet wallet.")]
     Wallet(wallet::Command),
 }
@@ -0,0 +1,62 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use structopt::StructOpt;
+
+use witnet_config::config::Config;
+use witnet_rad::assembly;
+
+pub fn exec_cmd(command: Command, _config: Config) -> Result<(), failure::Error> {
+    match command {
+        Command::Assemble { file } => {
+            let packed = assembly::assemble(&read_input(file)?)?;
+            println!("{}", serde_json::to_string(&packed)?);
+
+            Ok(())
+        }
+        Command::Disassemble { file } => {
+            let packed: Vec<u8> = serde_json::from_str(&read_input(file)?)?;
+            println!("{}", assembly::disassemble(&packed)?);
+
+            Ok(())
+        }
+    }
+}
+
+/// Read the whole file, or stdin if no file is given.
+fn read_input(file: Option<PathBuf>) -> Result<String, failure::Error> {
+    match file {
+        Some(path) => Ok(fs::read_to_string(path)?),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+
+            Ok(input)
+        }
+    }
+}
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    #[structopt(
+        name = "assemble",
+        about = "Compile a RADON script from its textual notation into a JSON array of bytes."
+    )]
+    Assemble {
+        /// Path to the file containing the script. If not specified will read it from stdin.
+        #[structopt(short = "f", long = "file")]
+        file: Option<PathBuf>,
+    },
+    #[structopt(
+        name = "disassemble",
+        about = "Write a RADON script given as a JSON array of bytes in its textual notation."
+    )]
+    Disassemble {
+        /// Path to the file containing the script. If not specified will read it from stdin.
+        #[structopt(short = "f", long = "file")]
+        file: Option<PathBuf>,
+    },
+}
@@ -1,8 +1,10 @@
-use std::net::SocketAddr;
+use std::{fs::File, io, net::SocketAddr, path::PathBuf};
 
 use structopt::StructOpt;
 
 use witnet_config::{config::Config, loaders};
+use witnet_data_structures::chain::RADRequest;
+use witnet_rad as rad;
 use witnet_wallet as wallet;
 
 pub fn exec_cmd(command: Command, mut config: Config) -> Result<(), failure::Error> {
@@ -17,6 +19,21 @@ pub fn exec_cmd(command: Command, mut config: Config) -> Result<(), failure::Err
             let _result = wallet::run(config);
             Ok(())
         }
+        Command::RunRadRequest { file, trace } => {
+            let request: RADRequest = match file {
+                Some(path) => serde_json::from_reader(File::open(path)?)?,
+                None => serde_json::from_reader(io::stdin())?,
+            };
+
+            let context = wallet::retrieval_context(rad::RetrievalLimits::from(&config.rad));
+            let (result, request_trace) = rad::run_request_with_trace(&request, &context);
+            if trace {
+                println!("{}", serde_json::to_string_pretty(&request_trace)?);
+            }
+            println!("{}", result?);
+
+            Ok(())
+        }
         Command::ShowConfig => {
             println!(
                 "[wallet]\n{}",
@@ -35,6 +52,19 @@ pub enum Command {
         alias = "run"
     )]
     Run(ConfigParams),
+    #[structopt(
+        name = "run-rad-request",
+        about = "Run a RAD request locally, as the `runRadRequest` method of the wallet does."
+    )]
+    RunRadRequest {
+        /// Path to a JSON file containing the RAD request. If not specified will read it from stdin.
+        #[structopt(short = "f", long = "file")]
+        file: Option<PathBuf>,
+        /// Print the input, output or error and time taken of every call of the scripts, including
+        /// the calls of the subscripts of higher-order operators.
+        #[structopt(long = "trace")]
+        trace: bool,
+    },
     #[structopt(
         name = "show-config",
         about = "Dump the loaded config in Toml format to stdout."
@@ -1,6 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
-    convert::{TryFrom, TryInto},
+    convert::TryInto,
 };
 
 use witnet_crypto::{
@@ -12,8 +12,8 @@ use witnet_data_structures::{
     chain::{
         Block, BlockMerkleRoots, CheckpointBeacon, DataRequestOutput, DataRequestStage,
         DataRequestState, Epoch, Hash, Hashable, Input, KeyedSignature, OutputPointer,
-        PublicKeyHash, RADConsensus, RADRequest, Reputation, ReputationEngine, UnspentOutputsPool,
-        ValueTransferOutput,
+        PublicKeyHash, RADConsensus, RADHeader, RADRequest, RADRetrieve, RADType, Reputation,
+        ReputationEngine, UnspentOutputsPool, ValueTransferOutput,
     },
     data_request::{calculate_dr_vt_reward, true_revealer, DataRequestPool},
     error::{BlockError, DataRequestError, TransactionError},
@@ -23,7 +23,11 @@ use witnet_data_structures::{
     },
     vrf::{BlockEligibilityClaim, DataRequestEligibilityClaim, VrfCtx},
 };
-use witnet_rad::{run_consensus, script::unpack_radon_script, types::RadonTypes};
+use witnet_rad::{
+    run_consensus_on_reveals,
+    script::unpack_radon_script,
+    typing::{check_script, RadonStaticType},
+};
 
 /// Calculate the sum of the values of the outputs pointed by the
 /// inputs of a transaction. If an input pointed-output is not
@@ -120,37 +124,102 @@ pub fn validate_mint_transaction(
     Ok(())
 }
 
-/// Function to validate a rad request
+/// Function to validate a rad request, checking that its sources are well formed, that its
+/// scripts can be unpacked and that every one of their calls is supported for the type of its input
 pub fn validate_rad_request(rad_request: &RADRequest) -> Result<(), failure::Error> {
     let retrieval_paths = &rad_request.retrieve;
+    let mut retrieval_types = Vec::with_capacity(retrieval_paths.len());
     for path in retrieval_paths {
-        unpack_radon_script(path.script.as_slice())?;
+        let input_type = validate_rad_retrieve(path)?;
+        let script = unpack_radon_script(path.script.as_slice())?;
+        retrieval_types.push(check_script(input_type, &script)?);
     }
 
+    // The aggregate script runs on the results of all the retrievals
     let aggregate = &rad_request.aggregate;
-    unpack_radon_script(aggregate.script.as_slice())?;
+    let aggregate_script = unpack_radon_script(aggregate.script.as_slice())?;
+    let aggregate_type = check_script(
+        RadonStaticType::array_of(RadonStaticType::common(&retrieval_types)),
+        &aggregate_script,
+    )?;
 
+    // The consensus script runs on the aggregation results revealed by the witnesses
     let consensus = &rad_request.consensus;
-    unpack_radon_script(consensus.script.as_slice())?;
+    let consensus_script = unpack_radon_script(consensus.script.as_slice())?;
+    check_script(RadonStaticType::array_of(aggregate_type), &consensus_script)?;
 
     Ok(())
 }
 
-/// Function to validate a tally consensus
+/// Function to validate the fields of a source that are relevant to its kind, returning the type
+/// of the data that it retrieves
+pub fn validate_rad_retrieve(retrieve: &RADRetrieve) -> Result<RadonStaticType, failure::Error> {
+    if retrieve.kind != RADType::HttpPost
+        && (!retrieve.body.is_empty() || !retrieve.headers.is_empty())
+    {
+        Err(DataRequestError::UnexpectedRequestPayload {
+            kind: retrieve.kind.clone(),
+        })?
+    }
+
+    match retrieve.kind {
+        RADType::HttpGet => Ok(RadonStaticType::String),
+        RADType::HttpPost => {
+            for header in &retrieve.headers {
+                if !is_valid_header(header) {
+                    Err(DataRequestError::InvalidHeader {
+                        name: header.name.clone(),
+                    })?
+                }
+            }
+
+            Ok(RadonStaticType::String)
+        }
+        RADType::Rng => {
+            if !retrieve.url.is_empty() {
+                Err(DataRequestError::UnexpectedUrl {
+                    kind: retrieve.kind.clone(),
+                })?
+            }
+
+            Ok(RadonStaticType::Bytes)
+        }
+        RADType::Tally => {
+            // The hash must be written in full, as `Hash::from_str` skips non hex characters
+            let is_hash =
+                retrieve.url.len() == 64 && retrieve.url.chars().all(|c| c.is_ascii_hexdigit());
+            if !is_hash {
+                Err(DataRequestError::InvalidTallySource {
+                    url: retrieve.url.clone(),
+                })?
+            }
+
+            // Tallies can be of any type
+            Ok(RadonStaticType::Any)
+        }
+    }
+}
+
+/// Whether the name of a header is an HTTP token and its value has no control characters
+fn is_valid_header(header: &RADHeader) -> bool {
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c);
+    let is_value_char = |c: char| c == '\t' || !c.is_ascii_control();
+
+    !header.name.is_empty()
+        && header.name.chars().all(is_token_char)
+        && header.value.chars().all(is_value_char)
+}
+
+/// Function to validate a tally consensus, returning for each reveal whether it is honest
 pub fn validate_consensus(
     reveals: &[&[u8]],
     miner_tally: &[u8],
     consensus: &RADConsensus,
-) -> Result<(), failure::Error> {
-    let radon_types_vec: Vec<RadonTypes> = reveals
-        .iter()
-        .filter_map(|&input| RadonTypes::try_from(input).ok())
-        .collect();
-
-    let local_tally = run_consensus(radon_types_vec, consensus)?;
+) -> Result<Vec<bool>, failure::Error> {
+    let (local_tally, honest) = run_consensus_on_reveals(reveals, consensus)?;
 
     if local_tally == miner_tally {
-        Ok(())
+        Ok(honest)
     } else {
         Err(TransactionError::MismatchedConsensus {
             local_tally,
@@ -382,12 +451,12 @@ pub fn validate_tally_transaction<'a>(
     let dr_output = &dr_state.data_request;
 
     // The unwrap is safe because we know that the data request exists
-    let reveals: Vec<&[u8]> = dr_pool
+    let (revealers, reveals): (Vec<PublicKeyHash>, Vec<&[u8]>) = dr_pool
         .get_reveals(&dr_pointer)
         .unwrap()
         .into_iter()
-        .map(|reveal| reveal.body.reveal.as_slice())
-        .collect();
+        .map(|reveal| (reveal.body.pkh, reveal.body.reveal.as_slice()))
+        .unzip();
 
     //TODO: Check Tally convergence
 
@@ -395,29 +464,46 @@ pub fn validate_tally_transaction<'a>(
     let miner_tally = ta_tx.tally.clone();
     let tally_stage = &dr_output.data_request.consensus;
 
-    validate_consensus(&reveals, &miner_tally, tally_stage)?;
-    validate_tally_outputs(&dr_state, &ta_tx, reveals.len())?;
+    let honest = validate_consensus(&reveals, &miner_tally, tally_stage)?;
+
+    // Validate the liars flagged by the miner
+    let mut local_liars: Vec<PublicKeyHash> = revealers
+        .into_iter()
+        .zip(honest)
+        .filter_map(|(pkh, honest)| if honest { None } else { Some(pkh) })
+        .collect();
+    local_liars.sort();
+    if local_liars != ta_tx.liars {
+        Err(TransactionError::MismatchedLiars {
+            local_liars,
+            miner_liars: ta_tx.liars.clone(),
+        })?
+    }
+
+    validate_tally_outputs(&dr_state, &ta_tx, reveals.len() - ta_tx.liars.len())?;
 
     Ok((ta_tx.outputs.iter().collect(), dr_output.tally_fee))
 }
 
+/// Function to validate the outputs of a tally transaction, which must reward each one of the
+/// `n_honest` honest revealers and return the rest of the reward to the data request creator
 pub fn validate_tally_outputs(
     dr_state: &DataRequestState,
     ta_tx: &TallyTransaction,
-    n_reveals: usize,
+    n_honest: usize,
 ) -> Result<(), failure::Error> {
     let witnesses = dr_state.data_request.witnesses as usize;
-    let change_required = witnesses > n_reveals;
+    let change_required = witnesses > n_honest;
 
-    if change_required && (ta_tx.outputs.len() != n_reveals + 1) {
+    if change_required && (ta_tx.outputs.len() != n_honest + 1) {
         Err(TransactionError::WrongNumberOutputs {
             outputs: ta_tx.outputs.len(),
-            expected_outputs: n_reveals + 1,
+            expected_outputs: n_honest + 1,
         })?
-    } else if !change_required && (ta_tx.outputs.len() != n_reveals) {
+    } else if !change_required && (ta_tx.outputs.len() != n_honest) {
         Err(TransactionError::WrongNumberOutputs {
             outputs: ta_tx.outputs.len(),
-            expected_outputs: n_reveals,
+            expected_outputs: n_honest,
         })?
     }
 
@@ -444,7 +530,7 @@ pub fn validate_tally_outputs(
 
             match reveal {
                 Some(r) => {
-                    if !true_revealer(&r, &ta_tx.tally) {
+                    if !true_revealer(r, ta_tx) {
                         Err(TransactionError::DishonestReward)?
                     }
                 }
@@ -838,6 +838,7 @@ fn example_data_request() -> RADRequest {
             kind: RADType::HttpGet,
             url: "".to_string(),
             script: vec![0x90],
+            ..RADRetrieve::default()
         }],
         aggregate: RADAggregate { script: vec![0x90] },
         consensus: RADConsensus { script: vec![0x90] },
@@ -880,6 +881,154 @@ fn data_request_empty_scripts() {
     assert_eq!(x.unwrap(), ());
 }
 
+#[test]
+fn data_request_wrong_script_types() {
+    // [ ParseJson, ToFloat, ParseJson ] applies ParseJson to a float
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            kind: RADType::HttpGet,
+            url: "".to_string(),
+            script: vec![0x93, 0x53, 0xcc, 0x82, 0x53],
+            ..RADRetrieve::default()
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<RadError>().unwrap(),
+        RadError::UnsupportedOperator {
+            input_type: "RadonFloat".to_string(),
+            operator: "ParseJson".to_string(),
+            args: None,
+        }
+    );
+
+    // [ ParseJson, ToMap ] retrieves a map, which cannot be averaged by [ [ Reduce, AverageMean ] ]
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            kind: RADType::HttpGet,
+            url: "".to_string(),
+            script: vec![0x92, 0x53, 0xcc, 0x84],
+            ..RADRetrieve::default()
+        }],
+        aggregate: RADAggregate {
+            script: vec![0x91, 0x92, 0x66, 0x20],
+        },
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<RadError>().unwrap(),
+        RadError::UnsupportedReducer {
+            inner_type: "RadonMap".to_string(),
+            reducer: "RadonReducers::AverageMean".to_string(),
+        }
+    );
+}
+
+#[test]
+fn data_request_sources() {
+    let post = RADRetrieve {
+        kind: RADType::HttpPost,
+        url: "https://example.com".to_string(),
+        script: vec![0x90],
+        body: b"{}".to_vec(),
+        headers: vec![RADHeader {
+            name: "Content-Type".to_string(),
+            value: "application/json".to_string(),
+        }],
+    };
+    let rng = RADRetrieve {
+        kind: RADType::Rng,
+        script: vec![0x90],
+        ..RADRetrieve::default()
+    };
+    let tally = RADRetrieve {
+        kind: RADType::Tally,
+        url: Hash::SHA256([1; 32]).to_string(),
+        script: vec![0x90],
+        ..RADRetrieve::default()
+    };
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![post.clone(), rng.clone(), tally.clone()],
+        ..example_data_request()
+    });
+    assert_eq!(x.unwrap(), ());
+
+    // Only HTTP-POST sources can have a body
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            kind: RADType::HttpGet,
+            ..post.clone()
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<DataRequestError>().unwrap(),
+        DataRequestError::UnexpectedRequestPayload {
+            kind: RADType::HttpGet,
+        }
+    );
+
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            headers: vec![RADHeader {
+                name: "Content Type".to_string(),
+                value: "application/json".to_string(),
+            }],
+            ..post
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<DataRequestError>().unwrap(),
+        DataRequestError::InvalidHeader {
+            name: "Content Type".to_string(),
+        }
+    );
+
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            url: "https://example.com".to_string(),
+            ..rng.clone()
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<DataRequestError>().unwrap(),
+        DataRequestError::UnexpectedUrl { kind: RADType::Rng }
+    );
+
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            url: "https://example.com".to_string(),
+            ..tally
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<DataRequestError>().unwrap(),
+        DataRequestError::InvalidTallySource {
+            url: "https://example.com".to_string(),
+        }
+    );
+
+    // RNG sources retrieve bytes, so [ ParseJson ] cannot be applied
+    let x = test_rad_request(RADRequest {
+        retrieve: vec![RADRetrieve {
+            script: vec![0x91, 0x53],
+            ..rng
+        }],
+        ..example_data_request()
+    });
+    assert_eq!(
+        x.unwrap_err().downcast::<RadError>().unwrap(),
+        RadError::UnsupportedOperator {
+            input_type: "RadonBytes".to_string(),
+            operator: "ParseJson".to_string(),
+            args: None,
+        }
+    );
+}
+
 #[test]
 fn data_request_witnesses_0() {
     // A data request with 0 witnesses is invalid
@@ -1321,7 +1470,7 @@ fn test_commit_with_dr(c_tx: &CommitTransaction) -> Result<(), failure::Error> {
     assert_eq!(dr_hash, DR_HASH.parse().unwrap());
     let dr_epoch = 0;
     dr_pool
-        .process_data_request(&dr_transaction, dr_epoch)
+        .process_data_request(&dr_transaction, dr_epoch, &Hash::default())
         .unwrap();
 
     validate_commit_transaction(&c_tx, &dr_pool, commit_beacon, vrf, &rep_eng).map(|_| ())
@@ -1359,7 +1508,7 @@ fn test_commit_difficult_proof() -> Result<(), failure::Error> {
     assert_eq!(dr_hash, DR_HASH.parse().unwrap());
     let dr_epoch = 0;
     dr_pool
-        .process_data_request(&dr_transaction, dr_epoch)
+        .process_data_request(&dr_transaction, dr_epoch, &Hash::default())
         .unwrap();
 
     // Insert valid proof
@@ -1395,7 +1544,7 @@ fn test_commit() -> Result<(), failure::Error> {
     assert_eq!(dr_hash, DR_HASH.parse().unwrap());
     let dr_epoch = 0;
     dr_pool
-        .process_data_request(&dr_transaction, dr_epoch)
+        .process_data_request(&dr_transaction, dr_epoch, &Hash::default())
         .unwrap();
 
     // Insert valid proof
@@ -1558,7 +1707,7 @@ fn commitment_invalid_proof() {
     let dr_transaction = DRTransaction::new(dr_body, vec![drs]);
     let dr_epoch = 0;
     dr_pool
-        .process_data_request(&dr_transaction, dr_epoch)
+        .process_data_request(&dr_transaction, dr_epoch, &Hash::default())
         .unwrap();
 
     // Sign commitment
@@ -1611,7 +1760,7 @@ fn commitment_dr_in_reveal_stage() {
     let dr_hash = dr_transaction.hash();
     let dr_epoch = 0;
     dr_pool
-        .process_data_request(&dr_transaction, dr_epoch)
+        .process_data_request(&dr_transaction, dr_epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
 
@@ -1663,7 +1812,7 @@ fn dr_pool_with_dr_in_reveal_stage() -> (DataRequestPool, Hash) {
     let c_tx = CommitTransaction::new(cb, vec![cs]);
 
     dr_pool
-        .process_data_request(&dr_transaction, epoch)
+        .process_data_request(&dr_transaction, epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
     dr_pool.process_commit(&c_tx, &block_hash).unwrap();
@@ -1897,7 +2046,7 @@ fn reveal_valid_commitment() {
 
     // Include DRTransaction in DataRequestPool
     dr_pool
-        .process_data_request(&dr_transaction, epoch)
+        .process_data_request(&dr_transaction, epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
 
@@ -1973,7 +2122,7 @@ fn dr_pool_with_dr_in_tally_stage(
 
     // Include DRTransaction in DataRequestPool
     dr_pool
-        .process_data_request(&dr_transaction, epoch)
+        .process_data_request(&dr_transaction, epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
 
@@ -2037,7 +2186,7 @@ fn dr_pool_with_dr_in_tally_stage_2_reveals(
 
     // Include DRTransaction in DataRequestPool
     dr_pool
-        .process_data_request(&dr_transaction, epoch)
+        .process_data_request(&dr_transaction, epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
 
@@ -2156,8 +2305,12 @@ fn tally_dr_not_tally_stage() {
         pkh: dr_pkh,
         value: 800,
     };
-    let tally_transaction =
-        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt_change]);
+    let tally_transaction = TallyTransaction::new(
+        dr_pointer,
+        tally_value.clone(),
+        vec![vt0, vt_change],
+        vec![],
+    );
 
     let mut dr_pool = DataRequestPool::default();
     let x = validate_tally_transaction(&tally_transaction, &dr_pool);
@@ -2166,7 +2319,7 @@ fn tally_dr_not_tally_stage() {
         TransactionError::DataRequestNotFound { hash: dr_pointer },
     );
     dr_pool
-        .process_data_request(&dr_transaction, epoch)
+        .process_data_request(&dr_transaction, epoch, &Hash::default())
         .unwrap();
     dr_pool.update_data_request_stages();
     let x = validate_tally_transaction(&tally_transaction, &dr_pool);
@@ -2210,8 +2363,12 @@ fn tally_invalid_consensus() {
         value: 800,
     };
 
-    let tally_transaction =
-        TallyTransaction::new(dr_pointer, fake_tally_value.clone(), vec![vt0, vt_change]);
+    let tally_transaction = TallyTransaction::new(
+        dr_pointer,
+        fake_tally_value.clone(),
+        vec![vt0, vt_change],
+        vec![],
+    );
     let x = validate_tally_transaction(&tally_transaction, &dr_pool);
     assert_eq!(
         x.unwrap_err().downcast::<TransactionError>().unwrap(),
@@ -2235,8 +2392,12 @@ fn tally_valid() {
         pkh: dr_pkh,
         value: 800,
     };
-    let tally_transaction =
-        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt_change]);
+    let tally_transaction = TallyTransaction::new(
+        dr_pointer,
+        tally_value.clone(),
+        vec![vt0, vt_change],
+        vec![],
+    );
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(x.unwrap(), ());
 }
@@ -2261,6 +2422,7 @@ fn tally_too_many_outputs() {
         dr_pointer,
         tally_value.clone(),
         vec![vt0, vt1, vt2, vt3, vt_change],
+        vec![],
     );
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(
@@ -2282,7 +2444,8 @@ fn tally_too_less_outputs() {
     let tally_value = vec![0x92, 0x00, 0x00];
     let vt0 = ValueTransferOutput { pkh, value: 500 };
 
-    let tally_transaction = TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0]);
+    let tally_transaction =
+        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0], vec![]);
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(
         x.unwrap_err().downcast::<TransactionError>().unwrap(),
@@ -2306,8 +2469,12 @@ fn tally_invalid_change() {
         pkh: dr_pkh,
         value: 1000,
     };
-    let tally_transaction =
-        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt_change]);
+    let tally_transaction = TallyTransaction::new(
+        dr_pointer,
+        tally_value.clone(),
+        vec![vt0, vt_change],
+        vec![],
+    );
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(
         x.unwrap_err().downcast::<TransactionError>().unwrap(),
@@ -2328,7 +2495,8 @@ fn tally_double_reward() {
     let tally_value = vec![0x92, 0x00, 0x00];
     let vt0 = ValueTransferOutput { pkh, value: 500 };
     let vt1 = ValueTransferOutput { pkh, value: 500 };
-    let tally_transaction = TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1]);
+    let tally_transaction =
+        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1], vec![]);
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(
         x.unwrap_err().downcast::<TransactionError>().unwrap(),
@@ -2336,7 +2504,27 @@ fn tally_double_reward() {
     );
 }
 
-// TODO: Create a test to check the true_revealer function that will be implemented in FIXME(#640)
+#[test]
+fn tally_mismatched_liars() {
+    // Reveal value: integer(0)
+    let reveal_value = vec![0x00];
+    let (dr_pool, dr_pointer, pkh, pkh2) = dr_pool_with_dr_in_tally_stage_2_reveals(reveal_value);
+
+    // Tally value: [integer(0), integer(0)]
+    let tally_value = vec![0x92, 0x00, 0x00];
+    let vt0 = ValueTransferOutput { pkh, value: 500 };
+    // Both reveals are in consensus, so the second one cannot be flagged as a liar
+    let tally_transaction =
+        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0], vec![pkh2]);
+    let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
+    assert_eq!(
+        x.unwrap_err().downcast::<TransactionError>().unwrap(),
+        TransactionError::MismatchedLiars {
+            local_liars: vec![],
+            miner_liars: vec![pkh2],
+        },
+    );
+}
 
 #[test]
 fn tally_reveal_not_found() {
@@ -2351,7 +2539,8 @@ fn tally_reveal_not_found() {
         pkh: PublicKeyHash::default(),
         value: 500,
     };
-    let tally_transaction = TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1]);
+    let tally_transaction =
+        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1], vec![]);
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(
         x.unwrap_err().downcast::<TransactionError>().unwrap(),
@@ -2372,7 +2561,8 @@ fn tally_valid_2_reveals() {
         pkh: pkh2,
         value: 500,
     };
-    let tally_transaction = TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1]);
+    let tally_transaction =
+        TallyTransaction::new(dr_pointer, tally_value.clone(), vec![vt0, vt1], vec![]);
     let x = validate_tally_transaction(&tally_transaction, &dr_pool).map(|_| ());
     assert_eq!(x.unwrap(), ());
 }
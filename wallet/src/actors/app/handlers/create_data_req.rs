@@ -1,20 +1,38 @@
 use actix::prelude::*;
 
+use witnet_data_structures::chain::Hashable;
+
 use crate::actors::App;
-use crate::api;
+use crate::{api, app, validation, wallet};
 
 impl Message for api::CreateDataReqRequest {
-    type Result = Result<(), api::Error>;
+    type Result = Result<api::CreateDataReqResponse, api::Error>;
 }
 
 impl Handler<api::CreateDataReqRequest> for App {
-    type Result = Result<(), api::Error>;
+    type Result = ResponseActFuture<Self, api::CreateDataReqResponse, api::Error>;
+
+    fn handle(&mut self, msg: api::CreateDataReqRequest, _ctx: &mut Self::Context) -> Self::Result {
+        let validated_params = api::validate_create_data_req(msg).map_err(api::validation_error);
+
+        let f = fut::result(validated_params)
+            .and_then(|params, slf: &mut Self, _ctx| {
+                slf.create_data_request(params)
+                    .map_err(|err| match err {
+                        app::Error::UnknownSession => api::Error::Unauthorized,
+                        app::Error::WrongWallet(_) => api::Error::Forbidden,
+                        app::Error::BuildTransaction(
+                            e @ wallet::BuildTransactionError::InsufficientBalance { .. },
+                        ) => api::validation_error(validation::error("reward", format!("{}", e))),
+                        e => api::internal_error(e),
+                    })
+                    .into_actor(slf)
+            })
+            .map(|transaction, _slf, _ctx| api::CreateDataReqResponse {
+                transaction_id: transaction.hash(),
+                transaction,
+            });
 
-    fn handle(
-        &mut self,
-        _msg: api::CreateDataReqRequest,
-        _ctx: &mut Self::Context,
-    ) -> Self::Result {
-        Ok(())
+        Box::new(f)
     }
 }
@@ -0,0 +1,23 @@
+use actix::prelude::*;
+
+use crate::actors::App;
+use crate::wallet;
+
+/// Data request of an unlocked wallet resolved by a tally included in a block.
+pub struct DataRequestTallied(pub wallet::WalletId, pub wallet::DataRequestTally);
+
+impl Message for DataRequestTallied {
+    type Result = ();
+}
+
+impl Handler<DataRequestTallied> for App {
+    type Result = <DataRequestTallied as Message>::Result;
+
+    fn handle(
+        &mut self,
+        DataRequestTallied(wallet_id, tally): DataRequestTallied,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.notify_tally(wallet_id, tally, ctx);
+    }
+}
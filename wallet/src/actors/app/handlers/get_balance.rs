@@ -0,0 +1,32 @@
+use actix::prelude::*;
+
+use crate::actors::App;
+use crate::{api, app, wallet};
+
+impl Message for api::GetBalanceRequest {
+    type Result = Result<api::GetBalanceResponse, api::Error>;
+}
+
+impl Handler<api::GetBalanceRequest> for App {
+    type Result = ResponseFuture<api::GetBalanceResponse, api::Error>;
+
+    fn handle(&mut self, msg: api::GetBalanceRequest, _ctx: &mut Self::Context) -> Self::Result {
+        let f = self
+            .get_balance(&msg.session_id, &msg.wallet_id)
+            .map_err(|err| match err {
+                app::Error::UnknownSession => api::Error::Unauthorized,
+                app::Error::WrongWallet(_) => api::Error::Forbidden,
+                e => api::internal_error(e),
+            })
+            .map(|accounts| api::GetBalanceResponse {
+                total: accounts
+                    .iter()
+                    .fold(wallet::Balance::default(), |total, balance| {
+                        total + *balance
+                    }),
+                accounts,
+            });
+
+        Box::new(f)
+    }
+}
@@ -1,20 +1,32 @@
 use actix::prelude::*;
 
 use crate::actors::App;
-use crate::api;
+use crate::{api, app};
 
 impl Message for api::GetTransactionsRequest {
-    type Result = Result<(), api::Error>;
+    type Result = Result<api::GetTransactionsResponse, api::Error>;
 }
 
 impl Handler<api::GetTransactionsRequest> for App {
-    type Result = Result<(), api::Error>;
+    type Result = ResponseFuture<api::GetTransactionsResponse, api::Error>;
 
     fn handle(
         &mut self,
-        _msg: api::GetTransactionsRequest,
+        msg: api::GetTransactionsRequest,
         _ctx: &mut Self::Context,
     ) -> Self::Result {
-        Ok(())
+        let f = self
+            .get_transactions(&msg.session_id, &msg.wallet_id, msg.limit, msg.page)
+            .map_err(|err| match err {
+                app::Error::UnknownSession => api::Error::Unauthorized,
+                app::Error::WrongWallet(_) => api::Error::Forbidden,
+                e => api::internal_error(e),
+            })
+            .map(|(total, transactions)| api::GetTransactionsResponse {
+                total,
+                transactions,
+            });
+
+        Box::new(f)
     }
 }
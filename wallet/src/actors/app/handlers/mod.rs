@@ -2,8 +2,10 @@ mod close_session;
 mod create_data_req;
 mod create_mnemonics;
 mod create_wallet;
+mod data_request_tallied;
 mod forward;
 mod generate_address;
+mod get_balance;
 mod get_transactions;
 mod get_wallet_infos;
 mod import_seed;
@@ -17,4 +19,5 @@ mod subscribe;
 mod unlock_wallet;
 mod unsubscribe;
 
+pub use data_request_tallied::*;
 pub use stop::*;
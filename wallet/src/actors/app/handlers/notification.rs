@@ -1,8 +1,6 @@
 use actix::prelude::*;
 
-use jsonrpc_core as rpc;
-use jsonrpc_pubsub as pubsub;
-use serde_json::{Map, Value};
+use serde_json::Value;
 
 use witnet_net::client::tcp::jsonrpc as rpc_client;
 
@@ -17,31 +15,7 @@ impl Handler<rpc_client::Notification> for App {
             ">> Received notification from jsonrpc-client with checkpoint: {:?}",
             checkpoint
         );
-        self.subscriptions
-            .iter()
-            .filter_map(|s| s.as_ref())
-            .enumerate()
-            .for_each(|(slot, subscriber)| {
-                let value = msg.0.clone();
-                let mut obj = Map::new();
-                obj.insert("newBlock".to_string(), value);
-
-                let params = rpc::Params::Map(obj);
-
-                log::debug!("Sending notification to wallet-subscribers.");
-
-                subscriber
-                    .notify(params)
-                    .map(|_| ())
-                    .into_actor(self)
-                    .map_err(move |err, act, _ctx| {
-                        let id = pubsub::SubscriptionId::Number(slot as u64);
-                        act.unsubscribe(id)
-                            .expect("failed to removed faulty subscription");
-                        log::error!("Error notifying client: {}.", err,);
-                    })
-                    .spawn(ctx);
-            });
+        self.notify("newBlock", msg.0, ctx);
     }
 }
 
@@ -10,10 +10,34 @@ impl Handler<api::RunRadReqRequest> for App {
     type Result = ResponseFuture<api::RunRadReqResponse, api::Error>;
 
     fn handle(&mut self, msg: api::RunRadReqRequest, _ctx: &mut Self::Context) -> Self::Result {
+        if msg.trace {
+            let f = self
+                .run_rad_request_with_trace(msg.rad_request)
+                .map_err(api::internal_error)
+                .map(|(result, trace)| {
+                    let (result, error) = match result {
+                        Ok(result) => (Some(result), None),
+                        Err(error) => (None, Some(error.to_string())),
+                    };
+
+                    api::RunRadReqResponse {
+                        result,
+                        error,
+                        trace: Some(trace),
+                    }
+                });
+
+            return Box::new(f);
+        }
+
         let f = self
             .run_rad_request(msg.rad_request)
             .map_err(api::internal_error)
-            .map(|result| api::RunRadReqResponse { result });
+            .map(|result| api::RunRadReqResponse {
+                result: Some(result),
+                error: None,
+                trace: None,
+            });
 
         Box::new(f)
     }
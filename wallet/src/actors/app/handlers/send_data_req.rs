@@ -1,16 +1,31 @@
 use actix::prelude::*;
 
 use crate::actors::App;
-use crate::api;
+use crate::{api, app, validation, wallet};
 
 impl Message for api::SendDataReqRequest {
-    type Result = Result<(), api::Error>;
+    type Result = Result<api::SendDataReqResponse, api::Error>;
 }
 
 impl Handler<api::SendDataReqRequest> for App {
-    type Result = Result<(), api::Error>;
+    type Result = ResponseActFuture<Self, api::SendDataReqResponse, api::Error>;
 
-    fn handle(&mut self, _msg: api::SendDataReqRequest, _ctx: &mut Self::Context) -> Self::Result {
-        Ok(())
+    fn handle(&mut self, msg: api::SendDataReqRequest, _ctx: &mut Self::Context) -> Self::Result {
+        let f = self
+            .send_data_request(msg.session_id, msg.wallet_id, msg.transaction)
+            .map_err(|err, _slf, _ctx| match err {
+                app::Error::UnknownSession => api::Error::Unauthorized,
+                app::Error::WrongWallet(_) => api::Error::Forbidden,
+                app::Error::BuildTransaction(
+                    e @ wallet::BuildTransactionError::UnknownInput(_),
+                ) => api::validation_error(validation::error("transaction", format!("{}", e))),
+                e @ app::Error::NodeNotConnected | e @ app::Error::RequestFailed(_) => {
+                    api::node_error(e)
+                }
+                e => api::internal_error(e),
+            })
+            .map(|transaction_id, _slf, _ctx| api::SendDataReqResponse { transaction_id });
+
+        Box::new(f)
     }
 }
@@ -1,16 +1,38 @@
 use actix::prelude::*;
 
 use crate::actors::App;
-use crate::api;
+use crate::{api, app, validation, wallet};
 
 impl Message for api::SendVttRequest {
-    type Result = Result<(), api::Error>;
+    type Result = Result<api::SendVttResponse, api::Error>;
 }
 
 impl Handler<api::SendVttRequest> for App {
-    type Result = Result<(), api::Error>;
+    type Result = ResponseActFuture<Self, api::SendVttResponse, api::Error>;
 
-    fn handle(&mut self, _msg: api::SendVttRequest, _ctx: &mut Self::Context) -> Self::Result {
-        Ok(())
+    fn handle(&mut self, msg: api::SendVttRequest, _ctx: &mut Self::Context) -> Self::Result {
+        let f = self
+            .send_vtt(
+                msg.session_id,
+                msg.wallet_id,
+                msg.to_address,
+                msg.amount,
+                msg.fee,
+                msg.subject,
+            )
+            .map_err(|err, _slf, _ctx| match err {
+                app::Error::UnknownSession => api::Error::Unauthorized,
+                app::Error::WrongWallet(_) => api::Error::Forbidden,
+                app::Error::BuildTransaction(
+                    e @ wallet::BuildTransactionError::InsufficientBalance { .. },
+                ) => api::validation_error(validation::error("amount", format!("{}", e))),
+                e @ app::Error::NodeNotConnected | e @ app::Error::RequestFailed(_) => {
+                    api::node_error(e)
+                }
+                e => api::internal_error(e),
+            })
+            .map(|transaction_id, _slf, _ctx| api::SendVttResponse { transaction_id });
+
+        Box::new(f)
     }
 }
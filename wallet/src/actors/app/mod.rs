@@ -2,6 +2,7 @@
 //!
 //! See [`App`](App) actor for more information.
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -10,18 +11,25 @@ use actix::utils::TimerFunc;
 use futures::future;
 use jsonrpc_core as rpc;
 use jsonrpc_pubsub as pubsub;
-use serde_json::json;
 
 use witnet_crypto::mnemonic::MnemonicGen;
+use witnet_data_structures::{
+    chain::{Hash, Hashable, InventoryItem, PublicKeyHash, ValueTransferOutput},
+    transaction::{DRTransaction, Transaction, VTTransaction},
+};
 use witnet_net::client::tcp::{jsonrpc as rpc_client, JsonRpcClient};
 use witnet_protected::ProtectedString;
 use witnet_rad as rad;
 
-use crate::actors::{crypto, rad_executor, storage, Crypto, RadExecutor, Storage};
+use crate::actors::{
+    crypto, rad_executor, storage, sync, Crypto, RadExecutor, Storage, Synchronizer,
+};
 use crate::{app, wallet};
 
 pub mod handlers;
 
+/// Expose message to notify the resolution of data requests.
+pub use handlers::DataRequestTallied;
 /// Expose message to stop application.
 pub use handlers::Stop;
 
@@ -34,6 +42,7 @@ pub struct App {
     storage: Addr<Storage>,
     rad_executor: Addr<RadExecutor>,
     crypto: Addr<Crypto>,
+    synchronizer: Option<Addr<Synchronizer>>,
     node_client: Option<Addr<JsonRpcClient>>,
     subscriptions: [Option<pubsub::Sink>; 10],
     sessions: HashMap<app::SessionId, wallet::WalletId>,
@@ -44,18 +53,20 @@ pub struct App {
 impl App {
     /// Start actor.
     pub fn start(
-        db: rocksdb::DB,
+        db: Arc<rocksdb::DB>,
         storage: Addr<Storage>,
         crypto: Addr<Crypto>,
         rad_executor: Addr<RadExecutor>,
+        synchronizer: Option<Addr<Synchronizer>>,
         node_client: Option<Addr<JsonRpcClient>>,
         session_expiration: Duration,
     ) -> Addr<Self> {
         let slf = Self {
-            db: Arc::new(db),
+            db,
             storage,
             crypto,
             rad_executor,
+            synchronizer,
             node_client,
             session_expiration,
             subscriptions: Default::default(),
@@ -80,6 +91,21 @@ impl App {
         Box::new(f)
     }
 
+    /// Run a RADRequest recording the execution of every call of its scripts. The trace is
+    /// returned even if the request fails.
+    pub fn run_rad_request_with_trace(
+        &self,
+        req: wallet::RADRequest,
+    ) -> ResponseFuture<(rad::Result<rad::types::RadonTypes>, rad::RADRequestTrace), app::Error>
+    {
+        let f = self
+            .rad_executor
+            .send(rad_executor::RunWithTrace(req))
+            .map_err(app::Error::RadScheduleFailed);
+
+        Box::new(f)
+    }
+
     /// Generate a random BIP39 mnemonics sentence
     pub fn generate_mnemonics(&self, params: app::CreateMnemonics) -> String {
         let mnemonic = MnemonicGen::new().with_len(params.length).generate();
@@ -124,17 +150,123 @@ impl App {
         Ok(())
     }
 
+    /// Send a notification to every subscriber, under the given name. Subscribers that cannot be
+    /// notified are removed.
+    pub fn notify(
+        &mut self,
+        name: &str,
+        value: serde_json::Value,
+        ctx: &mut <Self as Actor>::Context,
+    ) {
+        let mut obj = serde_json::Map::new();
+        obj.insert(name.to_string(), value);
+        let params = rpc::Params::Map(obj);
+
+        self.subscriptions
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, subscriber)| {
+                subscriber.as_ref().map(|subscriber| (slot, subscriber))
+            })
+            .for_each(|(slot, subscriber)| {
+                log::debug!("Sending notification to wallet-subscribers.");
+
+                subscriber
+                    .notify(params.clone())
+                    .map(|_| ())
+                    .into_actor(self)
+                    .map_err(move |err, act, _ctx| {
+                        let id = pubsub::SubscriptionId::Number(slot as u64);
+                        act.unsubscribe(id)
+                            .expect("failed to removed faulty subscription");
+                        log::error!("Error notifying client: {}.", err,);
+                    })
+                    .spawn(ctx);
+            });
+    }
+
+    /// Notify the subscribers that a data request of an unlocked wallet has been resolved,
+    /// decoding the result of its tally.
+    pub fn notify_tally(
+        &mut self,
+        wallet_id: wallet::WalletId,
+        tally: wallet::DataRequestTally,
+        ctx: &mut <Self as Actor>::Context,
+    ) {
+        let result = rad::types::RadonTypes::try_from(tally.report.tally.tally.as_slice())
+            .map_err(|err| err.to_string())
+            .and_then(|result| serde_json::to_value(result).map_err(|err| err.to_string()));
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(err) => {
+                log::warn!(
+                    "Failed to decode the tally of data request {}: {}",
+                    tally.data_request,
+                    err
+                );
+                (None, Some(err))
+            }
+        };
+        let value = serde_json::json!({
+            "walletId": wallet_id,
+            "account": tally.account,
+            "dataRequest": tally.data_request,
+            "epoch": tally.epoch,
+            "commits": tally.report.commits.len(),
+            "reveals": tally.report.reveals.len(),
+            "result": result,
+            "error": error,
+        });
+
+        self.notify("dataRequestTally", value, ctx);
+    }
+
     /// Forward a Json-RPC call to the node.
     pub fn forward(
         &mut self,
         method: String,
         params: rpc::Params,
+    ) -> ResponseFuture<serde_json::Value, app::Error> {
+        let req = rpc_client::Request::method(method)
+            .params(params)
+            .expect("rpc::Params failed serialization");
+
+        self.send_to_node(req)
+    }
+
+    /// Modify the content of an unlocked wallet without any other change to the wallet happening
+    /// in between, see [`ModifyWalletContent`](storage::ModifyWalletContent).
+    fn modify_wallet_content<F, T>(
+        &self,
+        wallet_id: wallet::WalletId,
+        key: Arc<wallet::Key>,
+        modify: F,
+    ) -> ResponseFuture<T, app::Error>
+    where
+        F: FnOnce(&mut wallet::WalletContent) -> Result<T, app::Error> + Send + 'static,
+        T: Send + 'static,
+    {
+        let f = self
+            .storage
+            .send(storage::ModifyWalletContent(
+                self.db.clone(),
+                wallet_id,
+                key,
+                modify,
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result);
+
+        Box::new(f)
+    }
+
+    /// Send a Json-RPC request to the node.
+    fn send_to_node(
+        &self,
+        req: rpc_client::Request,
     ) -> ResponseFuture<serde_json::Value, app::Error> {
         match &self.node_client {
             Some(addr) => {
-                let req = rpc_client::Request::method(method)
-                    .params(params)
-                    .expect("rpc::Params failed serialization");
                 let f = addr
                     .send(req)
                     .map_err(app::Error::RequestFailedToSend)
@@ -222,6 +354,9 @@ impl App {
 
         if session_wallet_id == wallet_id {
             self.wallet_keys.remove(&wallet_id);
+            if let Some(ref synchronizer) = self.synchronizer {
+                synchronizer.do_send(sync::Untrack(wallet_id));
+            }
             Ok(())
         } else {
             let err = app::Error::WrongWallet(wallet_id);
@@ -275,7 +410,11 @@ impl App {
                                 log::debug!("Unlocking wallet {}", &wallet_id);
                                 let session_id = Arc::new(session_id);
                                 slf.sessions.insert(session_id.clone(), wallet_id.clone());
-                                slf.wallet_keys.insert(wallet_id, wallet_key);
+                                slf.wallet_keys
+                                    .insert(wallet_id.clone(), wallet_key.clone());
+                                if let Some(ref synchronizer) = slf.synchronizer {
+                                    synchronizer.do_send(sync::Track(wallet_id, wallet_key));
+                                }
 
                                 fut::ok(session_id)
                             })
@@ -286,6 +425,330 @@ impl App {
         }
     }
 
+    /// Get the encryption key of a wallet, checking first that the given session has been granted
+    /// access to it.
+    fn session_wallet_key(
+        &self,
+        session_id: &app::SessionId,
+        wallet_id: &wallet::WalletId,
+    ) -> Result<Arc<wallet::Key>, app::Error> {
+        match self.sessions.get(session_id) {
+            Some(session_wallet_id) if session_wallet_id == wallet_id => self
+                .wallet_keys
+                .get(wallet_id)
+                .cloned()
+                .ok_or_else(|| app::Error::WrongWallet(wallet_id.clone())),
+            Some(_) => Err(app::Error::WrongWallet(wallet_id.clone())),
+            None => Err(app::Error::UnknownSession),
+        }
+    }
+
+    /// Build a value transfer transaction paying `amount` to `pkh` with the funds of the first
+    /// account of an unlocked wallet, sign it and send it to the node.
+    ///
+    /// The outputs spent by the transaction are reserved in the wallet before sending it, and
+    /// released again if the node does not accept it. The id of the transaction is returned.
+    fn send_vtt(
+        &self,
+        session_id: app::SessionId,
+        wallet_id: wallet::WalletId,
+        pkh: PublicKeyHash,
+        amount: u64,
+        fee: u64,
+        subject: String,
+    ) -> ResponseActFuture<Self, Hash, app::Error> {
+        let key = match self.session_wallet_key(&session_id, &wallet_id) {
+            Ok(key) => key,
+            Err(err) => return Box::new(fut::err(err)),
+        };
+        let outputs = vec![ValueTransferOutput { pkh, value: amount }];
+        let f = self
+            .modify_wallet_content(wallet_id.clone(), key.clone(), {
+                let wallet_id = wallet_id.clone();
+                move |content| {
+                    let master_key = content.master_key.clone();
+                    // Building the transaction marks the outputs it spends as spent, so they
+                    // cannot be selected by other transactions once the content is saved
+                    match content.account_mut(0) {
+                        Some(account) => account
+                            .build_vtt(&master_key, outputs, fee)
+                            .map_err(app::Error::BuildTransaction),
+                        None => Err(app::Error::UnknownAccount(wallet_id)),
+                    }
+                }
+            })
+            .into_actor(self)
+            .and_then(move |(body, keys), slf, _ctx| {
+                let transaction_id = body.hash();
+
+                slf.crypto
+                    .send(crypto::SignTransaction(transaction_id, keys))
+                    .map_err(app::Error::CryptoFailed)
+                    .map(move |signatures| VTTransaction::new(body, signatures))
+                    .into_actor(slf)
+                    .and_then({
+                        let wallet_id = wallet_id.clone();
+                        let key = key.clone();
+                        move |vtt, slf, _ctx| {
+                            slf.broadcast_transaction(
+                                wallet_id,
+                                key,
+                                Transaction::ValueTransfer(vtt),
+                                Some(subject),
+                            )
+                        }
+                    })
+                    .then(move |result, slf, _ctx| {
+                        slf.discard_on_error(wallet_id, key, transaction_id, result)
+                            .into_actor(slf)
+                    })
+            });
+
+        Box::new(f)
+    }
+
+    /// Build a transaction posting a data request funded with the first account of an unlocked
+    /// wallet and sign it.
+    ///
+    /// The wallet is not updated, so the returned transaction can be reviewed before sending it
+    /// with [`send_data_request`](App::send_data_request).
+    fn create_data_request(
+        &self,
+        params: app::CreateDataReq,
+    ) -> ResponseFuture<DRTransaction, app::Error> {
+        let app::CreateDataReq {
+            session_id,
+            wallet_id,
+            dr_output,
+        } = params;
+        let key = match self.session_wallet_key(&session_id, &wallet_id) {
+            Ok(key) => key,
+            Err(err) => return Box::new(future::err(err)),
+        };
+        let crypto_addr = self.crypto.clone();
+        let f = self
+            .storage
+            .send(storage::GetWalletContent(
+                self.db.clone(),
+                wallet_id.clone(),
+                key,
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result.map_err(app::Error::Storage))
+            .and_then(move |mut content| {
+                let master_key = content.master_key.clone();
+                match content.account_mut(0) {
+                    Some(account) => account
+                        .build_drt(&master_key, dr_output)
+                        .map_err(app::Error::BuildTransaction),
+                    None => Err(app::Error::UnknownAccount(wallet_id)),
+                }
+            })
+            .and_then(move |(body, keys)| {
+                crypto_addr
+                    .send(crypto::SignTransaction(body.hash(), keys))
+                    .map_err(app::Error::CryptoFailed)
+                    .map(move |signatures| DRTransaction::new(body, signatures))
+            });
+
+        Box::new(f)
+    }
+
+    /// Send to the node a data request transaction funded with the first account of an unlocked
+    /// wallet, see [`create_data_request`](App::create_data_request).
+    ///
+    /// The outputs spent by the transaction are reserved in the wallet before sending it, and
+    /// released again if the node does not accept it. The wallet follows the data request until
+    /// it is tallied.
+    fn send_data_request(
+        &self,
+        session_id: app::SessionId,
+        wallet_id: wallet::WalletId,
+        transaction: DRTransaction,
+    ) -> ResponseActFuture<Self, Hash, app::Error> {
+        let key = match self.session_wallet_key(&session_id, &wallet_id) {
+            Ok(key) => key,
+            Err(err) => return Box::new(fut::err(err)),
+        };
+        let transaction_id = transaction.hash();
+        let f = self
+            .broadcast_transaction(
+                wallet_id.clone(),
+                key.clone(),
+                Transaction::DataRequest(transaction),
+                None,
+            )
+            .then(move |result, slf, _ctx| {
+                slf.discard_on_error(wallet_id, key, transaction_id, result)
+                    .into_actor(slf)
+            });
+
+        Box::new(f)
+    }
+
+    /// Send a transaction built with the first account of an unlocked wallet to the node.
+    ///
+    /// The transaction is added to the account as pending before sending it, unless it already
+    /// is, and once the node accepts it, it is added to the transaction history of the account.
+    /// Failing to add it to the history is only logged, as the transaction has already been sent.
+    fn broadcast_transaction(
+        &self,
+        wallet_id: wallet::WalletId,
+        key: Arc<wallet::Key>,
+        transaction: Transaction,
+        subject: Option<String>,
+    ) -> ResponseActFuture<Self, Hash, app::Error> {
+        let transaction_id = transaction.hash();
+        let storage_addr = self.storage.clone();
+        let db = self.db.clone();
+        let f = self
+            .modify_wallet_content(wallet_id.clone(), key.clone(), {
+                let wallet_id = wallet_id.clone();
+                let transaction = transaction.clone();
+                move |content| match content.account_mut(0) {
+                    Some(account) => {
+                        // Transactions built by the account itself are already pending
+                        if !account.is_pending(&transaction_id) {
+                            account
+                                .add_pending(&transaction)
+                                .map_err(app::Error::BuildTransaction)?;
+                        }
+
+                        Ok(account
+                            .transaction_entry(&transaction, None)
+                            .map(|entry| wallet::TransactionEntry { subject, ..entry })
+                            .into_iter()
+                            .collect::<Vec<_>>())
+                    }
+                    None => Err(app::Error::UnknownAccount(wallet_id)),
+                }
+            })
+            .and_then(move |entries| {
+                rpc_client::Request::method("inventory")
+                    .params(InventoryItem::Transaction(transaction))
+                    .map_err(app::Error::RequestFailed)
+                    .map(|req| (req, entries))
+            })
+            .into_actor(self)
+            .and_then(|(req, entries), slf, _ctx| {
+                slf.send_to_node(req).map(move |_| entries).into_actor(slf)
+            })
+            .and_then(move |entries, slf, _ctx| {
+                log::info!("Transaction {} sent to the node", transaction_id);
+
+                storage_addr
+                    .send(storage::IndexTransactions(db, wallet_id, key, 0, entries))
+                    .map_err(app::Error::StorageFailed)
+                    .and_then(|result| result.map_err(app::Error::Storage))
+                    .then(move |result| -> Result<Hash, app::Error> {
+                        // The node already has the transaction, so it must stay pending
+                        if let Err(err) = result {
+                            log::error!(
+                                "Failed to add transaction {} to the history: {}",
+                                transaction_id,
+                                err
+                            );
+                        }
+
+                        Ok(transaction_id)
+                    })
+                    .into_actor(slf)
+            });
+
+        Box::new(f)
+    }
+
+    /// Stop tracking a pending transaction of the first account of an unlocked wallet if it could
+    /// not be sent, so the outputs it spends can be used again.
+    ///
+    /// The given result is returned unchanged.
+    fn discard_on_error(
+        &self,
+        wallet_id: wallet::WalletId,
+        key: Arc<wallet::Key>,
+        transaction_id: Hash,
+        result: Result<Hash, app::Error>,
+    ) -> ResponseFuture<Hash, app::Error> {
+        let err = match result {
+            Ok(transaction_id) => return Box::new(future::ok(transaction_id)),
+            Err(err) => err,
+        };
+        log::warn!("Transaction {} was not sent: {}", transaction_id, err);
+        let f = self
+            .modify_wallet_content(wallet_id, key, move |content| {
+                if let Some(account) = content.account_mut(0) {
+                    account.discard_pending(&transaction_id);
+                }
+
+                Ok(())
+            })
+            .then(move |result| -> Result<Hash, app::Error> {
+                if let Err(discard_err) = result {
+                    log::error!(
+                        "Failed to release the outputs spent by transaction {}: {}",
+                        transaction_id,
+                        discard_err
+                    );
+                }
+
+                Err(err)
+            });
+
+        Box::new(f)
+    }
+
+    /// Get a page of the transaction history of the first account of an unlocked wallet.
+    fn get_transactions(
+        &self,
+        session_id: &app::SessionId,
+        wallet_id: &wallet::WalletId,
+        limit: u32,
+        page: u32,
+    ) -> ResponseFuture<(u32, Vec<wallet::TransactionEntry>), app::Error> {
+        let key = match self.session_wallet_key(session_id, wallet_id) {
+            Ok(key) => key,
+            Err(err) => return Box::new(future::err(err)),
+        };
+        let f = self
+            .storage
+            .send(storage::GetTransactions(
+                self.db.clone(),
+                wallet_id.clone(),
+                key,
+                0,
+                limit,
+                page,
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result.map_err(app::Error::Storage));
+
+        Box::new(f)
+    }
+
+    /// Get the balance of each account of an unlocked wallet.
+    fn get_balance(
+        &self,
+        session_id: &app::SessionId,
+        wallet_id: &wallet::WalletId,
+    ) -> ResponseFuture<Vec<wallet::Balance>, app::Error> {
+        let key = match self.session_wallet_key(session_id, wallet_id) {
+            Ok(key) => key,
+            Err(err) => return Box::new(future::err(err)),
+        };
+        let f = self
+            .storage
+            .send(storage::GetWalletContent(
+                self.db.clone(),
+                wallet_id.clone(),
+                key,
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result.map_err(app::Error::Storage))
+            .map(|content| content.balances());
+
+        Box::new(f)
+    }
+
     /// Return a timer function that can be scheduled to expire the session after the configured time.
     fn set_session_to_expire(&self, session_id: app::SessionId) -> TimerFunc<Self> {
         log::debug!(
@@ -320,11 +783,12 @@ impl Actor for App {
     type Context = Context<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
-        if let Some(ref client) = self.node_client {
-            let recipient = ctx.address().recipient();
-            let request =
-                rpc_client::Request::method("witnet_subscribe").value(json!(["newBlocks"]));
-            client.do_send(rpc_client::SetSubscriber(recipient, request));
+        if let Some(ref synchronizer) = self.synchronizer {
+            let addr = ctx.address();
+            synchronizer.do_send(sync::SetListener(
+                addr.clone().recipient(),
+                addr.recipient(),
+            ));
         }
     }
 }
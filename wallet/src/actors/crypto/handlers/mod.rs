@@ -1,5 +1,7 @@
 mod gen_session_id;
 mod gen_wallet_keys;
+mod sign_transaction;
 
 pub use gen_session_id::*;
 pub use gen_wallet_keys::*;
+pub use sign_transaction::*;
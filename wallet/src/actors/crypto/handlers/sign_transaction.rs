@@ -0,0 +1,25 @@
+use actix::prelude::*;
+
+use witnet_crypto::key::ExtendedSK;
+use witnet_data_structures::chain::{Hash, KeyedSignature};
+
+use crate::actors::Crypto;
+
+/// Sign a transaction hash once per key, in the same order as the keys are given.
+pub struct SignTransaction(pub Hash, pub Vec<ExtendedSK>);
+
+impl Message for SignTransaction {
+    type Result = Vec<KeyedSignature>;
+}
+
+impl Handler<SignTransaction> for Crypto {
+    type Result = <SignTransaction as Message>::Result;
+
+    fn handle(
+        &mut self,
+        SignTransaction(hash, keys): SignTransaction,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.sign_transaction(hash, keys.as_ref())
+    }
+}
@@ -6,7 +6,13 @@ use std::cell::RefCell;
 use actix::prelude::*;
 use rand::Rng as _;
 
-use witnet_crypto::{hash::HashFunction, key::MasterKeyGen, pbkdf2::pbkdf2_sha256};
+use witnet_crypto::{
+    hash::HashFunction,
+    key::{ExtendedSK, MasterKeyGen, SignContext, PK},
+    pbkdf2::pbkdf2_sha256,
+    signature,
+};
+use witnet_data_structures::chain::{Hash, KeyedSignature, PublicKey, Signature};
 use witnet_protected::ProtectedString;
 
 use crate::{app, crypto, wallet};
@@ -96,6 +102,22 @@ impl Crypto {
             }
         }
     }
+
+    /// Sign the hash of a transaction with each one of the given keys.
+    pub fn sign_transaction(&self, hash: Hash, keys: &[ExtendedSK]) -> Vec<KeyedSignature> {
+        let Hash::SHA256(data) = hash;
+
+        keys.iter()
+            .map(|key| {
+                let public_key = PK::from_secret_key(&SignContext::signing_only(), &key.secret_key);
+
+                KeyedSignature {
+                    signature: Signature::from(signature::sign(key.secret_key, &data)),
+                    public_key: PublicKey::from(public_key),
+                }
+            })
+            .collect()
+    }
 }
 
 impl Actor for Crypto {
@@ -4,9 +4,11 @@ pub mod controller;
 pub mod crypto;
 pub mod rad_executor;
 pub mod storage;
+pub mod sync;
 
 pub use app::App;
 pub use controller::Controller;
 pub use crypto::Crypto;
 pub use rad_executor::RadExecutor;
 pub use storage::Storage;
+pub use sync::Synchronizer;
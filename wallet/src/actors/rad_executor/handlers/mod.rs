@@ -1,3 +1,5 @@
 mod run;
+mod run_with_trace;
 
 pub use run::*;
+pub use run_with_trace::*;
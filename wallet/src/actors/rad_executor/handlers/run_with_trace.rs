@@ -0,0 +1,26 @@
+use actix::prelude::*;
+
+use witnet_data_structures::chain::RADRequest;
+use witnet_rad::{self as rad, types::RadonTypes};
+
+use crate::actors::RadExecutor;
+
+/// Execute the containing RAD-request, recording the execution of every call of its scripts.
+pub struct RunWithTrace(pub RADRequest);
+
+impl Message for RunWithTrace {
+    type Result = (rad::Result<RadonTypes>, rad::RADRequestTrace);
+}
+
+impl Handler<RunWithTrace> for RadExecutor {
+    type Result = MessageResult<RunWithTrace>;
+
+    fn handle(
+        &mut self,
+        RunWithTrace(request): RunWithTrace,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        log::debug!("Executing RAD request with trace");
+        MessageResult(rad::run_request_with_trace(&request, &self.context()))
+    }
+}
@@ -7,28 +7,50 @@ use std::convert::TryFrom;
 use actix::prelude::*;
 use rayon::prelude::*;
 
-use witnet_data_structures::chain::RADRequest;
+use witnet_data_structures::chain::{Hash, RADRequest};
 use witnet_rad::{self as rad, types::RadonTypes};
 
 mod handlers;
 
 pub use handlers::*;
 
+/// Context for running RAD requests outside of the chain. There is no block to derive the seed of
+/// `RNG` sources from, so a random one is used, and `TALLY` sources are not resolved.
+pub fn retrieval_context(limits: rad::RetrievalLimits) -> rad::RetrievalContext {
+    rad::RetrievalContext {
+        rng_seed: Some(Hash::SHA256(rand::random())),
+        limits,
+        ..rad::RetrievalContext::default()
+    }
+}
+
 /// Actor that executes RAD-requests in a sync context.
-pub struct RadExecutor;
+pub struct RadExecutor {
+    /// Limits to the requests made to HTTP sources
+    limits: rad::RetrievalLimits,
+}
 
 impl RadExecutor {
     /// Start actor.
-    pub fn start() -> Addr<Self> {
-        SyncArbiter::start(1, || Self)
+    pub fn start(limits: rad::RetrievalLimits) -> Addr<Self> {
+        SyncArbiter::start(1, move || Self {
+            limits: limits.clone(),
+        })
+    }
+
+    /// Context for running RAD requests with the limits of this executor.
+    pub fn context(&self) -> rad::RetrievalContext {
+        retrieval_context(self.limits.clone())
     }
 
     /// Run RAD request
     pub fn run(&self, request: RADRequest) -> rad::Result<RadonTypes> {
+        let context = self.context();
+
         request
             .retrieve
             .par_iter()
-            .map(rad::run_retrieval)
+            .map(|retrieve| rad::run_retrieval(retrieve, &context))
             .collect::<Result<Vec<_>, _>>()
             .and_then(|retrievals| {
                 rad::run_aggregation(retrievals, &request.aggregate)
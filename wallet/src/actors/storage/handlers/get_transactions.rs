@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Get a page of the transaction history of an account of an unlocked wallet.
+pub struct GetTransactions(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+    /// Account index
+    pub u32,
+    /// Limit
+    pub u32,
+    /// Page
+    pub u32,
+);
+
+impl Message for GetTransactions {
+    type Result = Result<(u32, Vec<wallet::TransactionEntry>), storage::Error>;
+}
+
+impl Handler<GetTransactions> for Storage {
+    type Result = <GetTransactions as Message>::Result;
+
+    fn handle(
+        &mut self,
+        GetTransactions(db, id, key, account, limit, page): GetTransactions,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.get_transactions(db.as_ref(), id.as_ref(), key.as_ref(), account, limit, page)
+    }
+}
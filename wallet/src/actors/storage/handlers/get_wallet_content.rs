@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Decrypt the content of an unlocked wallet.
+pub struct GetWalletContent(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+);
+
+impl Message for GetWalletContent {
+    type Result = Result<wallet::WalletContent, storage::Error>;
+}
+
+impl Handler<GetWalletContent> for Storage {
+    type Result = <GetWalletContent as Message>::Result;
+
+    fn handle(
+        &mut self,
+        GetWalletContent(db, id, key): GetWalletContent,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.get_wallet_content(db.as_ref(), id.as_ref(), key.as_ref())
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Add transactions to the history index of an account of an unlocked wallet.
+pub struct IndexTransactions(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+    /// Account index
+    pub u32,
+    pub Vec<wallet::TransactionEntry>,
+);
+
+impl Message for IndexTransactions {
+    type Result = Result<(), storage::Error>;
+}
+
+impl Handler<IndexTransactions> for Storage {
+    type Result = <IndexTransactions as Message>::Result;
+
+    fn handle(
+        &mut self,
+        IndexTransactions(db, id, key, account, entries): IndexTransactions,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.index_transactions(db.as_ref(), id.as_ref(), key.as_ref(), account, entries)
+    }
+}
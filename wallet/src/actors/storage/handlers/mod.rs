@@ -1,9 +1,23 @@
 mod create_wallet;
 mod flush;
+mod get_transactions;
+mod get_wallet_content;
 mod get_wallet_infos;
+mod index_transactions;
+mod modify_wallet_content;
+mod reset_wallet;
+mod rollback_blocks;
+mod sync_block;
 mod unlock_wallet;
 
 pub use create_wallet::*;
 pub use flush::*;
+pub use get_transactions::*;
+pub use get_wallet_content::*;
 pub use get_wallet_infos::*;
+pub use index_transactions::*;
+pub use modify_wallet_content::*;
+pub use reset_wallet::*;
+pub use rollback_blocks::*;
+pub use sync_block::*;
 pub use unlock_wallet::*;
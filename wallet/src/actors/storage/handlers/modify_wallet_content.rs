@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Load the content of an unlocked wallet, modify it and save it.
+///
+/// The content is only saved if the given function succeeds.
+pub struct ModifyWalletContent<F>(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+    pub F,
+);
+
+impl<F, T, E> Message for ModifyWalletContent<F>
+where
+    F: FnOnce(&mut wallet::WalletContent) -> Result<T, E>,
+    T: 'static,
+    E: From<storage::Error> + 'static,
+{
+    type Result = Result<T, E>;
+}
+
+impl<F, T, E> Handler<ModifyWalletContent<F>> for Storage
+where
+    F: FnOnce(&mut wallet::WalletContent) -> Result<T, E>,
+    T: 'static,
+    E: From<storage::Error> + 'static,
+{
+    type Result = Result<T, E>;
+
+    fn handle(
+        &mut self,
+        ModifyWalletContent(db, id, key, modify): ModifyWalletContent<F>,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.modify_wallet_content(db.as_ref(), id.as_ref(), key.as_ref(), modify)
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use witnet_data_structures::chain::Epoch;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Forget everything an unlocked wallet has learned from the node's chain.
+pub struct ResetWallet(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+);
+
+impl Message for ResetWallet {
+    type Result = Result<Epoch, storage::Error>;
+}
+
+impl Handler<ResetWallet> for Storage {
+    type Result = <ResetWallet as Message>::Result;
+
+    fn handle(
+        &mut self,
+        ResetWallet(db, id, key): ResetWallet,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.reset_wallet(db.as_ref(), id.as_ref(), key.as_ref())
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Revert the last blocks applied to an unlocked wallet.
+pub struct RollbackBlocks(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+    /// Number of blocks to revert
+    pub usize,
+);
+
+impl Message for RollbackBlocks {
+    type Result = Result<usize, storage::Error>;
+}
+
+impl Handler<RollbackBlocks> for Storage {
+    type Result = <RollbackBlocks as Message>::Result;
+
+    fn handle(
+        &mut self,
+        RollbackBlocks(db, id, key, count): RollbackBlocks,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.rollback_blocks(db.as_ref(), id.as_ref(), key.as_ref(), count)
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use witnet_data_structures::chain::Block;
+
+use crate::actors::storage::Storage;
+use crate::{storage, wallet};
+
+/// Apply a block of the node's chain to an unlocked wallet.
+pub struct SyncBlock(
+    pub Arc<rocksdb::DB>,
+    pub wallet::WalletId,
+    pub Arc<wallet::Key>,
+    pub Block,
+);
+
+impl Message for SyncBlock {
+    type Result = Result<(wallet::SyncStatus, Vec<wallet::DataRequestTally>), storage::Error>;
+}
+
+impl Handler<SyncBlock> for Storage {
+    type Result = <SyncBlock as Message>::Result;
+
+    fn handle(
+        &mut self,
+        SyncBlock(db, id, key, block): SyncBlock,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.sync_block(db.as_ref(), id.as_ref(), key.as_ref(), &block)
+    }
+}
@@ -3,9 +3,12 @@
 //! It is charge of managing the connection to the key-value database. This actor is blocking so it
 //! must be used with a `SyncArbiter`.
 
+use std::collections::HashMap;
+
 use actix::prelude::*;
 use rocksdb::DB;
 
+use witnet_data_structures::chain::{Block, Epoch};
 use witnet_protected::ProtectedString;
 
 use crate::{storage, wallet};
@@ -81,6 +84,323 @@ impl Storage {
         Ok(())
     }
 
+    pub fn get_wallet_content(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+    ) -> Result<wallet::WalletContent, storage::Error> {
+        let encrypted: Vec<u8> = storage::get_opt(db, storage::keys::wallet(id))?
+            .ok_or_else(|| storage::Error::WalletNotFound)?;
+
+        storage::decrypt_wallet_content(
+            self.encrypt_salt_length,
+            self.encrypt_iv_length,
+            key,
+            encrypted.as_ref(),
+        )
+    }
+
+    pub fn update_wallet_content(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        content: &wallet::WalletContent,
+    ) -> Result<(), storage::Error> {
+        let mut batch = rocksdb::WriteBatch::default();
+
+        storage::put(
+            &mut batch,
+            storage::keys::wallet(id),
+            &storage::encrypt(self.encrypt_iv_length, key, content)?,
+        )?;
+
+        storage::write(db, batch)
+    }
+
+    /// Load the content of an unlocked wallet, let `modify` change it and save it if `modify`
+    /// succeeds.
+    ///
+    /// This actor handles one message at a time, so nothing else can change the wallet between
+    /// loading and saving its content.
+    pub fn modify_wallet_content<F, T, E>(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        modify: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(&mut wallet::WalletContent) -> Result<T, E>,
+        E: From<storage::Error>,
+    {
+        let mut content = self.get_wallet_content(db, id, key)?;
+        let value = modify(&mut content)?;
+        self.update_wallet_content(db, id, key, &content)?;
+
+        Ok(value)
+    }
+
+    /// Add transactions to the history index of an account, or update them if they were already
+    /// indexed.
+    pub fn index_transactions(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        account: u32,
+        entries: Vec<wallet::TransactionEntry>,
+    ) -> Result<(), storage::Error> {
+        let mut batch = rocksdb::WriteBatch::default();
+
+        self.batch_index_transactions(db, &mut batch, id, key, account, entries)?;
+
+        storage::write(db, batch)
+    }
+
+    /// Apply a block of the node's chain to an unlocked wallet, updating its unspent outputs,
+    /// balances and transaction history.
+    ///
+    /// Return whether the block was applied, along with the data requests of the wallet resolved
+    /// by its tallies.
+    pub fn sync_block(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        block: &Block,
+    ) -> Result<(wallet::SyncStatus, Vec<wallet::DataRequestTally>), storage::Error> {
+        let mut content = self.get_wallet_content(db, id, key)?;
+        let (status, entries, tallies) = content
+            .apply_block(block)
+            .map_err(storage::Error::KeyDerivation)?;
+
+        if status == wallet::SyncStatus::Applied {
+            let mut batch = rocksdb::WriteBatch::default();
+            let mut accounts_entries: HashMap<u32, Vec<wallet::TransactionEntry>> = HashMap::new();
+            for (account, entry) in entries {
+                accounts_entries.entry(account).or_default().push(entry);
+            }
+            for (account, entries) in accounts_entries {
+                self.batch_index_transactions(db, &mut batch, id, key, account, entries)?;
+            }
+            storage::put(
+                &mut batch,
+                storage::keys::wallet(id),
+                &storage::encrypt(self.encrypt_iv_length, key, &content)?,
+            )?;
+            storage::write(db, batch)?;
+        }
+
+        Ok((status, tallies))
+    }
+
+    /// Revert the last `count` blocks applied to an unlocked wallet. The transactions included in
+    /// those blocks are marked as pending in the transaction history.
+    ///
+    /// Return the number of blocks actually reverted.
+    pub fn rollback_blocks(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        count: usize,
+    ) -> Result<usize, storage::Error> {
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut content = self.get_wallet_content(db, id, key)?;
+        let mut reverted = 0;
+
+        while reverted < count {
+            let transactions = match content.rollback_block() {
+                Some(transactions) => transactions,
+                None => break,
+            };
+            reverted += 1;
+
+            for (account, transaction_id) in transactions {
+                let index: u32 = match storage::get_opt(
+                    db,
+                    storage::keys::account_transaction_index(id, account, &transaction_id),
+                )? {
+                    Some(index) => index,
+                    None => continue,
+                };
+                self.batch_mark_pending(db, &mut batch, id, key, account, index)?;
+            }
+        }
+
+        storage::put(
+            &mut batch,
+            storage::keys::wallet(id),
+            &storage::encrypt(self.encrypt_iv_length, key, &content)?,
+        )?;
+        storage::write(db, batch)?;
+
+        Ok(reverted)
+    }
+
+    /// Forget everything an unlocked wallet has learned from the node's chain, so it can be
+    /// applied again from the birth of the wallet. All the transactions in the history of the
+    /// wallet are marked as pending.
+    ///
+    /// Return the epoch from which the chain must be applied again.
+    pub fn reset_wallet(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+    ) -> Result<Epoch, storage::Error> {
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut content = self.get_wallet_content(db, id, key)?;
+        content.reset();
+
+        for account in 0..content.accounts.len() as u32 {
+            let count: u32 =
+                storage::get_default(db, storage::keys::account_transactions_count(id, account))?;
+            for index in 0..count {
+                self.batch_mark_pending(db, &mut batch, id, key, account, index)?;
+            }
+        }
+
+        storage::put(
+            &mut batch,
+            storage::keys::wallet(id),
+            &storage::encrypt(self.encrypt_iv_length, key, &content)?,
+        )?;
+        storage::write(db, batch)?;
+
+        Ok(content.epoch_born)
+    }
+
+    /// Add the operations needed to mark an entry of the transaction history of an account as
+    /// pending to a write batch.
+    fn batch_mark_pending(
+        &self,
+        db: &DB,
+        batch: &mut rocksdb::WriteBatch,
+        id: &str,
+        key: &wallet::Key,
+        account: u32,
+        index: u32,
+    ) -> Result<(), storage::Error> {
+        let entry_key = storage::keys::account_transaction(id, account, index);
+        let encrypted: Vec<u8> = storage::get(db, &entry_key)?;
+        let entry: wallet::TransactionEntry = storage::decrypt(
+            self.encrypt_salt_length,
+            self.encrypt_iv_length,
+            key,
+            encrypted.as_ref(),
+        )?;
+        let entry = wallet::TransactionEntry {
+            epoch: None,
+            status: wallet::TransactionStatus::Pending,
+            ..entry
+        };
+
+        storage::put(
+            batch,
+            entry_key,
+            &storage::encrypt(self.encrypt_iv_length, key, &entry)?,
+        )
+    }
+
+    /// Add the operations needed to index the given transactions to a write batch.
+    fn batch_index_transactions(
+        &self,
+        db: &DB,
+        batch: &mut rocksdb::WriteBatch,
+        id: &str,
+        key: &wallet::Key,
+        account: u32,
+        entries: Vec<wallet::TransactionEntry>,
+    ) -> Result<(), storage::Error> {
+        let count_key = storage::keys::account_transactions_count(id, account);
+        let mut count: u32 = storage::get_default(db, &count_key)?;
+        let mut new_indexes = HashMap::new();
+
+        for mut entry in entries {
+            let index_key =
+                storage::keys::account_transaction_index(id, account, &entry.transaction_id);
+            let existing_index = match new_indexes.get(&entry.transaction_id) {
+                Some(index) => Some(*index),
+                None => storage::get_opt::<u32, _>(db, &index_key)?,
+            };
+            let index = match existing_index {
+                Some(index) => {
+                    // Keep the subject given by the user when the transaction was created
+                    if entry.subject.is_none() {
+                        if let Some(encrypted) = storage::get_opt::<Vec<u8>, _>(
+                            db,
+                            storage::keys::account_transaction(id, account, index),
+                        )? {
+                            let old_entry: wallet::TransactionEntry = storage::decrypt(
+                                self.encrypt_salt_length,
+                                self.encrypt_iv_length,
+                                key,
+                                encrypted.as_ref(),
+                            )?;
+                            entry.subject = old_entry.subject;
+                        }
+                    }
+
+                    index
+                }
+                None => {
+                    let index = count;
+                    count += 1;
+                    new_indexes.insert(entry.transaction_id, index);
+                    storage::put(batch, index_key, &index)?;
+
+                    index
+                }
+            };
+
+            storage::put(
+                batch,
+                storage::keys::account_transaction(id, account, index),
+                &storage::encrypt(self.encrypt_iv_length, key, &entry)?,
+            )?;
+        }
+
+        storage::put(batch, count_key, &count)
+    }
+
+    /// Get a page of the transaction history of an account, most recent transactions first.
+    ///
+    /// Return the total number of indexed transactions along with the requested page.
+    pub fn get_transactions(
+        &self,
+        db: &DB,
+        id: &str,
+        key: &wallet::Key,
+        account: u32,
+        limit: u32,
+        page: u32,
+    ) -> Result<(u32, Vec<wallet::TransactionEntry>), storage::Error> {
+        let total: u32 =
+            storage::get_default(db, storage::keys::account_transactions_count(id, account))?;
+        let skip = page.saturating_mul(limit) as usize;
+        let entries = (0..total)
+            .rev()
+            .skip(skip)
+            .take(limit as usize)
+            .map(|index| {
+                let encrypted: Vec<u8> =
+                    storage::get(db, storage::keys::account_transaction(id, account, index))?;
+
+                storage::decrypt(
+                    self.encrypt_salt_length,
+                    self.encrypt_iv_length,
+                    key,
+                    encrypted.as_ref(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((total, entries))
+    }
+
     fn flush(&self, db: &DB) -> Result<(), storage::Error> {
         storage::flush(db)
     }
@@ -93,13 +413,15 @@ impl Storage {
     ) -> Result<wallet::Key, storage::Error> {
         let encrypted: Vec<u8> = storage::get_opt(db, storage::keys::wallet(id))?
             .ok_or_else(|| storage::Error::WalletNotFound)?;
-        let (_content, key) = storage::decrypt_password::<wallet::WalletContent>(
+        let (_version, key) = storage::decrypt_password::<u32>(
             self.encrypt_salt_length,
             self.encrypt_iv_length,
             self.encrypt_hash_iterations,
             password.as_bytes(),
             encrypted.as_ref(),
         )?;
+        // Fail early if the content cannot be read, e.g. because its format is not supported
+        self.get_wallet_content(db, id, &key)?;
 
         Ok(key)
     }
@@ -0,0 +1,8 @@
+mod notification;
+mod set_listener;
+mod track;
+mod untrack;
+
+pub use set_listener::*;
+pub use track::*;
+pub use untrack::*;
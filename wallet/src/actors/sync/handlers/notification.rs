@@ -0,0 +1,23 @@
+use actix::prelude::*;
+
+use witnet_data_structures::chain::Block;
+use witnet_net::client::tcp::jsonrpc as rpc_client;
+
+use crate::actors::Synchronizer;
+
+impl Handler<rpc_client::Notification> for Synchronizer {
+    type Result = <rpc_client::Notification as Message>::Result;
+
+    fn handle(&mut self, msg: rpc_client::Notification, ctx: &mut Self::Context) -> Self::Result {
+        match serde_json::from_value::<Block>(msg.0.clone()) {
+            Ok(block) => self.apply_block(block, ctx),
+            Err(err) => log::error!("Received a notification that is not a block: {}", err),
+        }
+
+        if let Some(ref listener) = self.listener {
+            if let Err(err) = listener.do_send(msg) {
+                log::error!("Couldn't forward notification: {}", err);
+            }
+        }
+    }
+}
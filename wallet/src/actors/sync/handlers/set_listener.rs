@@ -0,0 +1,29 @@
+use actix::prelude::*;
+
+use witnet_net::client::tcp::jsonrpc as rpc_client;
+
+use crate::actors::{app, Synchronizer};
+
+/// Set the recipients of the notifications received from the node and of the data requests
+/// resolved by the blocks applied to the wallets.
+pub struct SetListener(
+    pub Recipient<rpc_client::Notification>,
+    pub Recipient<app::DataRequestTallied>,
+);
+
+impl Message for SetListener {
+    type Result = ();
+}
+
+impl Handler<SetListener> for Synchronizer {
+    type Result = <SetListener as Message>::Result;
+
+    fn handle(
+        &mut self,
+        SetListener(recipient, tally_recipient): SetListener,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.listener = Some(recipient);
+        self.tally_listener = Some(tally_recipient);
+    }
+}
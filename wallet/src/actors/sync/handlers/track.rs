@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use actix::prelude::*;
+
+use crate::actors::Synchronizer;
+use crate::wallet;
+
+/// Keep an unlocked wallet in sync with the node.
+pub struct Track(pub wallet::WalletId, pub Arc<wallet::Key>);
+
+impl Message for Track {
+    type Result = ();
+}
+
+impl Handler<Track> for Synchronizer {
+    type Result = <Track as Message>::Result;
+
+    fn handle(&mut self, Track(wallet_id, key): Track, ctx: &mut Self::Context) -> Self::Result {
+        self.track(wallet_id, key, ctx)
+    }
+}
@@ -0,0 +1,19 @@
+use actix::prelude::*;
+
+use crate::actors::Synchronizer;
+use crate::wallet;
+
+/// Stop keeping a wallet in sync with the node.
+pub struct Untrack(pub wallet::WalletId);
+
+impl Message for Untrack {
+    type Result = ();
+}
+
+impl Handler<Untrack> for Synchronizer {
+    type Result = <Untrack as Message>::Result;
+
+    fn handle(&mut self, Untrack(wallet_id): Untrack, _ctx: &mut Self::Context) -> Self::Result {
+        self.untrack(&wallet_id)
+    }
+}
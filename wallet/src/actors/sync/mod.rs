@@ -0,0 +1,492 @@
+//! # Synchronization actor.
+//!
+//! See [`Synchronizer`](Synchronizer) actor for more information.
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use actix::prelude::*;
+use futures::{
+    future::{self, Either},
+    stream, Future, Stream,
+};
+use serde_json::json;
+
+use witnet_data_structures::chain::{Block, Epoch, Hash};
+use witnet_net::client::tcp::{jsonrpc as rpc_client, JsonRpcClient};
+
+use crate::actors::{app as app_actor, storage, Storage};
+use crate::{app, wallet};
+
+pub mod handlers;
+
+pub use handlers::*;
+
+/// Synchronization actor.
+///
+/// The synchronizer subscribes to the blocks consolidated by the node and applies them to every
+/// unlocked wallet, so their unspent outputs, balances and transaction history are kept up to
+/// date. Whenever a wallet is unlocked or a block does not follow the last block applied to a
+/// wallet, the wallet catches up with the node: the blocks that are no longer part of the chain
+/// of the node are reverted and the missing ones are requested to the node.
+pub struct Synchronizer {
+    db: Arc<rocksdb::DB>,
+    storage: Addr<Storage>,
+    node_client: Addr<JsonRpcClient>,
+    wallets: HashMap<wallet::WalletId, SyncedWallet>,
+    listener: Option<Recipient<rpc_client::Notification>>,
+    tally_listener: Option<Recipient<app_actor::DataRequestTallied>>,
+}
+
+/// Unlocked wallet kept in sync with the node.
+struct SyncedWallet {
+    key: Arc<wallet::Key>,
+    /// Whether the wallet is catching up with the node, in which case new blocks are not applied
+    /// to it.
+    catching_up: bool,
+}
+
+impl Synchronizer {
+    /// Start actor.
+    pub fn start(
+        db: Arc<rocksdb::DB>,
+        storage: Addr<Storage>,
+        node_client: Addr<JsonRpcClient>,
+    ) -> Addr<Self> {
+        let slf = Self {
+            db,
+            storage,
+            node_client,
+            wallets: Default::default(),
+            listener: None,
+            tally_listener: None,
+        };
+
+        slf.start()
+    }
+
+    /// Start keeping an unlocked wallet in sync with the node.
+    pub fn track(
+        &mut self,
+        wallet_id: wallet::WalletId,
+        key: Arc<wallet::Key>,
+        ctx: &mut <Self as Actor>::Context,
+    ) {
+        log::debug!("Synchronizing wallet {}", wallet_id);
+        self.wallets.insert(
+            wallet_id.clone(),
+            SyncedWallet {
+                key,
+                catching_up: false,
+            },
+        );
+        self.catch_up(wallet_id, ctx);
+    }
+
+    /// Stop keeping a wallet in sync with the node, e.g. because it has been locked.
+    pub fn untrack(&mut self, wallet_id: &wallet::WalletId) {
+        log::debug!("Wallet {} will no longer be synchronized", wallet_id);
+        self.wallets.remove(wallet_id);
+    }
+
+    /// Apply a new block of the node to all the wallets that are not catching up.
+    pub fn apply_block(&mut self, block: Block, ctx: &mut <Self as Actor>::Context) {
+        for (wallet_id, synced) in &self.wallets {
+            if synced.catching_up {
+                continue;
+            }
+            let wallet_id = wallet_id.clone();
+
+            self.storage
+                .send(storage::SyncBlock(
+                    self.db.clone(),
+                    wallet_id.clone(),
+                    synced.key.clone(),
+                    block.clone(),
+                ))
+                .map_err(app::Error::StorageFailed)
+                .and_then(|result| result.map_err(app::Error::Storage))
+                .into_actor(self)
+                .map(move |(status, tallies), slf, ctx| {
+                    notify_tallies(&slf.tally_listener, &wallet_id, tallies);
+                    match status {
+                        wallet::SyncStatus::Gap => slf.catch_up(wallet_id, ctx),
+                        status => {
+                            log::trace!("Block sync status for {}: {:?}", wallet_id, status)
+                        }
+                    }
+                })
+                .map_err(|err, _slf, _ctx| log::error!("Failed to apply block: {}", err))
+                .spawn(ctx);
+        }
+    }
+
+    /// Bring a wallet up to date with the chain of the node.
+    ///
+    /// The last blocks applied to the wallet are compared with the chain of the node in order to
+    /// find the most recent block they have in common. The wallet blocks after it are reverted
+    /// and then the blocks of the node after it are applied one by one. If there is no block in
+    /// common, the whole chain is applied again from the birth of the wallet.
+    pub fn catch_up(&mut self, wallet_id: wallet::WalletId, ctx: &mut <Self as Actor>::Context) {
+        let key = match self.wallets.get_mut(&wallet_id) {
+            Some(synced) if !synced.catching_up => {
+                synced.catching_up = true;
+                synced.key.clone()
+            }
+            _ => return,
+        };
+        let db = self.db.clone();
+        let storage_addr = self.storage.clone();
+        let node_client = self.node_client.clone();
+        let tally_listener = self.tally_listener.clone();
+
+        self.storage
+            .send(storage::GetWalletContent(
+                db.clone(),
+                wallet_id.clone(),
+                key.clone(),
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result.map_err(app::Error::Storage))
+            .and_then({
+                let node_client = node_client.clone();
+                move |content| {
+                    let beacons = content.synced_beacons();
+                    let epoch_born = content.epoch_born;
+                    let from_epoch = beacons
+                        .last()
+                        .map(|beacon| beacon.epoch)
+                        .unwrap_or(epoch_born);
+
+                    get_block_chain(&node_client, from_epoch)
+                        .map(move |chain| (beacons, epoch_born, chain))
+                }
+            })
+            .and_then({
+                let wallet_id = wallet_id.clone();
+                let storage_addr = storage_addr.clone();
+                let node_client = node_client.clone();
+                let db = db.clone();
+                let key = key.clone();
+                move |(beacons, epoch_born, chain)| match plan_catch_up(&beacons, chain) {
+                    CatchUp::Rescan => {
+                        log::warn!(
+                            "Wallet {} has no blocks in common with the node, rescanning the \
+                             chain from epoch {}",
+                            wallet_id,
+                            epoch_born
+                        );
+                        let f = storage_addr
+                            .send(storage::ResetWallet(db, wallet_id, key))
+                            .map_err(app::Error::StorageFailed)
+                            .and_then(|result| result.map_err(app::Error::Storage))
+                            .and_then(move |from_epoch| get_block_chain(&node_client, from_epoch))
+                            .map(|chain| {
+                                chain.into_iter().map(|(_, hash)| hash).collect::<Vec<_>>()
+                            });
+
+                        Either::A(f)
+                    }
+                    CatchUp::Rollback(rollback, hashes) => {
+                        let f = storage_addr
+                            .send(storage::RollbackBlocks(db, wallet_id, key, rollback))
+                            .map_err(app::Error::StorageFailed)
+                            .and_then(|result| result.map_err(app::Error::Storage))
+                            .map(move |reverted| {
+                                if reverted > 0 {
+                                    log::info!("Reverted {} blocks", reverted);
+                                }
+                                hashes
+                            });
+
+                        Either::B(f)
+                    }
+                }
+            })
+            .and_then({
+                let wallet_id = wallet_id.clone();
+                move |hashes| {
+                    stream::iter_ok(hashes)
+                        .and_then(move |hash| {
+                            let storage_addr = storage_addr.clone();
+                            let db = db.clone();
+                            let wallet_id = wallet_id.clone();
+                            let key = key.clone();
+                            let tally_listener = tally_listener.clone();
+
+                            get_block(&node_client, hash).and_then(move |block| {
+                                storage_addr
+                                    .send(storage::SyncBlock(db, wallet_id.clone(), key, block))
+                                    .map_err(app::Error::StorageFailed)
+                                    .and_then(|result| result.map_err(app::Error::Storage))
+                                    .map(move |(status, tallies)| {
+                                        notify_tallies(&tally_listener, &wallet_id, tallies);
+                                        status
+                                    })
+                            })
+                        })
+                        .filter(|status| *status == wallet::SyncStatus::Gap)
+                        .into_future()
+                        .map(|(gap, _)| gap.is_some())
+                        .map_err(|(err, _)| err)
+                }
+            })
+            .into_actor(self)
+            .then(move |result, slf, ctx| {
+                if let Some(synced) = slf.wallets.get_mut(&wallet_id) {
+                    synced.catching_up = false;
+                }
+                match result {
+                    Ok(true) => {
+                        log::debug!("Chain of the node changed while catching up, retrying");
+                        slf.catch_up(wallet_id, ctx);
+                    }
+                    Ok(false) => {
+                        log::info!("Wallet {} is synchronized", wallet_id);
+                        slf.release_resolved(wallet_id, ctx);
+                    }
+                    Err(err) => log::error!("Failed to synchronize wallet {}: {}", wallet_id, err),
+                }
+
+                fut::ok(())
+            })
+            .spawn(ctx);
+    }
+
+    /// Release the values locked by the data requests of a synchronized wallet that the node no
+    /// longer has pending, in case their tallies were missed.
+    pub fn release_resolved(
+        &mut self,
+        wallet_id: wallet::WalletId,
+        ctx: &mut <Self as Actor>::Context,
+    ) {
+        let key = match self.wallets.get(&wallet_id) {
+            Some(synced) => synced.key.clone(),
+            None => return,
+        };
+        let db = self.db.clone();
+        let storage_addr = self.storage.clone();
+        let node_client = self.node_client.clone();
+
+        self.storage
+            .send(storage::GetWalletContent(
+                db.clone(),
+                wallet_id.clone(),
+                key.clone(),
+            ))
+            .map_err(app::Error::StorageFailed)
+            .and_then(|result| result.map_err(app::Error::Storage))
+            .and_then(move |content| {
+                stream::iter_ok(content.locked_data_requests())
+                    .and_then(move |data_request| {
+                        is_data_request_pending(&node_client, data_request)
+                            .map(move |pending| (data_request, pending))
+                    })
+                    .filter(|(_, pending)| !pending)
+                    .map(|(data_request, _)| data_request)
+                    .collect()
+            })
+            .and_then(move |resolved| {
+                if resolved.is_empty() {
+                    return Either::A(future::ok(0));
+                }
+                let f = storage_addr
+                    .send(storage::ModifyWalletContent(
+                        db,
+                        wallet_id,
+                        key,
+                        move |content: &mut wallet::WalletContent| -> Result<usize, app::Error> {
+                            Ok(content.release_locked(&resolved))
+                        },
+                    ))
+                    .map_err(app::Error::StorageFailed)
+                    .and_then(|result| result);
+
+                Either::B(f)
+            })
+            .into_actor(self)
+            .map(|released, _slf, _ctx| {
+                if released > 0 {
+                    log::info!("Released the values locked by {} data requests", released);
+                }
+            })
+            .map_err(|err, _slf, _ctx| log::error!("Failed to release locked values: {}", err))
+            .spawn(ctx);
+    }
+}
+
+impl Actor for Synchronizer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let recipient = ctx.address().recipient();
+        let request = rpc_client::Request::method("witnet_subscribe").value(json!(["newBlocks"]));
+        self.node_client
+            .do_send(rpc_client::SetSubscriber(recipient, request));
+    }
+}
+
+/// How a wallet catches up with the chain of the node.
+#[derive(Debug, PartialEq)]
+enum CatchUp {
+    /// Revert the given number of blocks from the wallet and then apply the blocks with the
+    /// given hashes.
+    Rollback(usize, Vec<Hash>),
+    /// Forget everything the wallet learned from the chain and apply it again from the birth of
+    /// the wallet.
+    Rescan,
+}
+
+/// Compare the blocks that can still be reverted from a wallet, most recent first, with the
+/// chain of the node starting at the oldest of them.
+///
+/// If none of those blocks is part of the chain of the node, it changed further back than what
+/// can be reverted.
+fn plan_catch_up(beacons: &[wallet::SyncedBeacon], chain: Vec<(Epoch, Hash)>) -> CatchUp {
+    let ancestor = beacons
+        .iter()
+        .position(|beacon| chain.contains(&(beacon.epoch, beacon.block_hash)));
+    if ancestor.is_none() && !beacons.is_empty() {
+        return CatchUp::Rescan;
+    }
+    let from_epoch = ancestor.map(|index| beacons[index].epoch);
+    let hashes = chain
+        .into_iter()
+        .filter(|(epoch, _)| from_epoch.map_or(true, |from| *epoch > from))
+        .map(|(_, hash)| hash)
+        .collect();
+
+    CatchUp::Rollback(ancestor.unwrap_or(0), hashes)
+}
+
+/// Let the listener know about the data requests of a wallet resolved by a block.
+fn notify_tallies(
+    listener: &Option<Recipient<app_actor::DataRequestTallied>>,
+    wallet_id: &wallet::WalletId,
+    tallies: Vec<wallet::DataRequestTally>,
+) {
+    if let Some(listener) = listener {
+        for tally in tallies {
+            let msg = app_actor::DataRequestTallied(wallet_id.clone(), tally);
+            if let Err(err) = listener.do_send(msg) {
+                log::error!("Couldn't notify data request tally: {}", err);
+            }
+        }
+    }
+}
+
+/// Send a Json-RPC request to the node.
+fn send_to_node(
+    node_client: &Addr<JsonRpcClient>,
+    req: rpc_client::Request,
+) -> impl Future<Item = serde_json::Value, Error = app::Error> {
+    node_client
+        .send(req)
+        .map_err(app::Error::RequestFailedToSend)
+        .and_then(|result| result.map_err(app::Error::RequestFailed))
+}
+
+/// Get the epochs and hashes of the blocks of the node's chain, starting at `epoch`.
+fn get_block_chain(
+    node_client: &Addr<JsonRpcClient>,
+    epoch: Epoch,
+) -> impl Future<Item = Vec<(Epoch, Hash)>, Error = app::Error> {
+    let req = rpc_client::Request::method("getBlockChain").value(json!({ "epoch": epoch }));
+
+    send_to_node(node_client, req).and_then(|value| {
+        serde_json::from_value::<Vec<(Epoch, String)>>(value)
+            .map_err(|err| app::Error::NodeResponse(err.to_string()))
+            .and_then(|chain| {
+                chain
+                    .into_iter()
+                    .map(|(epoch, hash)| {
+                        Hash::from_str(&hash)
+                            .map(|hash| (epoch, hash))
+                            .map_err(|err| app::Error::NodeResponse(err.to_string()))
+                    })
+                    .collect()
+            })
+    })
+}
+
+/// Whether the node has a data request that has not been tallied yet.
+fn is_data_request_pending(
+    node_client: &Addr<JsonRpcClient>,
+    data_request: Hash,
+) -> impl Future<Item = bool, Error = app::Error> {
+    let req =
+        rpc_client::Request::method("dataRequestState").value(json!([data_request.to_string()]));
+
+    send_to_node(node_client, req).map(|state| !state.is_null())
+}
+
+/// Get a block of the node's chain by its hash.
+fn get_block(
+    node_client: &Addr<JsonRpcClient>,
+    hash: Hash,
+) -> impl Future<Item = Block, Error = app::Error> {
+    let req = rpc_client::Request::method("getBlock").value(json!([hash.to_string()]));
+
+    send_to_node(node_client, req).and_then(|value| {
+        serde_json::from_value(value).map_err(|err| app::Error::NodeResponse(err.to_string()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(byte: u8) -> Hash {
+        Hash::SHA256([byte; 32])
+    }
+
+    fn beacon(epoch: Epoch, byte: u8) -> wallet::SyncedBeacon {
+        wallet::SyncedBeacon {
+            epoch,
+            block_hash: hash(byte),
+        }
+    }
+
+    #[test]
+    fn catch_up_from_scratch() {
+        let chain = vec![(1, hash(1)), (2, hash(2))];
+
+        assert_eq!(
+            plan_catch_up(&[], chain),
+            CatchUp::Rollback(0, vec![hash(1), hash(2)])
+        );
+    }
+
+    #[test]
+    fn catch_up_with_new_blocks() {
+        let beacons = [beacon(2, 2), beacon(1, 1)];
+
+        assert_eq!(
+            plan_catch_up(&beacons, vec![(1, hash(1)), (2, hash(2))]),
+            CatchUp::Rollback(0, vec![])
+        );
+        assert_eq!(
+            plan_catch_up(&beacons, vec![(1, hash(1)), (2, hash(2)), (3, hash(3))]),
+            CatchUp::Rollback(0, vec![hash(3)])
+        );
+    }
+
+    #[test]
+    fn catch_up_after_reorg() {
+        let beacons = [beacon(3, 3), beacon(2, 2), beacon(1, 1)];
+        let chain = vec![(1, hash(1)), (2, hash(22)), (4, hash(44))];
+
+        assert_eq!(
+            plan_catch_up(&beacons, chain),
+            CatchUp::Rollback(2, vec![hash(22), hash(44)])
+        );
+    }
+
+    #[test]
+    fn catch_up_after_deep_reorg() {
+        let beacons = [beacon(2, 2), beacon(1, 1)];
+        let chain = vec![(1, hash(11)), (2, hash(22))];
+
+        assert_eq!(plan_catch_up(&beacons, chain), CatchUp::Rescan);
+    }
+}
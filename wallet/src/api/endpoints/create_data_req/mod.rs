@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use witnet_data_structures::{chain::Hash, transaction::DRTransaction};
+
+use crate::{app, wallet};
+
+mod validation;
+
+pub use validation::*;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDataReqRequest {
+    pub(crate) session_id: app::SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+    pub(crate) request: wallet::RADRequest,
+    pub(crate) witnesses: u16,
+    #[serde(default)]
+    pub(crate) backup_witnesses: u16,
+    pub(crate) reward: u64,
+    pub(crate) fee: u64,
+    #[serde(default)]
+    pub(crate) time_lock: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDataReqResponse {
+    pub(crate) transaction_id: Hash,
+    pub(crate) transaction: DRTransaction,
+}
@@ -0,0 +1,52 @@
+use witnet_validations::validations::validate_rad_request;
+
+use super::*;
+use crate::{app, validation, wallet};
+
+/// Validate `CreateDataReqRequest`.
+///
+/// To be valid it must pass these checks:
+/// - the scripts of the RAD request are valid
+/// - there is at least one witness
+/// - the reward is greater than 0 and the total value of the data request does not overflow
+pub fn validate_create_data_req(
+    req: CreateDataReqRequest,
+) -> Result<app::CreateDataReq, validation::Error> {
+    let session_id = req.session_id;
+    let wallet_id = req.wallet_id;
+    let request = validate_rad_request(&req.request)
+        .map_err(|err| validation::error("request", format!("{}", err)))
+        .map(|_| req.request);
+    let witnesses = if req.witnesses < 1 {
+        Err(validation::error(
+            "witnesses",
+            "At least one witness is required.",
+        ))
+    } else {
+        Ok(req.witnesses)
+    };
+    let reward = if req.reward < 1 {
+        Err(validation::error(
+            "reward",
+            "Reward must be greater than 0.",
+        ))
+    } else {
+        Ok(req.reward)
+    };
+    let (backup_witnesses, fee, time_lock) = (req.backup_witnesses, req.fee, req.time_lock);
+
+    let params = validation::combine(witnesses, reward, |witnesses, reward| (witnesses, reward));
+
+    validation::combine(request, params, |request, (witnesses, reward)| {
+        (request, witnesses, reward)
+    })
+    .and_then(|(request, witnesses, reward)| {
+        wallet::data_request_output(request, witnesses, backup_witnesses, reward, fee, time_lock)
+            .ok_or_else(|| validation::error("reward", "Data request value overflows."))
+    })
+    .map(|dr_output| app::CreateDataReq {
+        session_id,
+        wallet_id,
+        dr_output,
+    })
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{app, wallet};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBalanceRequest {
+    pub(crate) session_id: app::SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBalanceResponse {
+    pub(crate) total: wallet::Balance,
+    pub(crate) accounts: Vec<wallet::Balance>,
+}
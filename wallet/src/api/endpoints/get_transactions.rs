@@ -1,8 +1,19 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::{app, wallet};
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetTransactionsRequest {
-    pub wallet_id: String,
-    pub limit: u32,
-    pub page: u32,
+    pub(crate) session_id: app::SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+    pub(crate) limit: u32,
+    pub(crate) page: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionsResponse {
+    pub(crate) total: u32,
+    pub(crate) transactions: Vec<wallet::TransactionEntry>,
 }
@@ -4,6 +4,7 @@ pub mod create_mnemonics;
 pub mod create_wallet;
 pub mod forward;
 pub mod generate_address;
+pub mod get_balance;
 pub mod get_transactions;
 pub mod get_wallet_infos;
 pub mod import_seed;
@@ -21,6 +22,7 @@ pub use create_mnemonics::*;
 pub use create_wallet::*;
 pub use forward::*;
 pub use generate_address::*;
+pub use get_balance::*;
 pub use get_transactions::*;
 pub use get_wallet_infos::*;
 pub use import_seed::*;
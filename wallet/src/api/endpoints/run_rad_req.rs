@@ -7,9 +7,18 @@ use crate::wallet;
 pub struct RunRadReqRequest {
     #[serde(rename = "radRequest")]
     pub rad_request: wallet::RADRequest,
+    /// Whether to record the execution of every call of the scripts of the request.
+    #[serde(default)]
+    pub trace: bool,
 }
 
 #[derive(Debug, Serialize)]
 pub struct RunRadReqResponse {
-    pub result: rad::types::RadonTypes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<rad::types::RadonTypes>,
+    /// Error that stopped a traced execution of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<rad::RADRequestTrace>,
 }
@@ -1,4 +1,19 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use witnet_data_structures::{chain::Hash, transaction::DRTransaction};
+
+use crate::{app, wallet};
 
 #[derive(Debug, Deserialize)]
-pub struct SendDataReqRequest;
+#[serde(rename_all = "camelCase")]
+pub struct SendDataReqRequest {
+    pub(crate) session_id: app::SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+    pub(crate) transaction: DRTransaction,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendDataReqResponse {
+    pub(crate) transaction_id: Hash,
+}
@@ -1,10 +1,22 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use witnet_data_structures::chain::{Hash, PublicKeyHash};
+
+use crate::{app, wallet};
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SendVttRequest {
-    pub wallet_id: String,
-    pub to_address: Vec<u8>,
-    pub amount: u64,
-    pub fee: u64,
-    pub subject: String,
+    pub(crate) session_id: app::SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+    pub(crate) to_address: PublicKeyHash,
+    pub(crate) amount: u64,
+    pub(crate) fee: u64,
+    pub(crate) subject: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendVttResponse {
+    pub(crate) transaction_id: Hash,
 }
@@ -115,6 +115,7 @@ where
         ("Unlock-Wallet", "unlockWallet", api::UnlockWalletRequest),
         ("Lock-Wallet", "lockWallet", api::LockWalletRequest),
         ("Close-Session", "closeSession", api::CloseSessionRequest),
+        ("Get-Balance", "getBalance", api::GetBalanceRequest),
         (
             "Get-Transactions",
             "getTransactions",
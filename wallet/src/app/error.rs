@@ -15,6 +15,8 @@ pub enum Error {
     RequestFailedToSend(#[cause] actix::MailboxError),
     #[fail(display = "Request failed with an error: {}", _0)]
     RequestFailed(#[cause] tcp::Error),
+    #[fail(display = "Unexpected response from the node: {}", _0)]
+    NodeResponse(String),
     #[fail(display = "Could not subscribe: {}", _0)]
     SubscribeFailed(&'static str),
     #[fail(display = "Could not unsubscribe: {}", _0)]
@@ -35,4 +37,14 @@ pub enum Error {
     UnknownSession,
     #[fail(display = "Session does not have access to wallet: {}", _0)]
     WrongWallet(wallet::WalletId),
+    #[fail(display = "Wallet {} has no accounts", _0)]
+    UnknownAccount(wallet::WalletId),
+    #[fail(display = "Could not build transaction: {}", _0)]
+    BuildTransaction(#[cause] wallet::BuildTransactionError),
+}
+
+impl From<storage::Error> for Error {
+    fn from(err: storage::Error) -> Self {
+        Error::Storage(err)
+    }
 }
@@ -2,8 +2,11 @@
 use std::sync::Arc;
 
 use witnet_crypto::mnemonic::{Length, Mnemonic};
+use witnet_data_structures::chain::DataRequestOutput;
 use witnet_protected::ProtectedString;
 
+use crate::wallet;
+
 pub mod error;
 
 pub use error::Error;
@@ -15,6 +18,12 @@ pub struct CreateWallet {
     pub(crate) seed_source: SeedSource,
 }
 
+pub struct CreateDataReq {
+    pub(crate) session_id: SessionId,
+    pub(crate) wallet_id: wallet::WalletId,
+    pub(crate) dr_output: DataRequestOutput,
+}
+
 pub struct CreateMnemonics {
     pub(crate) length: Length,
 }
@@ -13,6 +13,7 @@
 #![deny(non_snake_case)]
 #![deny(unused_mut)]
 #![deny(missing_docs)]
+use std::sync::Arc;
 use std::time::Duration;
 
 use actix::prelude::*;
@@ -22,6 +23,7 @@ use jsonrpc_pubsub as pubsub;
 
 use witnet_config::config::Config;
 use witnet_net::{client::tcp::JsonRpcClient, server::ws::Server};
+use witnet_rad::RetrievalLimits;
 
 mod actors;
 mod api;
@@ -32,6 +34,8 @@ mod storage;
 mod validation;
 mod wallet;
 
+pub use actors::rad_executor::retrieval_context;
+
 /// Run the Witnet wallet application.
 pub fn run(conf: Config) -> Result<(), Error> {
     let session_expires_in = Duration::from_secs(conf.wallet.session_expires_in);
@@ -58,12 +62,16 @@ pub fn run(conf: Config) -> Result<(), Error> {
     let id_hash_iterations = conf.wallet.id_hash_iterations;
     let id_hash_function = conf.wallet.id_hash_function;
 
+    // Limits to the requests made to HTTP sources used by the RadExecutor actor
+    let retrieval_limits = RetrievalLimits::from(&conf.rad);
+
     let node_client = node_url.clone().map_or_else(
         || Ok(None),
         |url| JsonRpcClient::start(url.as_ref()).map(Some),
     )?;
     let db = rocksdb::DB::open(&rocksdb_opts, db_path.join(db_file_name))
         .map_err(|e| failure::format_err!("{}", e))?;
+    let db = Arc::new(db);
 
     let system = System::new("witnet-wallet");
     let storage = actors::Storage::start(
@@ -77,12 +85,16 @@ pub fn run(conf: Config) -> Result<(), Error> {
         id_hash_iterations,
         id_hash_function,
     );
-    let rad_executor = actors::RadExecutor::start();
+    let rad_executor = actors::RadExecutor::start(retrieval_limits);
+    let synchronizer = node_client
+        .clone()
+        .map(|client| actors::Synchronizer::start(db.clone(), storage.clone(), client));
     let app = actors::App::start(
         db,
         storage,
         crypto,
         rad_executor,
+        synchronizer,
         node_client,
         session_expires_in,
     );
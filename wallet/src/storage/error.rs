@@ -1,7 +1,7 @@
 //! # Error type for the Storage actor.
 use failure::Fail;
 
-use witnet_crypto::cipher;
+use witnet_crypto::{cipher, key::KeyDerivationError};
 
 /// Error type for errors that may originate in the Storage actor.
 #[derive(Debug, Fail)]
@@ -18,6 +18,12 @@ pub enum Error {
     Cipher(#[cause] cipher::Error),
     #[fail(display = "No wallet found with the given ID")]
     WalletNotFound,
+    #[fail(display = "encrypted value is too short: {} bytes", _0)]
+    EncryptedTooShort(usize),
+    #[fail(display = "unsupported wallet format version: {}", _0)]
+    UnsupportedWalletVersion(u32),
     #[fail(display = "Wrong Password")]
     WrongPassword(#[cause] cipher::Error),
+    #[fail(display = "Key derivation failed: {}", _0)]
+    KeyDerivation(#[cause] KeyDerivationError),
 }
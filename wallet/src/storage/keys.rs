@@ -1,3 +1,5 @@
+use witnet_data_structures::chain::Hash;
+
 #[inline]
 pub fn wallets() -> &'static str {
     "wallets"
@@ -12,3 +14,18 @@ pub fn wallet_info(id: &str) -> String {
 pub fn wallet(id: &str) -> String {
     format!("{}-wallet", id)
 }
+
+#[inline]
+pub fn account_transactions_count(id: &str, account: u32) -> String {
+    format!("{}-{}-transactions-count", id, account)
+}
+
+#[inline]
+pub fn account_transaction(id: &str, account: u32, index: u32) -> String {
+    format!("{}-{}-transaction-{}", id, account, index)
+}
+
+#[inline]
+pub fn account_transaction_index(id: &str, account: u32, transaction_id: &Hash) -> String {
+    format!("{}-{}-transaction-{}-index", id, account, transaction_id)
+}
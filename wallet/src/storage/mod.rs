@@ -116,10 +116,7 @@ pub fn decrypt_password<T>(
 where
     T: serde::de::DeserializeOwned,
 {
-    let len = encrypted.len();
-    let iv = &encrypted[0..encrypt_iv_length];
-    let data = &encrypted[encrypt_iv_length..len - encrypt_salt_length];
-    let salt = &encrypted[len - encrypt_salt_length..];
+    let (iv, data, salt) = split_encrypted(encrypt_salt_length, encrypt_iv_length, encrypted)?;
     let key = gen_key_salt(encrypt_hash_iterations, password, salt.to_vec())?;
     let value = cipher::decrypt_aes_cbc(&key.secret.as_ref(), data, iv)
         .map_err(error::Error::WrongPassword)
@@ -128,6 +125,64 @@ where
     Ok((value, key))
 }
 
+/// Decrypt the given value with an already generated key.
+pub fn decrypt<T>(
+    encrypt_salt_length: usize,
+    encrypt_iv_length: usize,
+    key: &Key,
+    encrypted: &[u8],
+) -> Result<T, error::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let (iv, data, _salt) = split_encrypted(encrypt_salt_length, encrypt_iv_length, encrypted)?;
+    let bytes =
+        cipher::decrypt_aes_cbc(key.secret.as_ref(), data, iv).map_err(error::Error::Cipher)?;
+
+    deserialize(bytes.as_ref())
+}
+
+/// Decrypt the content of a wallet with an already generated key, migrating it if it was saved
+/// with an older format.
+pub fn decrypt_wallet_content(
+    encrypt_salt_length: usize,
+    encrypt_iv_length: usize,
+    key: &Key,
+    encrypted: &[u8],
+) -> Result<wallet::WalletContent, error::Error> {
+    let (iv, data, _salt) = split_encrypted(encrypt_salt_length, encrypt_iv_length, encrypted)?;
+    let bytes =
+        cipher::decrypt_aes_cbc(key.secret.as_ref(), data, iv).map_err(error::Error::Cipher)?;
+
+    deserialize_wallet_content(bytes.as_ref())
+}
+
+/// Deserialize the content of a wallet according to the version of its format, which is always
+/// its first field.
+fn deserialize_wallet_content(bytes: &[u8]) -> Result<wallet::WalletContent, error::Error> {
+    match deserialize::<u32>(bytes)? {
+        wallet::WalletContent::VERSION => deserialize(bytes),
+        1 => deserialize::<wallet::WalletContentV1>(bytes).map(wallet::WalletContent::from),
+        version => Err(error::Error::UnsupportedWalletVersion(version)),
+    }
+}
+
+/// Split an encrypted value into its initialization vector, its encrypted data and its salt.
+fn split_encrypted(
+    encrypt_salt_length: usize,
+    encrypt_iv_length: usize,
+    encrypted: &[u8],
+) -> Result<(&[u8], &[u8], &[u8]), error::Error> {
+    let len = encrypted.len();
+    if len < encrypt_iv_length + encrypt_salt_length {
+        return Err(error::Error::EncryptedTooShort(len));
+    }
+    let (iv, rest) = encrypted.split_at(encrypt_iv_length);
+    let (data, salt) = rest.split_at(len - encrypt_iv_length - encrypt_salt_length);
+
+    Ok((iv, data, salt))
+}
+
 /// Serialize value to binary.
 pub fn serialize<T>(value: &T) -> Result<Vec<u8>, error::Error>
 where
@@ -230,3 +285,80 @@ where
 
     Ok(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use witnet_crypto::key::{ExtendedSK, MasterKeyGen};
+
+    use super::*;
+
+    #[test]
+    fn decrypt_encrypted_value() {
+        let key = gen_key(32, 1, b"password").unwrap();
+        let encrypted = encrypt(16, &key, &"value".to_string()).unwrap();
+
+        let value: String = decrypt(32, 16, &key, &encrypted).unwrap();
+        assert_eq!(value, "value");
+
+        let (value, _key): (String, Key) =
+            decrypt_password(32, 16, 1, b"password", &encrypted).unwrap();
+        assert_eq!(value, "value");
+    }
+
+    #[test]
+    fn decrypt_truncated_value() {
+        let key = gen_key(32, 1, b"password").unwrap();
+        let encrypted = encrypt(16, &key, &"value".to_string()).unwrap();
+
+        for len in &[0, 15, 47] {
+            let truncated = &encrypted[..*len];
+            match decrypt::<String>(32, 16, &key, truncated) {
+                Err(error::Error::EncryptedTooShort(l)) => assert_eq!(l, *len),
+                x => panic!("{:?}", x),
+            }
+            match decrypt_password::<String>(32, 16, 1, b"password", truncated) {
+                Err(error::Error::EncryptedTooShort(l)) => assert_eq!(l, *len),
+                x => panic!("{:?}", x.map(|(value, _key)| value)),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_wallet_content_v1() {
+        let master_key = MasterKeyGen::new([0; 32]).generate().unwrap();
+        let path = wallet::KeyPath::master()
+            .hardened(3)
+            .hardened(4919)
+            .hardened(0);
+        let keychain = |index| {
+            let final_keys: Vec<(wallet::KeyPath, ExtendedSK, String, Vec<()>, Vec<()>)> =
+                Vec::new();
+            (path.clone().index(index), final_keys)
+        };
+        let v1 = (
+            1u32,
+            master_key,
+            wallet::Wip::Wip3,
+            0x8000_0003u32,
+            10u32,
+            0u32,
+            vec![((keychain(1), keychain(0), keychain(2)), 0u64)],
+        );
+
+        let content = deserialize_wallet_content(&serialize(&v1).unwrap()).unwrap();
+        assert_eq!(content.version, wallet::WalletContent::VERSION);
+        assert_eq!(content.epoch_born, 10);
+        assert_eq!(content.accounts.len(), 1);
+
+        let content = deserialize_wallet_content(&serialize(&content).unwrap()).unwrap();
+        assert_eq!(content.version, wallet::WalletContent::VERSION);
+    }
+
+    #[test]
+    fn deserialize_unsupported_wallet_content() {
+        match deserialize_wallet_content(&serialize(&99u32).unwrap()) {
+            Err(error::Error::UnsupportedWalletVersion(99)) => (),
+            x => panic!("{:?}", x.map(|content| content.version)),
+        }
+    }
+}
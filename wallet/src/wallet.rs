@@ -1,10 +1,20 @@
 //! # Wallet-specific data types
 
-use jsonrpc_core::Value;
+use std::collections::VecDeque;
+
+use failure::Fail;
 use serde::{Deserialize, Serialize};
 
-use witnet_crypto::key::ExtendedSK;
+use witnet_crypto::key::{ChildNumber, ExtendedSK, KeyDerivationError, SignContext, PK};
 pub use witnet_data_structures::chain::RADRequest;
+use witnet_data_structures::chain::{
+    Block, BlockTransactions, DataRequestOutput, DataRequestReport, DataRequestStage,
+    DataRequestState, Epoch, Hash, Hashable, Input, OutputPointer, PublicKey, PublicKeyHash,
+    ValueTransferOutput,
+};
+use witnet_data_structures::transaction::{
+    DRTransactionBody, TallyTransaction, Transaction, VTTransactionBody,
+};
 use witnet_protected::Protected;
 
 pub type WalletId = String;
@@ -37,11 +47,22 @@ pub struct WalletContent {
     pub(crate) epoch_born: u32,
     pub(crate) epoch_last: u32,
     pub(crate) accounts: Vec<Account>,
+    /// Beacon of the last block of the node's chain applied to this wallet.
+    pub(crate) last_beacon: Option<SyncedBeacon>,
+    /// Changes made by the most recently applied blocks, oldest first, so they can be reverted
+    /// if the node reorganizes its chain.
+    pub(crate) synced_blocks: VecDeque<SyncedBlock>,
 }
 
 impl WalletContent {
-    const VERSION: u32 = 1;
+    /// Version of the format in which the content of a wallet is saved.
+    ///
+    /// Version 1 wallets could not follow the node's chain yet, see
+    /// [`WalletContentV1`](WalletContentV1).
+    pub(crate) const VERSION: u32 = 2;
     const PURPOSE: u32 = 0x8000_0003;
+    /// Maximum number of applied blocks that can be reverted.
+    const MAX_SYNCED_BLOCKS: usize = 100;
 
     pub fn new(master_key: MasterKey, key_spec: Wip, accounts: Vec<Account>) -> Self {
         Self {
@@ -52,14 +73,383 @@ impl WalletContent {
             purpose: Self::PURPOSE,
             epoch_born: 0,
             epoch_last: 0,
+            last_beacon: None,
+            synced_blocks: VecDeque::new(),
+        }
+    }
+
+    /// Balance of each account of this wallet.
+    pub fn balances(&self) -> Vec<Balance> {
+        self.accounts.iter().map(Account::balance).collect()
+    }
+
+    /// Get a mutable reference to the account with the given index.
+    pub fn account_mut(&mut self, index: usize) -> Option<&mut Account> {
+        self.accounts.get_mut(index)
+    }
+
+    /// Data requests of the accounts of this wallet whose value is locked until their tally.
+    pub fn locked_data_requests(&self) -> Vec<Hash> {
+        self.accounts
+            .iter()
+            .flat_map(|account| account.locked.iter())
+            .map(|locked| locked.data_request)
+            .collect()
+    }
+
+    /// Release the values locked by the given data requests, e.g. because the node no longer
+    /// has them pending although their tally was never applied to this wallet.
+    ///
+    /// The data requests are still followed, so their tally can resolve them if it shows up.
+    /// Return the number of released values.
+    pub fn release_locked(&mut self, data_requests: &[Hash]) -> usize {
+        let mut released = 0;
+        for account in &mut self.accounts {
+            let count = account.locked.len();
+            account
+                .locked
+                .retain(|locked| !data_requests.contains(&locked.data_request));
+            released += count - account.locked.len();
+        }
+
+        released
+    }
+
+    /// Beacons of the blocks that can still be reverted, most recent first.
+    pub fn synced_beacons(&self) -> Vec<SyncedBeacon> {
+        self.synced_blocks
+            .iter()
+            .rev()
+            .map(|synced| synced.beacon)
+            .collect()
+    }
+
+    /// Apply a block of the node's chain to the accounts of this wallet: add the outputs paying
+    /// to their addresses and mark as spent the outputs consumed by the block inputs.
+    ///
+    /// The block is only applied if it follows the last applied one. Return the history entries
+    /// of the transactions touching each account, along with the index of the account, and the
+    /// data requests of the accounts resolved by the tallies of the block.
+    ///
+    /// New keys are derived as the addresses of the accounts get used, so there are always enough
+    /// unused keys to find the outputs of a wallet restored from its seed.
+    pub fn apply_block(
+        &mut self,
+        block: &Block,
+    ) -> Result<
+        (
+            SyncStatus,
+            Vec<(u32, TransactionEntry)>,
+            Vec<DataRequestTally>,
+        ),
+        KeyDerivationError,
+    > {
+        let block_hash = block.hash();
+        let epoch = block.block_header.beacon.checkpoint;
+
+        if self
+            .synced_blocks
+            .iter()
+            .any(|synced| synced.beacon.block_hash == block_hash)
+        {
+            return Ok((SyncStatus::AlreadySynced, Vec::new(), Vec::new()));
+        }
+        if let Some(last_beacon) = self.last_beacon {
+            if last_beacon.block_hash != block.block_header.beacon.hash_prev_block {
+                return Ok((SyncStatus::Gap, Vec::new(), Vec::new()));
+            }
+        }
+
+        let master_key = &self.master_key;
+        for account in &mut self.accounts {
+            account.keychains.discover(master_key)?;
+        }
+
+        let beacon = SyncedBeacon { epoch, block_hash };
+        let mut synced = SyncedBlock {
+            beacon,
+            changes: Vec::new(),
+            stages: Vec::new(),
+        };
+        let mut entries = Vec::new();
+        let mut tallies = Vec::new();
+
+        for transaction in block_transactions(&block.txns) {
+            let transaction_id = transaction.hash();
+
+            for (index, account) in self.accounts.iter_mut().enumerate() {
+                let entry = account.transaction_entry(&transaction, Some(epoch));
+                if entry.is_none() && !account.follows(&transaction) {
+                    continue;
+                }
+                let changes = account.apply_transaction(&transaction, epoch);
+                account.keychains.discover(master_key)?;
+
+                if let (Transaction::Tally(tally), Some((data_request, Some(tracked)))) =
+                    (&transaction, &changes.tracked)
+                {
+                    if let Some(report) = tracked.resolve(tally) {
+                        tallies.push(DataRequestTally {
+                            account: index as u32,
+                            data_request: *data_request,
+                            epoch,
+                            report,
+                        });
+                    }
+                }
+                synced.changes.push((index as u32, transaction_id, changes));
+                entries.extend(entry.map(|entry| (index as u32, entry)));
+            }
+        }
+
+        for (index, account) in self.accounts.iter_mut().enumerate() {
+            for (data_request, stage) in account.update_data_request_stages() {
+                synced.stages.push((index as u32, data_request, stage));
+            }
+            account.balance = account.available_balance();
+        }
+        self.last_beacon = Some(beacon);
+        self.epoch_last = epoch;
+        self.synced_blocks.push_back(synced);
+        if self.synced_blocks.len() > Self::MAX_SYNCED_BLOCKS {
+            self.synced_blocks.pop_front();
+        }
+
+        Ok((SyncStatus::Applied, entries, tallies))
+    }
+
+    /// Revert the changes made by the last applied block, if it is still known.
+    ///
+    /// Return the transactions of the reverted block that touched the accounts of this wallet,
+    /// along with the index of the account.
+    pub fn rollback_block(&mut self) -> Option<Vec<(u32, Hash)>> {
+        let synced = self.synced_blocks.pop_back()?;
+        let mut transactions = Vec::with_capacity(synced.changes.len());
+
+        for (index, data_request, stage) in synced.stages {
+            if let Some(account) = self.accounts.get_mut(index as usize) {
+                account.revert_data_request_stage(&data_request, stage);
+            }
+        }
+        for (index, transaction_id, changes) in synced.changes.into_iter().rev() {
+            if let Some(account) = self.accounts.get_mut(index as usize) {
+                account.revert_transaction(changes);
+            }
+            transactions.push((index, transaction_id));
+        }
+        for account in &mut self.accounts {
+            account.balance = account.available_balance();
+        }
+        self.last_beacon = self.synced_blocks.back().map(|previous| previous.beacon);
+        self.epoch_last = self.last_beacon.map(|beacon| beacon.epoch).unwrap_or(0);
+
+        Some(transactions)
+    }
+
+    /// Forget everything learned from the node's chain, so it can be applied again from the
+    /// birth of the wallet, e.g. if the node reorganizes more blocks than can be reverted.
+    ///
+    /// Transactions sent from the wallet that have not been included in a block yet are
+    /// forgotten as well.
+    pub fn reset(&mut self) {
+        for account in &mut self.accounts {
+            account.reset();
+        }
+        self.last_beacon = None;
+        self.epoch_last = self.epoch_born;
+        self.synced_blocks.clear();
+    }
+}
+
+/// Content of a wallet saved with version 1 of the format.
+#[derive(Deserialize)]
+pub struct WalletContentV1 {
+    _version: u32,
+    master_key: MasterKey,
+    key_spec: Wip,
+    purpose: u32,
+    epoch_born: u32,
+    epoch_last: u32,
+    accounts: Vec<AccountV1>,
+}
+
+#[derive(Deserialize)]
+struct AccountV1 {
+    keychains: KeyChainsV1,
+    _balance: u64,
+}
+
+#[derive(Deserialize)]
+struct KeyChainsV1 {
+    external: KeyChainV1,
+    internal: KeyChainV1,
+    rad: KeyChainV1,
+}
+
+#[derive(Deserialize)]
+struct KeyChainV1 {
+    path: KeyPath,
+    /// Path, key, address and the placeholders for the outputs of each key.
+    _final_keys: Vec<(KeyPath, ExtendedSK, String, Vec<()>, Vec<()>)>,
+}
+
+impl From<WalletContentV1> for WalletContent {
+    /// Version 1 wallets could not receive outputs, so only the paths of their keychains are
+    /// kept. Their keys are derived again the first time a block is applied to the wallet.
+    fn from(old: WalletContentV1) -> Self {
+        let accounts = old
+            .accounts
+            .into_iter()
+            .map(|AccountV1 { keychains, .. }| {
+                Account::new(KeyChains {
+                    external: KeyChain::new(keychains.external.path),
+                    internal: KeyChain::new(keychains.internal.path),
+                    rad: KeyChain::new(keychains.rad.path),
+                })
+            })
+            .collect();
+
+        Self {
+            version: Self::VERSION,
+            master_key: old.master_key,
+            key_spec: old.key_spec,
+            purpose: old.purpose,
+            epoch_born: old.epoch_born,
+            epoch_last: old.epoch_last,
+            accounts,
+            last_beacon: None,
+            synced_blocks: VecDeque::new(),
         }
     }
 }
 
+/// Epoch and hash of a block of the node's chain applied to a wallet.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncedBeacon {
+    pub epoch: Epoch,
+    pub block_hash: Hash,
+}
+
+/// Changes made to a wallet by a block of the node's chain.
+#[derive(Serialize, Deserialize)]
+pub struct SyncedBlock {
+    /// Epoch and hash of the block.
+    beacon: SyncedBeacon,
+    /// Changes made by each transaction of the block touching the wallet, along with the index
+    /// of the account and the transaction id.
+    changes: Vec<(u32, Hash, TransactionChanges)>,
+    /// Data requests of the accounts whose stage advanced at the end of the block, along with the
+    /// index of the account and their previous stage.
+    stages: Vec<(u32, Hash, DataRequestStage)>,
+}
+
+/// Changes made to an account by a transaction included in a block.
+#[derive(Default, Serialize, Deserialize)]
+pub struct TransactionChanges {
+    /// Outputs created by the transaction and owned by the account.
+    received: Vec<OutputPointer>,
+    /// Outputs of the account spent by the transaction.
+    spent: Vec<OutputPointer>,
+    /// Transaction sent from the account that got confirmed.
+    confirmed: Option<PendingTransaction>,
+    /// Data request of the account whose value got locked until its tally.
+    requested: Option<LockedValue>,
+    /// Data request of the account whose value got unlocked by its tally.
+    tallied: Option<LockedValue>,
+    /// Data request of the account whose stage changed, along with its previous state.
+    tracked: Option<(Hash, Option<TrackedDataRequest>)>,
+}
+
+/// Data request of an account resolved by a tally included in a block.
+#[derive(Clone, Debug)]
+pub struct DataRequestTally {
+    /// Index of the account that sent the data request.
+    pub(crate) account: u32,
+    pub(crate) data_request: Hash,
+    /// Epoch of the block including the tally.
+    pub(crate) epoch: Epoch,
+    /// Commits, reveals and tally of the data request.
+    pub(crate) report: DataRequestReport,
+}
+
+/// Outcome of applying a block to a wallet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncStatus {
+    /// The block has been applied.
+    Applied,
+    /// The block had already been applied.
+    AlreadySynced,
+    /// The block does not follow the last applied block, so some blocks are missing or the node
+    /// has reorganized its chain.
+    Gap,
+}
+
+/// Build the output of a data request paying `reward` to each one of its `witnesses`, and
+/// splitting `fee` evenly among its commit, reveal and tally transactions. The remainder of the
+/// split is added to the tally fee.
+///
+/// Return `None` if the total value of the data request overflows.
+pub fn data_request_output(
+    data_request: RADRequest,
+    witnesses: u16,
+    backup_witnesses: u16,
+    reward: u64,
+    fee: u64,
+    time_lock: u64,
+) -> Option<DataRequestOutput> {
+    let value = reward.checked_mul(u64::from(witnesses))?.checked_add(fee)?;
+    let commit_fee = fee / 3;
+    let reveal_fee = fee / 3;
+    let tally_fee = fee - commit_fee - reveal_fee;
+
+    Some(DataRequestOutput {
+        data_request,
+        value,
+        witnesses,
+        backup_witnesses,
+        commit_fee,
+        reveal_fee,
+        tally_fee,
+        time_lock,
+    })
+}
+
+/// List the transactions of a block in the order they are applied to the chain state.
+fn block_transactions(txns: &BlockTransactions) -> Vec<Transaction> {
+    let mut transactions = vec![Transaction::Mint(txns.mint.clone())];
+    transactions.extend(
+        txns.value_transfer_txns
+            .iter()
+            .cloned()
+            .map(Transaction::ValueTransfer),
+    );
+    transactions.extend(
+        txns.data_request_txns
+            .iter()
+            .cloned()
+            .map(Transaction::DataRequest),
+    );
+    transactions.extend(txns.commit_txns.iter().cloned().map(Transaction::Commit));
+    transactions.extend(txns.reveal_txns.iter().cloned().map(Transaction::Reveal));
+    transactions.extend(txns.tally_txns.iter().cloned().map(Transaction::Tally));
+
+    transactions
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Account {
     keychains: KeyChains,
     balance: u64,
+    /// Transactions sent from this account that have not been included in a block yet.
+    pending: Vec<PendingTransaction>,
+    /// Values of the data requests of this account that have not been tallied yet.
+    ///
+    /// They are released by the tally of each data request or, if that is missed, once the
+    /// node no longer has the data request pending, see
+    /// [`release_locked`](WalletContent::release_locked).
+    locked: Vec<LockedValue>,
+    /// Data requests sent from this account that have not been tallied yet.
+    data_requests: Vec<TrackedDataRequest>,
 }
 
 impl Account {
@@ -67,7 +457,731 @@ impl Account {
         Self {
             keychains,
             balance: 0,
+            pending: Vec::new(),
+            locked: Vec::new(),
+            data_requests: Vec::new(),
+        }
+    }
+
+    /// Breakdown of the funds of this account.
+    pub fn balance(&self) -> Balance {
+        let pending_ids = self
+            .pending
+            .iter()
+            .map(|pending| pending.transaction_id)
+            .collect::<Vec<_>>();
+        // Outputs spent by pending transactions are still unspent in the chain
+        let pending_spent = self
+            .keychains
+            .spendable()
+            .flat_map(|final_key| final_key.stxos.iter())
+            .filter(|stxo| pending_ids.contains(&stxo.spent_in))
+            .map(|stxo| stxo.value)
+            .sum::<u64>();
+
+        Balance {
+            confirmed: self.available_balance() + pending_spent,
+            pending_incoming: self.pending.iter().map(|pending| pending.change).sum(),
+            pending_outgoing: self.pending.iter().map(|pending| pending.spent).sum(),
+            locked: self.locked.iter().map(|locked| locked.value).sum(),
+        }
+    }
+
+    /// Sum of the values of all the unspent outputs of this account.
+    pub fn available_balance(&self) -> u64 {
+        self.keychains
+            .spendable()
+            .flat_map(|final_key| final_key.utxos.iter())
+            .map(|utxo| utxo.value)
+            .sum()
+    }
+
+    /// Build the body of a value transfer transaction paying `outputs` plus `fee` with the
+    /// unspent outputs of this account.
+    ///
+    /// If the selected outputs sum up to more than needed, a change output is paid to a new key
+    /// derived from the internal keychain. The selected outputs are marked as spent.
+    ///
+    /// Return the transaction body along with the keys that must sign each one of its inputs, in
+    /// the same order.
+    pub fn build_vtt(
+        &mut self,
+        master_key: &MasterKey,
+        mut outputs: Vec<ValueTransferOutput>,
+        fee: u64,
+    ) -> Result<(VTTransactionBody, Vec<ExtendedSK>), BuildTransactionError> {
+        let outputs_value = outputs.iter().map(|output| output.value).sum::<u64>();
+        let required = outputs_value
+            .checked_add(fee)
+            .ok_or_else(|| BuildTransactionError::ValueOverflow)?;
+        let (inputs, keys, spent, change) = self.select_inputs(master_key, required)?;
+        let change_value = change.as_ref().map_or(0, |change| change.value);
+        outputs.extend(change);
+
+        let body = VTTransactionBody::new(inputs, outputs);
+        let transaction_id = body.hash();
+
+        for input in &body.inputs {
+            self.keychains.spend(input.output_pointer(), transaction_id);
+        }
+        self.pending.push(PendingTransaction {
+            transaction_id,
+            spent,
+            change: change_value,
+        });
+        self.balance = self.available_balance();
+
+        Ok((body, keys))
+    }
+
+    /// Build the body of a transaction posting a data request funded with the unspent outputs of
+    /// this account, paying any change to a new key derived from the internal keychain.
+    ///
+    /// Unlike [`build_vtt`](Account::build_vtt), the selected outputs are not marked as spent:
+    /// that only happens when the transaction is sent, see
+    /// [`add_pending`](Account::add_pending).
+    ///
+    /// Return the transaction body along with the keys that must sign each one of its inputs, in
+    /// the same order.
+    pub fn build_drt(
+        &mut self,
+        master_key: &MasterKey,
+        dr_output: DataRequestOutput,
+    ) -> Result<(DRTransactionBody, Vec<ExtendedSK>), BuildTransactionError> {
+        let (inputs, keys, _, change) = self.select_inputs(master_key, dr_output.value)?;
+        let body = DRTransactionBody::new(inputs, change.into_iter().collect(), dr_output);
+
+        Ok((body, keys))
+    }
+
+    /// Select unspent outputs of this account worth at least `required`, deriving a change key
+    /// from the internal keychain if they are worth more than that.
+    ///
+    /// Return the inputs spending the selected outputs, the keys that must sign them, their total
+    /// value and the change output, if any.
+    fn select_inputs(
+        &mut self,
+        master_key: &MasterKey,
+        required: u64,
+    ) -> Result<
+        (
+            Vec<Input>,
+            Vec<ExtendedSK>,
+            u64,
+            Option<ValueTransferOutput>,
+        ),
+        BuildTransactionError,
+    > {
+        let available = self.available_balance();
+
+        if required == 0 || required > available {
+            return Err(BuildTransactionError::InsufficientBalance {
+                required,
+                available,
+            });
         }
+
+        // FIXME: this is a very naive utxo selection algorithm
+        let mut selected = Vec::new();
+        let mut acc = 0;
+        'selection: for final_key in self.keychains.spendable() {
+            for utxo in &final_key.utxos {
+                acc += utxo.value;
+                selected.push((utxo.output_pointer.clone(), final_key.key.clone()));
+                if acc >= required {
+                    break 'selection;
+                }
+            }
+        }
+
+        let change = acc - required;
+        let change_output = if change > 0 {
+            let change_key = self.keychains.internal.next_unused(master_key)?;
+
+            Some(ValueTransferOutput {
+                pkh: change_key.pkh,
+                value: change,
+            })
+        } else {
+            None
+        };
+
+        let (inputs, keys): (Vec<Input>, Vec<ExtendedSK>) = selected
+            .into_iter()
+            .map(|(output_pointer, key)| (Input::new(output_pointer), key))
+            .unzip();
+
+        Ok((inputs, keys, acc, change_output))
+    }
+
+    /// Whether the transaction was sent from this account and has not been included in a block
+    /// yet.
+    pub fn is_pending(&self, transaction_id: &Hash) -> bool {
+        self.pending
+            .iter()
+            .any(|pending| &pending.transaction_id == transaction_id)
+    }
+
+    /// Keep track of a transaction sent from this account that has not been included in a block
+    /// yet, marking as spent the outputs consumed by its inputs.
+    ///
+    /// Data requests are also followed until they are tallied.
+    pub fn add_pending(&mut self, transaction: &Transaction) -> Result<(), BuildTransactionError> {
+        let transaction_id = transaction.hash();
+        let (inputs, outputs) = match transaction {
+            Transaction::ValueTransfer(tx) => (&tx.body.inputs, &tx.body.outputs),
+            Transaction::DataRequest(tx) => (&tx.body.inputs, &tx.body.outputs),
+            _ => return Err(BuildTransactionError::UnsupportedTransaction),
+        };
+
+        let mut spent = 0;
+        for input in inputs {
+            spent += self
+                .keychains
+                .unspent_value(input.output_pointer())
+                .ok_or_else(|| {
+                    BuildTransactionError::UnknownInput(input.output_pointer().clone())
+                })?;
+        }
+        for input in inputs {
+            self.keychains.spend(input.output_pointer(), transaction_id);
+        }
+        let change = outputs
+            .iter()
+            .filter(|output| self.keychains.owns(&output.pkh))
+            .map(|output| output.value)
+            .sum();
+
+        self.pending.push(PendingTransaction {
+            transaction_id,
+            spent,
+            change,
+        });
+        if let Transaction::DataRequest(_) = transaction {
+            self.data_requests.push(TrackedDataRequest {
+                transaction_id,
+                state: None,
+            });
+        }
+        self.balance = self.available_balance();
+
+        Ok(())
+    }
+
+    /// Forget the outputs, pending transactions and data requests of this account.
+    fn reset(&mut self) {
+        self.keychains.reset();
+        self.balance = 0;
+        self.pending.clear();
+        self.locked.clear();
+        self.data_requests.clear();
+    }
+
+    /// Stop tracking a pending transaction that could not be sent, marking as unspent again the
+    /// outputs consumed by its inputs.
+    ///
+    /// Return whether the transaction was pending.
+    pub fn discard_pending(&mut self, transaction_id: &Hash) -> bool {
+        let index = match self
+            .pending
+            .iter()
+            .position(|pending| &pending.transaction_id == transaction_id)
+        {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.pending.remove(index);
+        self.keychains.unspend_all(transaction_id);
+        self.data_requests
+            .retain(|tracked| &tracked.transaction_id != transaction_id || tracked.state.is_some());
+        self.balance = self.available_balance();
+
+        true
+    }
+
+    /// Add the outputs of a transaction paying to this account and mark as spent the outputs of
+    /// this account consumed by its inputs.
+    ///
+    /// The transaction is no longer pending if it was sent from this account, and the values of
+    /// the data requests of this account are locked until their tally. The commits, reveals and
+    /// tallies of those data requests are added to their state.
+    ///
+    /// Outputs that had already been received or spent are skipped in the returned changes.
+    pub fn apply_transaction(
+        &mut self,
+        transaction: &Transaction,
+        epoch: Epoch,
+    ) -> TransactionChanges {
+        let transaction_id = transaction.hash();
+        let (inputs, outputs): (&[Input], Vec<&ValueTransferOutput>) = match transaction {
+            Transaction::ValueTransfer(tx) => {
+                (tx.body.inputs.as_slice(), tx.body.outputs.iter().collect())
+            }
+            Transaction::DataRequest(tx) => {
+                (tx.body.inputs.as_slice(), tx.body.outputs.iter().collect())
+            }
+            Transaction::Tally(tx) => (&[][..], tx.outputs.iter().collect()),
+            Transaction::Mint(tx) => (&[][..], vec![&tx.output]),
+            Transaction::Commit(_) | Transaction::Reveal(_) => (&[][..], vec![]),
+        };
+
+        let spent = inputs
+            .iter()
+            .map(Input::output_pointer)
+            .filter(|output_pointer| self.keychains.spend(output_pointer, transaction_id))
+            .cloned()
+            .collect::<Vec<_>>();
+        let received = outputs
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, output)| {
+                let utxo = Utxo {
+                    output_pointer: OutputPointer {
+                        transaction_id,
+                        output_index: index as u32,
+                    },
+                    value: output.value,
+                };
+                let output_pointer = utxo.output_pointer.clone();
+
+                if self.keychains.receive(&output.pkh, utxo) {
+                    Some(output_pointer)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let confirmed = self
+            .pending
+            .iter()
+            .position(|pending| pending.transaction_id == transaction_id)
+            .map(|index| self.pending.remove(index));
+        let (requested, tallied, tracked) = match transaction {
+            Transaction::DataRequest(tx) if !spent.is_empty() || confirmed.is_some() => {
+                let locked = LockedValue {
+                    data_request: transaction_id,
+                    value: tx.body.dr_output.value,
+                };
+                self.locked.push(locked.clone());
+                let previous = self.untrack_data_request(&transaction_id);
+                let pkh = tx
+                    .signatures
+                    .first()
+                    .map(|signature| signature.public_key.pkh())
+                    .unwrap_or_default();
+                self.data_requests.push(TrackedDataRequest {
+                    transaction_id,
+                    state: Some(DataRequestState::new(tx.body.dr_output.clone(), pkh, epoch)),
+                });
+
+                (Some(locked), None, Some((transaction_id, previous)))
+            }
+            Transaction::Commit(tx) => {
+                let tracked = tx.signatures.first().and_then(|signature| {
+                    let pkh = signature.public_key.pkh();
+                    self.update_data_request(&tx.body.dr_pointer, |state| {
+                        state.add_commit(pkh, tx.clone())
+                    })
+                });
+
+                (None, None, tracked)
+            }
+            Transaction::Reveal(tx) => {
+                let tracked = tx.signatures.first().and_then(|signature| {
+                    let pkh = signature.public_key.pkh();
+                    self.update_data_request(&tx.body.dr_pointer, |state| {
+                        state.add_reveal(pkh, tx.clone())
+                    })
+                });
+
+                (None, None, tracked)
+            }
+            Transaction::Tally(tx) => {
+                let tallied = self
+                    .locked
+                    .iter()
+                    .position(|locked| locked.data_request == tx.dr_pointer)
+                    .map(|index| self.locked.remove(index));
+                let tracked = self
+                    .untrack_data_request(&tx.dr_pointer)
+                    .map(|previous| (tx.dr_pointer, Some(previous)));
+
+                (None, tallied, tracked)
+            }
+            _ => (None, None, None),
+        };
+
+        TransactionChanges {
+            received,
+            spent,
+            confirmed,
+            requested,
+            tallied,
+            tracked,
+        }
+    }
+
+    /// Whether the transaction is a commit, reveal or tally of a data request of this account.
+    pub fn follows(&self, transaction: &Transaction) -> bool {
+        let dr_pointer = match transaction {
+            Transaction::Commit(tx) => &tx.body.dr_pointer,
+            Transaction::Reveal(tx) => &tx.body.dr_pointer,
+            Transaction::Tally(tx) => &tx.dr_pointer,
+            _ => return false,
+        };
+
+        self.data_requests
+            .iter()
+            .any(|tracked| &tracked.transaction_id == dr_pointer)
+    }
+
+    /// Stop following a data request, returning its last known state.
+    fn untrack_data_request(&mut self, data_request: &Hash) -> Option<TrackedDataRequest> {
+        self.data_requests
+            .iter()
+            .position(|tracked| &tracked.transaction_id == data_request)
+            .map(|index| self.data_requests.remove(index))
+    }
+
+    /// Update the state of a data request included in a block, returning its previous state if
+    /// the update succeeds.
+    fn update_data_request<F>(
+        &mut self,
+        data_request: &Hash,
+        update: F,
+    ) -> Option<(Hash, Option<TrackedDataRequest>)>
+    where
+        F: FnOnce(&mut DataRequestState) -> Result<(), failure::Error>,
+    {
+        let tracked = self
+            .data_requests
+            .iter_mut()
+            .find(|tracked| &tracked.transaction_id == data_request)?;
+        let previous = tracked.clone();
+        let state = tracked.state.as_mut()?;
+
+        match update(state) {
+            Ok(()) => Some((*data_request, Some(previous))),
+            Err(err) => {
+                log::warn!("Failed to update data request {}: {}", data_request, err);
+                None
+            }
+        }
+    }
+
+    /// Advance the stage of the data requests of this account once all the transactions of a
+    /// block have been applied, the same way the node does.
+    ///
+    /// Return the data requests whose stage changed along with their previous stage.
+    fn update_data_request_stages(&mut self) -> Vec<(Hash, DataRequestStage)> {
+        self.data_requests
+            .iter_mut()
+            .filter_map(|tracked| {
+                let state = tracked.state.as_mut()?;
+                let previous = state.stage;
+
+                if state.update_stage() {
+                    log::debug!(
+                        "Data request {} moved from {:?} to {:?} stage",
+                        tracked.transaction_id,
+                        previous,
+                        state.stage
+                    );
+                    Some((tracked.transaction_id, previous))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Set back the stage of a data request of this account.
+    fn revert_data_request_stage(&mut self, data_request: &Hash, stage: DataRequestStage) {
+        let state = self
+            .data_requests
+            .iter_mut()
+            .find(|tracked| &tracked.transaction_id == data_request)
+            .and_then(|tracked| tracked.state.as_mut());
+
+        if let Some(state) = state {
+            state.stage = stage;
+        }
+    }
+
+    /// Undo the changes made to this account by a transaction.
+    fn revert_transaction(&mut self, changes: TransactionChanges) {
+        for output_pointer in &changes.spent {
+            self.keychains.unspend(output_pointer);
+        }
+        for output_pointer in &changes.received {
+            self.keychains.forget(output_pointer);
+        }
+        if let Some(pending) = changes.confirmed {
+            self.pending.push(pending);
+        }
+        if let Some(requested) = changes.requested {
+            self.locked.retain(|locked| locked != &requested);
+        }
+        if let Some(tallied) = changes.tallied {
+            self.locked.push(tallied);
+        }
+        if let Some((data_request, previous)) = changes.tracked {
+            self.untrack_data_request(&data_request);
+            self.data_requests.extend(previous);
+        }
+    }
+
+    /// Summarize how a transaction affects the funds of this account.
+    ///
+    /// Return `None` if the transaction does not touch any of the addresses of this account.
+    pub fn transaction_entry(
+        &self,
+        transaction: &Transaction,
+        epoch: Option<Epoch>,
+    ) -> Option<TransactionEntry> {
+        let keychains = &self.keychains;
+        let received = |outputs: &[ValueTransferOutput]| -> u64 {
+            outputs
+                .iter()
+                .filter(|output| keychains.owns(&output.pkh))
+                .map(|output| output.value)
+                .sum()
+        };
+        // Value of the inputs spending outputs of this account, and whether the value of all the
+        // inputs is known so the fee can be computed
+        let spent = |inputs: &[Input]| -> (u64, bool) {
+            inputs.iter().fold((0, true), |(value, known), input| {
+                match keychains.output_value(input.output_pointer()) {
+                    Some(input_value) => (value + input_value, known),
+                    None => (value, false),
+                }
+            })
+        };
+        let outputs_sum =
+            |outputs: &[ValueTransferOutput]| -> u64 { outputs.iter().map(|o| o.value).sum() };
+
+        let (kind, spent_value, received_value, fee) = match transaction {
+            Transaction::ValueTransfer(tx) => {
+                let (spent_value, known) = spent(&tx.body.inputs);
+                let fee = if known {
+                    spent_value.saturating_sub(outputs_sum(&tx.body.outputs))
+                } else {
+                    0
+                };
+
+                (
+                    TransactionKind::ValueTransfer,
+                    spent_value,
+                    received(&tx.body.outputs),
+                    fee,
+                )
+            }
+            Transaction::DataRequest(tx) => {
+                let (spent_value, known) = spent(&tx.body.inputs);
+                let fee = if known {
+                    spent_value
+                        .saturating_sub(outputs_sum(&tx.body.outputs) + tx.body.dr_output.value)
+                } else {
+                    0
+                };
+
+                (
+                    TransactionKind::DataRequest,
+                    spent_value,
+                    received(&tx.body.outputs),
+                    fee,
+                )
+            }
+            Transaction::Commit(tx) => {
+                if !tx
+                    .signatures
+                    .iter()
+                    .any(|signature| keychains.owns(&signature.public_key.pkh()))
+                {
+                    return None;
+                }
+
+                (TransactionKind::Commit, 0, 0, 0)
+            }
+            Transaction::Reveal(tx) => {
+                if !keychains.owns(&tx.body.pkh) {
+                    return None;
+                }
+
+                (TransactionKind::Reveal, 0, 0, 0)
+            }
+            Transaction::Tally(tx) => (TransactionKind::Tally, 0, received(&tx.outputs), 0),
+            Transaction::Mint(tx) => (TransactionKind::Mint, 0, received(&[tx.output.clone()]), 0),
+        };
+
+        let (direction, amount) = match kind {
+            TransactionKind::Commit | TransactionKind::Reveal => (Direction::Out, 0),
+            _ if spent_value > 0 => (
+                Direction::Out,
+                spent_value.saturating_sub(received_value + fee),
+            ),
+            _ if received_value > 0 => (Direction::In, received_value),
+            _ => return None,
+        };
+
+        Some(TransactionEntry {
+            transaction_id: transaction.hash(),
+            kind,
+            direction,
+            amount,
+            fee,
+            epoch,
+            status: if epoch.is_some() {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Pending
+            },
+            subject: None,
+        })
+    }
+}
+
+/// Transaction sent from an account that has not been included in a block yet.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PendingTransaction {
+    pub(crate) transaction_id: Hash,
+    /// Value of the outputs of the account spent by the transaction.
+    pub(crate) spent: u64,
+    /// Value paid back to the account as change.
+    pub(crate) change: u64,
+}
+
+/// Data request sent from an account, followed until it is tallied.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedDataRequest {
+    pub(crate) transaction_id: Hash,
+    /// Stage, commits and reveals of the data request, or `None` if it has not been included in
+    /// a block yet.
+    pub(crate) state: Option<DataRequestState>,
+}
+
+impl TrackedDataRequest {
+    /// Build the report of the data request resolved by a tally.
+    ///
+    /// Return `None` if the data request was not waiting for its tally.
+    pub fn resolve(&self, tally: &TallyTransaction) -> Option<DataRequestReport> {
+        let state = self.state.clone()?;
+
+        state
+            .add_tally(tally.clone())
+            .map_err(|err| {
+                log::warn!(
+                    "Failed to resolve data request {}: {}",
+                    self.transaction_id,
+                    err
+                )
+            })
+            .ok()
+    }
+}
+
+/// Value of a data request of an account, locked until the data request is tallied.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LockedValue {
+    pub(crate) data_request: Hash,
+    pub(crate) value: u64,
+}
+
+/// Breakdown of the funds of an account.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    /// Value of the unspent outputs included in blocks.
+    pub(crate) confirmed: u64,
+    /// Value to be received by transactions that have not been included in a block yet.
+    pub(crate) pending_incoming: u64,
+    /// Value spent by transactions that have not been included in a block yet.
+    pub(crate) pending_outgoing: u64,
+    /// Value of the data requests that have not been tallied yet.
+    pub(crate) locked: u64,
+}
+
+impl std::ops::Add for Balance {
+    type Output = Balance;
+
+    fn add(self, other: Balance) -> Balance {
+        Balance {
+            confirmed: self.confirmed + other.confirmed,
+            pending_incoming: self.pending_incoming + other.pending_incoming,
+            pending_outgoing: self.pending_outgoing + other.pending_outgoing,
+            locked: self.locked + other.locked,
+        }
+    }
+}
+
+/// Entry of the transaction history of an account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEntry {
+    pub(crate) transaction_id: Hash,
+    pub(crate) kind: TransactionKind,
+    pub(crate) direction: Direction,
+    /// Value leaving the account (for outgoing transactions) or entering it (for incoming ones).
+    pub(crate) amount: u64,
+    /// Fee paid by the account, only known for transactions spending its outputs.
+    pub(crate) fee: u64,
+    /// Epoch of the block including the transaction, if any.
+    pub(crate) epoch: Option<Epoch>,
+    pub(crate) status: TransactionStatus,
+    /// Free text given by the user when the transaction was created with this wallet.
+    pub(crate) subject: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionKind {
+    ValueTransfer,
+    DataRequest,
+    Commit,
+    Reveal,
+    Tally,
+    Mint,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    In,
+    Out,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionStatus {
+    /// Sent to the node but not yet included in a block.
+    Pending,
+    /// Included in a block.
+    Confirmed,
+}
+
+/// Error returned when a transaction cannot be built with the funds of an account.
+#[derive(Debug, Fail)]
+pub enum BuildTransactionError {
+    #[fail(
+        display = "Insufficient balance: {} required but only {} available",
+        required, available
+    )]
+    InsufficientBalance { required: u64, available: u64 },
+    #[fail(display = "The value of the transaction outputs overflows")]
+    ValueOverflow,
+    #[fail(display = "Input {} is not an unspent output of the account", _0)]
+    UnknownInput(OutputPointer),
+    #[fail(display = "Only value transfer and data request transactions can be sent")]
+    UnsupportedTransaction,
+    #[fail(display = "Key derivation failed: {}", _0)]
+    KeyDerivation(#[cause] KeyDerivationError),
+}
+
+impl From<KeyDerivationError> for BuildTransactionError {
+    fn from(err: KeyDerivationError) -> Self {
+        BuildTransactionError::KeyDerivation(err)
     }
 }
 
@@ -89,12 +1203,180 @@ impl KeyChains {
             rad,
         }
     }
+
+    /// Derive the keys needed to keep `GAP_LIMIT` unused keys after the last used one in both the
+    /// external and internal keychains.
+    pub fn discover(&mut self, master_key: &MasterKey) -> Result<(), KeyDerivationError> {
+        self.external.discover(master_key)?;
+        self.internal.discover(master_key)
+    }
+
+    /// Iterate over the keys whose outputs can be spent, that is, those of the external and
+    /// internal keychains.
+    pub fn spendable(&self) -> impl Iterator<Item = &FinalKey> {
+        self.external
+            .final_keys
+            .iter()
+            .chain(self.internal.final_keys.iter())
+    }
+
+    /// Whether the given address belongs to any of the keychains.
+    pub fn owns(&self, pkh: &PublicKeyHash) -> bool {
+        self.external
+            .final_keys
+            .iter()
+            .chain(self.internal.final_keys.iter())
+            .chain(self.rad.final_keys.iter())
+            .any(|final_key| &final_key.pkh == pkh)
+    }
+
+    /// Value of an unspent output owned by the keychains.
+    pub fn unspent_value(&self, output_pointer: &OutputPointer) -> Option<u64> {
+        self.spendable().find_map(|final_key| {
+            final_key
+                .utxos
+                .iter()
+                .find(|utxo| &utxo.output_pointer == output_pointer)
+                .map(|utxo| utxo.value)
+        })
+    }
+
+    /// Value of an output owned by the keychains, whether it has already been spent or not.
+    pub fn output_value(&self, output_pointer: &OutputPointer) -> Option<u64> {
+        self.spendable().find_map(|final_key| {
+            final_key
+                .utxos
+                .iter()
+                .find(|utxo| &utxo.output_pointer == output_pointer)
+                .map(|utxo| utxo.value)
+                .or_else(|| {
+                    final_key
+                        .stxos
+                        .iter()
+                        .find(|stxo| &stxo.output_pointer == output_pointer)
+                        .map(|stxo| stxo.value)
+                })
+        })
+    }
+
+    /// Mark the output pointed by `output_pointer` as spent in the transaction `spent_in`.
+    ///
+    /// Return whether the output was found among the unspent outputs.
+    fn spend(&mut self, output_pointer: &OutputPointer, spent_in: Hash) -> bool {
+        for final_key in self.final_keys_mut() {
+            if let Some(index) = final_key
+                .utxos
+                .iter()
+                .position(|utxo| &utxo.output_pointer == output_pointer)
+            {
+                let utxo = final_key.utxos.remove(index);
+                final_key.stxos.push(Stxo {
+                    output_pointer: utxo.output_pointer,
+                    value: utxo.value,
+                    spent_in,
+                });
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Add a new unspent output to the key owning `pkh`.
+    ///
+    /// Return `false` if no key owns `pkh` or the output was already known.
+    fn receive(&mut self, pkh: &PublicKeyHash, utxo: Utxo) -> bool {
+        match self
+            .final_keys_mut()
+            .find(|final_key| &final_key.pkh == pkh)
+        {
+            Some(final_key) => {
+                let known = final_key
+                    .utxos
+                    .iter()
+                    .map(|utxo| &utxo.output_pointer)
+                    .chain(final_key.stxos.iter().map(|stxo| &stxo.output_pointer))
+                    .any(|output_pointer| output_pointer == &utxo.output_pointer);
+                if !known {
+                    final_key.utxos.push(utxo);
+                }
+
+                !known
+            }
+            None => false,
+        }
+    }
+
+    /// Mark a spent output as unspent again.
+    fn unspend(&mut self, output_pointer: &OutputPointer) {
+        for final_key in self.final_keys_mut() {
+            if let Some(index) = final_key
+                .stxos
+                .iter()
+                .position(|stxo| &stxo.output_pointer == output_pointer)
+            {
+                let stxo = final_key.stxos.remove(index);
+                final_key.utxos.push(Utxo {
+                    output_pointer: stxo.output_pointer,
+                    value: stxo.value,
+                });
+
+                return;
+            }
+        }
+    }
+
+    /// Forget all the outputs of the keychains, keeping their keys.
+    fn reset(&mut self) {
+        for final_key in self.final_keys_mut() {
+            final_key.utxos.clear();
+            final_key.stxos.clear();
+        }
+    }
+
+    /// Mark as unspent again all the outputs spent in the transaction `spent_in`.
+    fn unspend_all(&mut self, spent_in: &Hash) {
+        for final_key in self.final_keys_mut() {
+            let (unspent, spent) = final_key
+                .stxos
+                .drain(..)
+                .partition::<Vec<_>, _>(|stxo| &stxo.spent_in == spent_in);
+            final_key.stxos = spent;
+            final_key.utxos.extend(unspent.into_iter().map(|stxo| Utxo {
+                output_pointer: stxo.output_pointer,
+                value: stxo.value,
+            }));
+        }
+    }
+
+    /// Remove an output, whether it has been spent or not.
+    fn forget(&mut self, output_pointer: &OutputPointer) {
+        for final_key in self.final_keys_mut() {
+            final_key
+                .utxos
+                .retain(|utxo| &utxo.output_pointer != output_pointer);
+            final_key
+                .stxos
+                .retain(|stxo| &stxo.output_pointer != output_pointer);
+        }
+    }
+
+    fn final_keys_mut(&mut self) -> impl Iterator<Item = &mut FinalKey> {
+        self.external
+            .final_keys
+            .iter_mut()
+            .chain(self.internal.final_keys.iter_mut())
+            .chain(self.rad.final_keys.iter_mut())
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct KeyChain {
     path: KeyPath,
     final_keys: Vec<FinalKey>,
+    /// Number of keys handed out, e.g. to receive the change of a transaction.
+    issued: u32,
 }
 
 impl KeyChain {
@@ -102,7 +1384,63 @@ impl KeyChain {
         Self {
             path,
             final_keys: Vec::new(),
+            issued: 0,
+        }
+    }
+
+    /// Number of consecutive unused keys that are derived after the last used one, as in BIP44.
+    const GAP_LIMIT: usize = 20;
+
+    /// Number of keys of this keychain up to the last one that has either been handed out or
+    /// received an output.
+    fn used_count(&self) -> usize {
+        let received = self
+            .final_keys
+            .iter()
+            .rposition(|final_key| !final_key.utxos.is_empty() || !final_key.stxos.is_empty())
+            .map_or(0, |index| index + 1);
+
+        received.max(self.issued as usize)
+    }
+
+    /// Derive keys until there are `GAP_LIMIT` unused keys after the last used one.
+    ///
+    /// Since outputs are only found for derived keys, this is what allows a wallet restored from
+    /// its seed to recover all its funds and transaction history.
+    pub fn discover(&mut self, master_key: &MasterKey) -> Result<(), KeyDerivationError> {
+        while self.final_keys.len() < self.used_count() + Self::GAP_LIMIT {
+            self.derive_next(master_key)?;
         }
+
+        Ok(())
+    }
+
+    /// Hand out the first unused key of this keychain.
+    pub fn next_unused(&mut self, master_key: &MasterKey) -> Result<&FinalKey, KeyDerivationError> {
+        let index = self.used_count();
+        self.issued = index as u32 + 1;
+        self.discover(master_key)?;
+
+        Ok(&self.final_keys[index])
+    }
+
+    /// Derive the next key of this keychain from the wallet master key and keep track of it.
+    fn derive_next(&mut self, master_key: &MasterKey) -> Result<&FinalKey, KeyDerivationError> {
+        let index = self.final_keys.len() as u32;
+        let path = self.path.join(index);
+        let key = master_key.derive(path.to_child_numbers())?;
+        let public_key = PK::from_secret_key(&SignContext::signing_only(), &key.secret_key);
+        let pkh = PublicKey::from(public_key).pkh();
+
+        self.final_keys.push(FinalKey {
+            path,
+            key,
+            pkh,
+            utxos: Vec::new(),
+            stxos: Vec::new(),
+        });
+
+        Ok(&self.final_keys[self.final_keys.len() - 1])
     }
 }
 
@@ -110,13 +1448,25 @@ impl KeyChain {
 pub struct FinalKey {
     path: KeyPath,
     key: ExtendedSK,
-    pkh: String,
+    pkh: PublicKeyHash,
     utxos: Vec<Utxo>,
     stxos: Vec<Stxo>,
 }
 
-pub type Utxo = ();
-pub type Stxo = ();
+/// Output owned by a key of the wallet that has not been spent yet.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Utxo {
+    pub(crate) output_pointer: OutputPointer,
+    pub(crate) value: u64,
+}
+
+/// Output owned by a key of the wallet that has been spent in the transaction `spent_in`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stxo {
+    pub(crate) output_pointer: OutputPointer,
+    pub(crate) value: u64,
+    pub(crate) spent_in: Hash,
+}
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct KeyPath {
@@ -143,39 +1493,17 @@ impl KeyPath {
         let path = self.clone();
         path.index(index)
     }
+
+    /// Convert this path into the list of child numbers used to derive a key.
+    pub fn to_child_numbers(&self) -> Vec<ChildNumber> {
+        self.path.iter().cloned().map(ChildNumber::from).collect()
+    }
 }
 
 /// TODO: implemented in PR #432
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Mnemonics {}
 
-/// TODO: Implement (radon crate)
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RADType(String);
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RADRetrieveArgs {
-    kind: RADType,
-    url: String,
-    script: Vec<Value>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RADAggregateArgs {
-    script: Vec<Value>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RADConsensusArgs {
-    script: Vec<Value>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RADDeliverArgs {
-    kind: RADType,
-    url: String,
-}
-
 /// HD Wallet Master ExtendedKey
 pub type MasterKey = ExtendedSK;
 
@@ -189,3 +1517,612 @@ pub struct Key {
     pub(crate) secret: Protected,
     pub(crate) salt: Vec<u8>,
 }
+
+#[cfg(test)]
+mod tests {
+    use witnet_crypto::key::MasterKeyGen;
+    use witnet_data_structures::chain::{BlockHeader, CheckpointBeacon, KeyedSignature};
+    use witnet_data_structures::transaction::{
+        CommitTransaction, CommitTransactionBody, DRTransaction, MintTransaction,
+        RevealTransaction, RevealTransactionBody, VTTransaction,
+    };
+    use witnet_data_structures::vrf::DataRequestEligibilityClaim;
+
+    use super::*;
+
+    fn wallet() -> WalletContent {
+        let master_key = MasterKeyGen::new([0; 32]).generate().unwrap();
+        let path = KeyPath::master().hardened(3).hardened(4919).hardened(0);
+        let mut wallet = WalletContent::new(
+            master_key.clone(),
+            Wip::Wip3,
+            vec![Account::new(KeyChains::new(path))],
+        );
+        wallet.accounts[0].keychains.discover(&master_key).unwrap();
+
+        wallet
+    }
+
+    /// Address of a key of the external keychain of the first account.
+    fn address(wallet: &WalletContent, index: usize) -> PublicKeyHash {
+        wallet.accounts[0].keychains.external.final_keys[index].pkh
+    }
+
+    fn mint(epoch: Epoch, pkh: PublicKeyHash, value: u64) -> BlockTransactions {
+        BlockTransactions {
+            mint: MintTransaction::new(epoch, ValueTransferOutput { pkh, value }),
+            ..BlockTransactions::default()
+        }
+    }
+
+    fn block(epoch: Epoch, hash_prev_block: Hash, txns: BlockTransactions) -> Block {
+        Block {
+            block_header: BlockHeader {
+                beacon: CheckpointBeacon {
+                    checkpoint: epoch,
+                    hash_prev_block,
+                },
+                ..BlockHeader::default()
+            },
+            block_sig: KeyedSignature::default(),
+            txns,
+        }
+    }
+
+    /// Apply a block following the last block applied to the wallet.
+    fn apply(
+        wallet: &mut WalletContent,
+        epoch: Epoch,
+        txns: BlockTransactions,
+    ) -> (Vec<(u32, TransactionEntry)>, Vec<DataRequestTally>) {
+        let hash_prev_block = wallet
+            .last_beacon
+            .map_or_else(Hash::default, |beacon| beacon.block_hash);
+        let block = block(epoch, hash_prev_block, txns);
+        let (status, entries, tallies) = wallet.apply_block(&block).unwrap();
+        assert_eq!(status, SyncStatus::Applied);
+
+        (entries, tallies)
+    }
+
+    /// Wallet whose first account received 10 and 20 in the first two keys of its external
+    /// keychain.
+    fn funded_wallet() -> WalletContent {
+        let mut wallet = wallet();
+        let (first, second) = (address(&wallet, 0), address(&wallet, 1));
+        apply(&mut wallet, 1, mint(1, first, 10));
+        apply(&mut wallet, 2, mint(2, second, 20));
+
+        wallet
+    }
+
+    /// Data request transaction funded with the first account of the wallet.
+    fn data_request(wallet: &mut WalletContent, value: u64) -> DRTransaction {
+        let master_key = wallet.master_key.clone();
+        let dr_output = DataRequestOutput {
+            value,
+            ..DataRequestOutput::default()
+        };
+        let (body, _) = wallet.accounts[0]
+            .build_drt(&master_key, dr_output)
+            .unwrap();
+
+        DRTransaction::new(body, vec![KeyedSignature::default()])
+    }
+
+    fn pay(value: u64) -> Vec<ValueTransferOutput> {
+        vec![ValueTransferOutput {
+            pkh: PublicKeyHash::default(),
+            value,
+        }]
+    }
+
+    #[test]
+    fn build_vtt_selects_outputs_and_pays_change() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let change_pkh = wallet.accounts[0].keychains.internal.final_keys[0].pkh;
+        let account = &mut wallet.accounts[0];
+
+        let (body, keys) = account.build_vtt(&master_key, pay(15), 1).unwrap();
+        assert_eq!(body.inputs.len(), 2);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(
+            body.outputs,
+            vec![
+                ValueTransferOutput {
+                    pkh: PublicKeyHash::default(),
+                    value: 15,
+                },
+                ValueTransferOutput {
+                    pkh: change_pkh,
+                    value: 14,
+                },
+            ]
+        );
+        assert!(account.is_pending(&body.hash()));
+        assert_eq!(account.available_balance(), 0);
+    }
+
+    #[test]
+    fn build_vtt_without_change() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let account = &mut wallet.accounts[0];
+
+        let (body, keys) = account.build_vtt(&master_key, pay(9), 1).unwrap();
+        assert_eq!(body.inputs.len(), 1);
+        assert_eq!(keys.len(), 1);
+        assert_eq!(body.outputs, pay(9));
+        assert_eq!(account.available_balance(), 20);
+    }
+
+    #[test]
+    fn build_vtt_insufficient_balance() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let account = &mut wallet.accounts[0];
+
+        match account.build_vtt(&master_key, pay(30), 1) {
+            Err(BuildTransactionError::InsufficientBalance {
+                required: 31,
+                available: 30,
+            }) => (),
+            x => panic!("{:?}", x.map(|(body, _)| body)),
+        }
+        match account.build_vtt(&master_key, pay(0), 0) {
+            Err(BuildTransactionError::InsufficientBalance { required: 0, .. }) => (),
+            x => panic!("{:?}", x.map(|(body, _)| body)),
+        }
+        match account.build_vtt(&master_key, pay(u64::max_value()), 1) {
+            Err(BuildTransactionError::ValueOverflow) => (),
+            x => panic!("{:?}", x.map(|(body, _)| body)),
+        }
+        assert!(account.pending.is_empty());
+        assert_eq!(account.available_balance(), 30);
+    }
+
+    #[test]
+    fn build_vtt_does_not_reuse_spent_outputs() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let account = &mut wallet.accounts[0];
+
+        let (first, _) = account.build_vtt(&master_key, pay(9), 1).unwrap();
+        let (second, _) = account.build_vtt(&master_key, pay(19), 1).unwrap();
+        assert_ne!(first.inputs, second.inputs);
+        match account.build_vtt(&master_key, pay(1), 0) {
+            Err(BuildTransactionError::InsufficientBalance { available: 0, .. }) => (),
+            x => panic!("{:?}", x.map(|(body, _)| body)),
+        }
+    }
+
+    #[test]
+    fn discard_pending_releases_outputs() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let account = &mut wallet.accounts[0];
+        let (body, _) = account.build_vtt(&master_key, pay(15), 1).unwrap();
+
+        assert!(account.discard_pending(&body.hash()));
+        assert!(!account.is_pending(&body.hash()));
+        assert_eq!(account.available_balance(), 30);
+        assert!(!account.discard_pending(&body.hash()));
+    }
+
+    #[test]
+    fn sent_vtt_is_confirmed_by_a_block() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let (body, _) = wallet.accounts[0]
+            .build_vtt(&master_key, pay(15), 1)
+            .unwrap();
+        let transaction_id = body.hash();
+        let txns = BlockTransactions {
+            value_transfer_txns: vec![VTTransaction::new(body, vec![])],
+            ..BlockTransactions::default()
+        };
+
+        let (entries, _) = apply(&mut wallet, 3, txns);
+        let account = &wallet.accounts[0];
+        assert!(!account.is_pending(&transaction_id));
+        assert_eq!(account.available_balance(), 14);
+        assert_eq!(entries.len(), 1);
+        let (index, entry) = &entries[0];
+        assert_eq!(*index, 0);
+        assert_eq!(entry.transaction_id, transaction_id);
+        assert_eq!(entry.direction, Direction::Out);
+        assert_eq!(entry.amount, 15);
+        assert_eq!(entry.fee, 1);
+        assert_eq!(entry.status, TransactionStatus::Confirmed);
+    }
+
+    #[test]
+    fn apply_block_detects_gaps_and_duplicates() {
+        let mut wallet = wallet();
+        let first = block(1, Hash::default(), mint(1, address(&wallet, 0), 10));
+        let first_hash = first.hash();
+        assert_eq!(wallet.apply_block(&first).unwrap().0, SyncStatus::Applied);
+        assert_eq!(
+            wallet.apply_block(&first).unwrap().0,
+            SyncStatus::AlreadySynced
+        );
+
+        let unrelated = block(3, Hash::SHA256([1; 32]), BlockTransactions::default());
+        assert_eq!(wallet.apply_block(&unrelated).unwrap().0, SyncStatus::Gap);
+
+        let second = block(2, first_hash, mint(2, address(&wallet, 0), 20));
+        assert_eq!(wallet.apply_block(&second).unwrap().0, SyncStatus::Applied);
+        assert_eq!(
+            wallet.synced_beacons(),
+            vec![
+                SyncedBeacon {
+                    epoch: 2,
+                    block_hash: second.hash(),
+                },
+                SyncedBeacon {
+                    epoch: 1,
+                    block_hash: first_hash,
+                },
+            ]
+        );
+        assert_eq!(wallet.epoch_last, 2);
+        assert_eq!(wallet.accounts[0].available_balance(), 30);
+    }
+
+    #[test]
+    fn rollback_block_reverts_the_last_block() {
+        let mut wallet = funded_wallet();
+        let beacons = wallet.synced_beacons();
+        let minted = MintTransaction::new(
+            2,
+            ValueTransferOutput {
+                pkh: address(&wallet, 1),
+                value: 20,
+            },
+        );
+
+        assert_eq!(wallet.rollback_block(), Some(vec![(0, minted.hash())]));
+        assert_eq!(wallet.accounts[0].available_balance(), 10);
+        assert_eq!(wallet.last_beacon, Some(beacons[1]));
+        assert_eq!(wallet.epoch_last, 1);
+
+        assert!(wallet.rollback_block().is_some());
+        assert_eq!(wallet.accounts[0].available_balance(), 0);
+        assert_eq!(wallet.last_beacon, None);
+        assert_eq!(wallet.rollback_block(), None);
+    }
+
+    #[test]
+    fn rollback_block_restores_pending_transactions() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        let (body, _) = wallet.accounts[0]
+            .build_vtt(&master_key, pay(15), 1)
+            .unwrap();
+        let transaction_id = body.hash();
+        let txns = BlockTransactions {
+            value_transfer_txns: vec![VTTransaction::new(body, vec![])],
+            ..BlockTransactions::default()
+        };
+        apply(&mut wallet, 3, txns);
+
+        wallet.rollback_block().unwrap();
+        let account = &wallet.accounts[0];
+        assert!(account.is_pending(&transaction_id));
+        assert_eq!(account.available_balance(), 0);
+        assert_eq!(account.balance().confirmed, 30);
+        assert_eq!(account.balance().pending_incoming, 14);
+    }
+
+    #[test]
+    fn reorg_replaces_the_last_block() {
+        let mut wallet = funded_wallet();
+        let common = wallet.synced_beacons()[1];
+        wallet.rollback_block().unwrap();
+
+        let replacement = block(3, common.block_hash, mint(3, address(&wallet, 2), 5));
+        assert_eq!(
+            wallet.apply_block(&replacement).unwrap().0,
+            SyncStatus::Applied
+        );
+        assert_eq!(wallet.accounts[0].available_balance(), 15);
+        assert_eq!(wallet.synced_beacons().len(), 2);
+    }
+
+    #[test]
+    fn synced_blocks_are_limited() {
+        let mut wallet = wallet();
+        let pkh = address(&wallet, 0);
+        let count = WalletContent::MAX_SYNCED_BLOCKS as u32 + 10;
+        for epoch in 1..=count {
+            apply(&mut wallet, epoch, mint(epoch, pkh, 1));
+        }
+        assert_eq!(
+            wallet.synced_beacons().len(),
+            WalletContent::MAX_SYNCED_BLOCKS
+        );
+
+        let mut reverted = 0;
+        while wallet.rollback_block().is_some() {
+            reverted += 1;
+        }
+        assert_eq!(reverted, WalletContent::MAX_SYNCED_BLOCKS);
+        assert_eq!(wallet.accounts[0].available_balance(), 10);
+    }
+
+    #[test]
+    fn reset_forgets_the_chain() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        wallet.accounts[0]
+            .build_vtt(&master_key, pay(15), 1)
+            .unwrap();
+        wallet.epoch_born = 1;
+
+        wallet.reset();
+        assert_eq!(wallet.accounts[0].available_balance(), 0);
+        assert!(wallet.accounts[0].pending.is_empty());
+        assert!(wallet.synced_beacons().is_empty());
+        assert_eq!(wallet.last_beacon, None);
+        assert_eq!(wallet.epoch_last, 1);
+
+        let pkh = address(&wallet, 0);
+        apply(&mut wallet, 1, mint(1, pkh, 10));
+        assert_eq!(wallet.accounts[0].available_balance(), 10);
+    }
+
+    #[test]
+    fn discover_keeps_gap_limit_unused_keys() {
+        let master_key = MasterKeyGen::new([0; 32]).generate().unwrap();
+        let mut keychain = KeyChain::new(KeyPath::master().hardened(3).index(1));
+
+        keychain.discover(&master_key).unwrap();
+        assert_eq!(keychain.final_keys.len(), KeyChain::GAP_LIMIT);
+        keychain.discover(&master_key).unwrap();
+        assert_eq!(keychain.final_keys.len(), KeyChain::GAP_LIMIT);
+
+        keychain.final_keys[5].utxos.push(Utxo {
+            output_pointer: OutputPointer::default(),
+            value: 1,
+        });
+        keychain.discover(&master_key).unwrap();
+        assert_eq!(keychain.final_keys.len(), 6 + KeyChain::GAP_LIMIT);
+    }
+
+    #[test]
+    fn next_unused_hands_out_consecutive_keys() {
+        let master_key = MasterKeyGen::new([0; 32]).generate().unwrap();
+        let mut keychain = KeyChain::new(KeyPath::master().hardened(3).index(0));
+
+        let first = keychain.next_unused(&master_key).unwrap().pkh;
+        let second = keychain.next_unused(&master_key).unwrap().pkh;
+        assert_eq!(first, keychain.final_keys[0].pkh);
+        assert_eq!(second, keychain.final_keys[1].pkh);
+        assert_eq!(keychain.issued, 2);
+        assert_eq!(keychain.final_keys.len(), 2 + KeyChain::GAP_LIMIT);
+
+        // A key that received an output is not handed out
+        keychain.final_keys[2].stxos.push(Stxo {
+            output_pointer: OutputPointer::default(),
+            value: 1,
+            spent_in: Hash::default(),
+        });
+        let fourth = keychain.next_unused(&master_key).unwrap().pkh;
+        assert_eq!(fourth, keychain.final_keys[3].pkh);
+    }
+
+    #[test]
+    fn apply_block_discovers_keys_after_the_last_used_one() {
+        let mut wallet = wallet();
+        let last = KeyChain::GAP_LIMIT - 1;
+        let pkh = address(&wallet, last);
+        apply(&mut wallet, 1, mint(1, pkh, 10));
+        assert_eq!(
+            wallet.accounts[0].keychains.external.final_keys.len(),
+            last + 1 + KeyChain::GAP_LIMIT
+        );
+
+        // Outputs paying to keys beyond the gap limit of the previous block are found
+        let last = 2 * KeyChain::GAP_LIMIT - 1;
+        let pkh = address(&wallet, last);
+        apply(&mut wallet, 2, mint(2, pkh, 20));
+        assert_eq!(wallet.accounts[0].available_balance(), 30);
+        assert_eq!(
+            wallet.accounts[0].keychains.external.final_keys.len(),
+            last + 1 + KeyChain::GAP_LIMIT
+        );
+    }
+
+    #[test]
+    fn balance_of_pending_transactions() {
+        let mut wallet = funded_wallet();
+        let master_key = wallet.master_key.clone();
+        assert_eq!(
+            wallet.balances(),
+            vec![Balance {
+                confirmed: 30,
+                ..Balance::default()
+            }]
+        );
+
+        let (body, _) = wallet.accounts[0]
+            .build_vtt(&master_key, pay(15), 1)
+            .unwrap();
+        assert_eq!(
+            wallet.accounts[0].balance(),
+            Balance {
+                confirmed: 30,
+                pending_incoming: 14,
+                pending_outgoing: 30,
+                locked: 0,
+            }
+        );
+
+        let txns = BlockTransactions {
+            value_transfer_txns: vec![VTTransaction::new(body, vec![])],
+            ..BlockTransactions::default()
+        };
+        apply(&mut wallet, 3, txns);
+        assert_eq!(
+            wallet.accounts[0].balance(),
+            Balance {
+                confirmed: 14,
+                ..Balance::default()
+            }
+        );
+    }
+
+    #[test]
+    fn balance_of_data_requests() {
+        let mut wallet = funded_wallet();
+        let dr = data_request(&mut wallet, 5);
+        let dr_id = dr.hash();
+        let txns = BlockTransactions {
+            data_request_txns: vec![dr],
+            ..BlockTransactions::default()
+        };
+
+        apply(&mut wallet, 3, txns);
+        assert_eq!(
+            wallet.accounts[0].balance(),
+            Balance {
+                confirmed: 25,
+                locked: 5,
+                ..Balance::default()
+            }
+        );
+        assert_eq!(wallet.locked_data_requests(), vec![dr_id]);
+
+        assert_eq!(wallet.release_locked(&[dr_id]), 1);
+        assert_eq!(wallet.accounts[0].balance().locked, 0);
+        assert!(wallet.locked_data_requests().is_empty());
+        assert_eq!(wallet.release_locked(&[dr_id]), 0);
+    }
+
+    #[test]
+    fn add_pending_reserves_data_request_outputs() {
+        let mut wallet = funded_wallet();
+        let dr = data_request(&mut wallet, 5);
+        let dr_id = dr.hash();
+        let transaction = Transaction::DataRequest(dr);
+        let account = &mut wallet.accounts[0];
+        // Building the transaction does not reserve its outputs
+        assert_eq!(account.available_balance(), 30);
+
+        account.add_pending(&transaction).unwrap();
+        assert!(account.is_pending(&dr_id));
+        assert_eq!(account.available_balance(), 20);
+        assert_eq!(
+            account.data_requests,
+            vec![TrackedDataRequest {
+                transaction_id: dr_id,
+                state: None,
+            }]
+        );
+        match account.add_pending(&transaction) {
+            Err(BuildTransactionError::UnknownInput(_)) => (),
+            x => panic!("{:?}", x),
+        }
+
+        assert!(account.discard_pending(&dr_id));
+        assert_eq!(account.available_balance(), 30);
+        assert!(account.data_requests.is_empty());
+    }
+
+    #[test]
+    fn add_pending_only_supports_value_transfers_and_data_requests() {
+        let mut wallet = funded_wallet();
+        let transaction = Transaction::Mint(MintTransaction::default());
+
+        match wallet.accounts[0].add_pending(&transaction) {
+            Err(BuildTransactionError::UnsupportedTransaction) => (),
+            x => panic!("{:?}", x),
+        }
+    }
+
+    /// Stage of a data request followed by the first account of the wallet.
+    fn stage(wallet: &WalletContent, data_request: &Hash) -> Option<DataRequestStage> {
+        wallet.accounts[0]
+            .data_requests
+            .iter()
+            .find(|tracked| &tracked.transaction_id == data_request)
+            .and_then(|tracked| tracked.state.as_ref())
+            .map(|state| state.stage)
+    }
+
+    #[test]
+    fn data_request_is_followed_until_its_tally() {
+        let mut wallet = funded_wallet();
+        let dr = data_request(&mut wallet, 5);
+        let dr_id = dr.hash();
+        let txns = BlockTransactions {
+            data_request_txns: vec![dr],
+            ..BlockTransactions::default()
+        };
+        apply(&mut wallet, 3, txns);
+        assert_eq!(stage(&wallet, &dr_id), Some(DataRequestStage::COMMIT));
+
+        let commit = CommitTransaction::new(
+            CommitTransactionBody::new(
+                dr_id,
+                Hash::default(),
+                DataRequestEligibilityClaim::default(),
+            ),
+            vec![KeyedSignature::default()],
+        );
+        let txns = BlockTransactions {
+            commit_txns: vec![commit],
+            ..BlockTransactions::default()
+        };
+        apply(&mut wallet, 4, txns);
+        assert_eq!(stage(&wallet, &dr_id), Some(DataRequestStage::REVEAL));
+
+        let reveal = RevealTransaction::new(
+            RevealTransactionBody::new(dr_id, vec![], PublicKeyHash::default()),
+            vec![KeyedSignature::default()],
+        );
+        let txns = BlockTransactions {
+            reveal_txns: vec![reveal],
+            ..BlockTransactions::default()
+        };
+        apply(&mut wallet, 5, txns);
+        assert_eq!(stage(&wallet, &dr_id), Some(DataRequestStage::TALLY));
+
+        let tally = TallyTransaction::new(dr_id, vec![], vec![], vec![]);
+        let txns = BlockTransactions {
+            tally_txns: vec![tally],
+            ..BlockTransactions::default()
+        };
+        let (_, tallies) = apply(&mut wallet, 6, txns);
+        assert_eq!(tallies.len(), 1);
+        assert_eq!(tallies[0].account, 0);
+        assert_eq!(tallies[0].data_request, dr_id);
+        assert_eq!(tallies[0].epoch, 6);
+        assert_eq!(stage(&wallet, &dr_id), None);
+        assert_eq!(wallet.accounts[0].balance().locked, 0);
+
+        // Reverting the tally follows the data request again
+        wallet.rollback_block().unwrap();
+        assert_eq!(stage(&wallet, &dr_id), Some(DataRequestStage::TALLY));
+        assert_eq!(wallet.accounts[0].balance().locked, 5);
+
+        // Reverting the reveal sets back its stage
+        wallet.rollback_block().unwrap();
+        assert_eq!(stage(&wallet, &dr_id), Some(DataRequestStage::REVEAL));
+    }
+
+    #[test]
+    fn tally_of_unknown_data_request_is_ignored() {
+        let mut wallet = funded_wallet();
+        let tally = TallyTransaction::new(Hash::SHA256([1; 32]), vec![], vec![], vec![]);
+        let txns = BlockTransactions {
+            tally_txns: vec![tally],
+            ..BlockTransactions::default()
+        };
+
+        let (entries, tallies) = apply(&mut wallet, 3, txns);
+        assert!(entries.is_empty());
+        assert!(tallies.is_empty());
+    }
+}